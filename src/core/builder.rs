@@ -1,10 +1,14 @@
+use crate::core::context::ValidationContext;
 use crate::core::contract::{
-    IValidate, IValidatorRule, IValidatorRuleCustom, IValidatorRuleCustomAsync, ValidatorFailure,
+    IValidate, IValidatorRule, IValidatorRuleCustom, IValidatorRuleCustomAsync, IValidatorRuleDto,
+    ValidatorFailure,
 };
-use crate::core::errors::{ValidationError, ValidationErrors};
+use crate::core::errors::{RuleFailure, ValidationError, ValidationErrors, ValidationNode};
 use crate::core::field_builder::main::FieldBuilder;
+use crate::core::schema::descriptor::{FieldDescriptor, NestedDescriptor};
+use crate::core::temporal::clock::Clock;
 use async_trait::async_trait;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
 use std::marker::PhantomData;
 use std::sync::Arc;
@@ -15,6 +19,30 @@ where
     E: Error + Send + Sync + 'static,
 {
     async fn validate_async(&self, dto: &T) -> Result<Vec<ValidationError>, E>;
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        _ctx: &ValidationContext,
+    ) -> Result<Vec<ValidationError>, E>
+    where
+        T: Sync,
+    {
+        self.validate_async(dto).await
+    }
+
+    /// `validate_with_context` keeping the children of `RuleFailure::Children` apart.
+    async fn validate_all(&self, dto: &T, ctx: &ValidationContext) -> Result<Vec<RuleFailure>, E>
+    where
+        T: Sync,
+    {
+        let errors = self.validate_with_context(dto, ctx).await?;
+        Ok(errors.into_iter().map(RuleFailure::Field).collect())
+    }
+
+    fn describe(&self) -> FieldDescriptor {
+        FieldDescriptor::default()
+    }
 }
 
 pub struct FieldRules<T, V, E>
@@ -24,6 +52,7 @@ where
     pub field_name: &'static str,
     pub accessor: Arc<dyn Fn(&T) -> &V + Send + Sync>,
     pub rules: Vec<Box<dyn IValidatorRule<V>>>,
+    pub rules_dto: Vec<Box<dyn IValidatorRuleDto<T, V>>>,
     pub rules_custom: Vec<Box<dyn IValidatorRuleCustom<V, E>>>,
     pub rules_custom_async: Vec<Box<dyn IValidatorRuleCustomAsync<V, E>>>,
 }
@@ -36,37 +65,84 @@ where
     E: Error + Send + Sync + 'static,
 {
     async fn validate_async(&self, dto: &T) -> Result<Vec<ValidationError>, E> {
+        self.validate_with_context(dto, &ValidationContext::default())
+            .await
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<Vec<ValidationError>, E> {
+        let failures = self.validate_all(dto, ctx).await?;
+        Ok(failures
+            .into_iter()
+            .flat_map(|failure| match failure {
+                RuleFailure::Field(error) => vec![error],
+                RuleFailure::Children(children) => children.into_iter().map(|(_, e)| e).collect(),
+            })
+            .collect())
+    }
+
+    async fn validate_all(&self, dto: &T, ctx: &ValidationContext) -> Result<Vec<RuleFailure>, E> {
         let mut errors = vec![];
         let value = (self.accessor)(dto);
 
         for rule in &self.rules {
-            if let Err(e) = rule.validate(value) {
+            if let Err(e) = rule.validate_all(value, ctx) {
                 errors.push(e);
             }
         }
 
-        for rule in &self.rules_custom {
-            if let Some(e) = rule.validate(value)? {
+        for rule in &self.rules_dto {
+            if let Err(e) = rule.validate_all(dto, value, ctx) {
                 errors.push(e);
             }
         }
 
+        for rule in &self.rules_custom {
+            if let Some(e) = rule.validate_with_context(value, ctx)? {
+                errors.push(RuleFailure::Field(e));
+            }
+        }
+
         for rule in &self.rules_custom_async {
-            if let Some(e) = rule.validate(value).await? {
-                errors.push(e);
+            if let Some(e) = rule.validate_with_context(value, ctx).await? {
+                errors.push(RuleFailure::Field(e));
             }
         }
 
         Ok(errors)
     }
+
+    fn describe(&self) -> FieldDescriptor {
+        let rules: Vec<_> = (self.rules.iter().map(|r| r.describe()))
+            .chain(self.rules_dto.iter().map(|r| r.describe()))
+            .flatten()
+            .collect();
+        let custom_rules = self.rules.len() + self.rules_dto.len() - rules.len()
+            + self.rules_custom.len()
+            + self.rules_custom_async.len();
+
+        FieldDescriptor {
+            rules,
+            custom_rules,
+            nested: None,
+        }
+    }
 }
 
+type FieldSanitizer<T> = Box<dyn Fn(&mut T) + Send + Sync>;
+
 pub struct RulesBuilder<T, E>
 where
     E: Error + Send + Sync + 'static,
 {
     pub fields: HashMap<String, Box<dyn ValidateFieldAsync<T, E>>>,
     pub nested: HashMap<String, Box<dyn IValidate<T, E>>>,
+    /// Declared with `FieldBuilder::sanitize`, applied in declaration order.
+    pub sanitizers: Vec<FieldSanitizer<T>>,
+    pub context: ValidationContext,
     pub _phantom: PhantomData<E>,
 }
 
@@ -76,13 +152,25 @@ where
     E: Error + Send + Sync + 'static,
 {
     pub fn new() -> Self {
+        Self::new_with_context(ValidationContext::default())
+    }
+
+    pub fn new_with_context(context: ValidationContext) -> Self {
         Self {
             fields: HashMap::new(),
             nested: HashMap::new(),
+            sanitizers: vec![],
+            context,
             _phantom: Default::default(),
         }
     }
 
+    /// Overrides the clock of the context for temporal rules (`in_past`, `min_age`, ...).
+    pub fn with_clock(&mut self, clock: impl Clock + 'static) -> &mut Self {
+        self.context.set_clock(Arc::new(clock));
+        self
+    }
+
     pub fn field<TField>(
         &mut self,
         field_name: &'static str,
@@ -97,6 +185,7 @@ where
             field_name,
             accessor,
             rules: vec![],
+            rules_dto: vec![],
             rules_custom: vec![],
             rules_custom_async: vec![],
         };
@@ -107,18 +196,53 @@ where
         }
     }
 
+    pub fn sanitize(&self, dto: &mut T) {
+        for sanitizer in &self.sanitizers {
+            sanitizer(dto);
+        }
+        for validator in self.nested.values() {
+            validator.sanitize(dto);
+        }
+    }
+
     pub async fn validate(&self, dto: &T) -> Result<ValidationErrors, E> {
+        self.validate_with_context(dto, &self.context).await
+    }
+
+    pub async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<ValidationErrors, E> {
         let mut result = ValidationErrors::default();
 
         for (field_name, field) in &self.fields {
-            let field_errors = field.validate_async(dto).await?;
-            for error in field_errors {
-                result.add(vec![field_name.clone()], error);
+            let mut children = vec![];
+            let mut own = None;
+            for failure in field.validate_all(dto, ctx).await? {
+                match failure {
+                    RuleFailure::Field(error) => own = Some(error),
+                    RuleFailure::Children(errors) => children.extend(errors),
+                }
+            }
+            // an error of the field itself replaces the children of multi-requirement rules
+            match own {
+                Some(error) => result.add(vec![field_name.clone()], error),
+                None => {
+                    for (child, error) in children {
+                        result.add(vec![field_name.clone(), child], error);
+                    }
+                }
             }
         }
 
         for (field_name, validator) in &self.nested {
-            match validator.validate(dto).await {
+            // a field that failed its own rules (e.g. a document that does not parse) has
+            // nothing to descend into
+            if matches!(result.errors.get(field_name), Some(ValidationNode::Leaf(_))) {
+                continue;
+            }
+            match validator.validate_with_context(dto, ctx).await {
                 Ok(_) => {}
                 Err(ValidatorFailure::Invalid(nested_errors)) => {
                     result.add_nested(vec![field_name.clone()], nested_errors);
@@ -131,6 +255,23 @@ where
 
         Ok(result)
     }
+
+    pub fn describe(&self) -> BTreeMap<String, FieldDescriptor> {
+        let mut fields: BTreeMap<_, _> = self
+            .fields
+            .iter()
+            .map(|(name, field)| (name.clone(), field.describe()))
+            .collect();
+
+        for (name, validator) in &self.nested {
+            fields.entry(name.clone()).or_default().nested = Some(NestedDescriptor {
+                shape: validator.nested_shape(),
+                validator: validator.describe(),
+            });
+        }
+
+        fields
+    }
 }
 
 impl<T, E> Default for RulesBuilder<T, E>
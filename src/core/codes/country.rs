@@ -0,0 +1,40 @@
+use crate::core::codes::table;
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+static COUNTRIES: Lazy<Vec<(&'static str, &'static str)>> = Lazy::new(|| {
+    table(include_str!("../../../data/iso3166_countries.txt"))
+        .filter_map(|line| line.split_once(' '))
+        .collect()
+});
+
+static ALPHA2: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| COUNTRIES.iter().map(|(alpha2, _)| *alpha2).collect());
+
+static ALPHA3: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| COUNTRIES.iter().map(|(_, alpha3)| *alpha3).collect());
+
+/// The ISO 3166-1 alpha-2 code (`UA`) spelled as `code` in any case.
+pub(crate) fn alpha2(code: &str) -> Option<&'static str> {
+    ALPHA2.get(code.to_ascii_uppercase().as_str()).copied()
+}
+
+/// The ISO 3166-1 alpha-3 code (`UKR`) spelled as `code` in any case.
+pub(crate) fn alpha3(code: &str) -> Option<&'static str> {
+    ALPHA3.get(code.to_ascii_uppercase().as_str()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_iso_3166_codes() {
+        assert_eq!(alpha2("ua"), Some("UA"));
+        assert_eq!(alpha2("GB"), Some("GB"));
+        assert_eq!(alpha2("UK"), None);
+        assert_eq!(alpha3("ukr"), Some("UKR"));
+        assert_eq!(alpha3("UA"), None);
+        assert_eq!(alpha2("Ü"), None);
+    }
+}
@@ -0,0 +1,44 @@
+use crate::core::codes::table;
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+static LANGUAGES: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| table(include_str!("../../../data/iso639_languages.txt")).collect());
+
+static SCRIPTS: Lazy<HashSet<&'static str>> =
+    Lazy::new(|| table(include_str!("../../../data/iso15924_scripts.txt")).collect());
+
+/// The ISO 639 language code (`uk`, `ukr`) spelled as `code` in any case. Two-letter codes are
+/// ISO 639-1; three-letter ones are ISO 639-2 (both forms) or 639-3.
+pub(crate) fn language(code: &str) -> Option<&'static str> {
+    LANGUAGES.get(code.to_ascii_lowercase().as_str()).copied()
+}
+
+/// The ISO 15924 script code (`Cyrl`) spelled as `code` in any case.
+pub(crate) fn script(code: &str) -> Option<&'static str> {
+    let mut chars = code.chars();
+    let first = chars.next()?;
+    let titled: String = first
+        .to_uppercase()
+        .chain(chars.flat_map(char::to_lowercase))
+        .collect();
+    SCRIPTS.get(titled.as_str()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_iso_639_and_15924_codes() {
+        assert_eq!(language("UK"), Some("uk"));
+        assert_eq!(language("ukr"), Some("ukr"));
+        assert_eq!(language("ger"), Some("ger"));
+        assert_eq!(language("deu"), Some("deu"));
+        assert_eq!(language("xx"), None);
+        assert_eq!(script("cyrl"), Some("Cyrl"));
+        assert_eq!(script("HANT"), Some("Hant"));
+        assert_eq!(script("Abcd"), None);
+        assert_eq!(script(""), None);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::core::codes::country::alpha2;
+use crate::core::codes::language::{language, script};
+use std::collections::HashSet;
+
+#[derive(PartialEq, PartialOrd)]
+enum Position {
+    Language,
+    Extlang,
+    Script,
+    Region,
+    Variant,
+}
+
+/// The BCP 47 (RFC 5646) tag `tag` in its conventional case (`zh-Hant-TW`, `de-CH-1996`), when
+/// it is well-formed and its language, script and region subtags are known ISO codes.
+/// Grandfathered tags (`i-klingon`) are not supported; `und` and private use (`x-...`) are.
+pub(crate) fn canonical_locale(tag: &str) -> Option<String> {
+    let mut subtags = tag.split('-');
+    let mut canonical = vec![];
+
+    let first = subtags.next()?;
+    if first.eq_ignore_ascii_case("x") {
+        return private_use(first, subtags, canonical);
+    }
+    if !matches!(first.len(), 2 | 3) {
+        return None;
+    }
+    canonical.push(language(first)?.to_string());
+
+    let mut position = Position::Language;
+    let mut variants = HashSet::new();
+    while let Some(subtag) = subtags.next() {
+        if !(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let alphabetic = subtag.bytes().all(|b| b.is_ascii_alphabetic());
+        let digits = subtag.bytes().all(|b| b.is_ascii_digit());
+
+        if subtag.len() == 1 {
+            return extensions(subtag, subtags, canonical);
+        } else if alphabetic && subtag.len() == 3 && position < Position::Script {
+            // Up to three extended language subtags: `zh-yue`.
+            if position == Position::Extlang && canonical.len() > 3 {
+                return None;
+            }
+            canonical.push(language(subtag)?.to_string());
+            position = Position::Extlang;
+        } else if alphabetic && subtag.len() == 4 && position < Position::Script {
+            canonical.push(script(subtag)?.to_string());
+            position = Position::Script;
+        } else if position < Position::Region
+            && ((alphabetic && subtag.len() == 2) || (digits && subtag.len() == 3))
+        {
+            canonical.push(match digits {
+                true => subtag.to_string(),
+                false => alpha2(subtag)?.to_string(),
+            });
+            position = Position::Region;
+        } else if subtag.len() >= 5 || (subtag.len() == 4 && subtag.as_bytes()[0].is_ascii_digit())
+        {
+            let variant = subtag.to_ascii_lowercase();
+            if !variants.insert(variant.clone()) {
+                return None;
+            }
+            canonical.push(variant);
+            position = Position::Variant;
+        } else {
+            return None;
+        }
+    }
+    Some(canonical.join("-"))
+}
+
+/// Extensions (`u-ca-buddhist`) and a trailing private use part, all lowercase.
+fn extensions<'a>(
+    singleton: &'a str,
+    mut subtags: impl Iterator<Item = &'a str>,
+    mut canonical: Vec<String>,
+) -> Option<String> {
+    let mut singleton = singleton;
+    let mut seen = HashSet::new();
+    loop {
+        if singleton.eq_ignore_ascii_case("x") {
+            return private_use(singleton, subtags, canonical);
+        }
+        let lowered = singleton.to_ascii_lowercase();
+        if !seen.insert(lowered.clone()) {
+            return None;
+        }
+        canonical.push(lowered);
+
+        let mut count = 0;
+        loop {
+            let Some(subtag) = subtags.next() else {
+                return (count > 0).then(|| canonical.join("-"));
+            };
+            if subtag.len() == 1 && subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+                if count == 0 {
+                    return None;
+                }
+                singleton = subtag;
+                break;
+            }
+            if !(2..=8).contains(&subtag.len())
+                || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+            {
+                return None;
+            }
+            canonical.push(subtag.to_ascii_lowercase());
+            count += 1;
+        }
+    }
+}
+
+fn private_use<'a>(
+    singleton: &'a str,
+    subtags: impl Iterator<Item = &'a str>,
+    mut canonical: Vec<String>,
+) -> Option<String> {
+    canonical.push(singleton.to_ascii_lowercase());
+    let mut count = 0;
+    for subtag in subtags {
+        if !(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return None;
+        }
+        canonical.push(subtag.to_ascii_lowercase());
+        count += 1;
+    }
+    (count > 0).then(|| canonical.join("-"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canonical(tag: &str) -> Option<String> {
+        canonical_locale(tag)
+    }
+
+    #[test]
+    fn accepts_well_formed_tags() {
+        for tag in [
+            "uk",
+            "uk-UA",
+            "en-US",
+            "zh-Hant-TW",
+            "sr-Latn-RS",
+            "es-419",
+            "de-CH-1996",
+            "sl-rozaj-biske",
+            "zh-yue-HK",
+            "en-US-u-ca-gregory",
+            "de-DE-u-co-phonebk-x-private",
+            "x-whatever",
+            "und",
+        ] {
+            assert_eq!(canonical(tag).as_deref(), Some(tag), "{tag}");
+        }
+    }
+
+    #[test]
+    fn returns_the_conventional_case() {
+        assert_eq!(canonical("ZH-hant-tw").as_deref(), Some("zh-Hant-TW"));
+        assert_eq!(
+            canonical("EN-us-U-CA-GREGORY").as_deref(),
+            Some("en-US-u-ca-gregory")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_or_unknown_subtags() {
+        for tag in [
+            "",
+            "uk_UA",
+            "english",
+            "xx-UA",
+            "uk-XX",
+            "zh-Abcd",
+            "en-US-US",
+            "de-1996-1996",
+            "en-u",
+            "en-u-ca-u-nu",
+            "en-x",
+            "en--US",
+            "en-US-",
+            "i-klingon",
+        ] {
+            assert_eq!(canonical(tag), None, "{tag}");
+        }
+    }
+}
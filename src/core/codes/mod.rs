@@ -0,0 +1,65 @@
+pub(crate) mod country;
+pub(crate) mod language;
+pub(crate) mod locale;
+pub(crate) mod timezone;
+
+use crate::core::errors::ValidationError;
+use std::collections::HashMap;
+
+/// How the ISO code rules (`iso3166_alpha2`, `iso639_language`, `bcp47_locale`,
+/// `iana_timezone`, ...) treat letter case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CodeCase {
+    /// Only the published spelling: `UA`, `uk`, `zh-Hant-TW`, `Europe/Kyiv`. Other spellings of
+    /// a known code fail with `validator.code.case`, whose `expected` param holds the right one.
+    #[default]
+    Canonical,
+    /// Any spelling of a known code: `ua`, `UK`, `ZH-hant-tw`, `europe/kyiv`.
+    Insensitive,
+}
+
+impl CodeCase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CodeCase::Canonical => "canonical",
+            CodeCase::Insensitive => "insensitive",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "canonical" => Some(CodeCase::Canonical),
+            "insensitive" => Some(CodeCase::Insensitive),
+            _ => None,
+        }
+    }
+}
+
+/// Looks `value` up by its `canonical` spelling: unknown codes fail with `key`, known ones
+/// spelled differently with `validator.code.case` unless `case` is insensitive.
+pub(crate) fn check_code<S: AsRef<str>>(
+    value: &str,
+    case: CodeCase,
+    key: &str,
+    canonical: impl Fn(&str) -> Option<S>,
+) -> Result<(), ValidationError> {
+    let Some(expected) = canonical(value) else {
+        return Err(ValidationError::new(key));
+    };
+    let expected = expected.as_ref();
+    if case == CodeCase::Canonical && value != expected {
+        return Err(ValidationError::new_with_params(
+            "validator.code.case",
+            HashMap::from([("expected".into(), expected.to_string())]),
+        ));
+    }
+    Ok(())
+}
+
+/// The non-comment lines of a bundled code table.
+fn table(source: &'static str) -> impl Iterator<Item = &'static str> {
+    source
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
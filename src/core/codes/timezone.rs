@@ -0,0 +1,33 @@
+use crate::core::codes::table;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+/// Lowercased name to the published one, links (`Europe/Kiev`) included.
+static TIMEZONES: Lazy<HashMap<String, &'static str>> = Lazy::new(|| {
+    table(include_str!("../../../data/iana_timezones.txt"))
+        .map(|name| (name.to_ascii_lowercase(), name))
+        .collect()
+});
+
+/// The IANA time zone name (`Europe/Kyiv`) spelled as `name` in any case.
+pub(crate) fn timezone(name: &str) -> Option<&'static str> {
+    TIMEZONES.get(&name.to_ascii_lowercase()).copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_iana_names_and_links() {
+        assert_eq!(timezone("Europe/Kyiv"), Some("Europe/Kyiv"));
+        assert_eq!(timezone("europe/kiev"), Some("Europe/Kiev"));
+        assert_eq!(
+            timezone("America/Argentina/Buenos_Aires"),
+            Some("America/Argentina/Buenos_Aires")
+        );
+        assert_eq!(timezone("UTC"), Some("UTC"));
+        assert_eq!(timezone("Europe/Atlantis"), None);
+        assert_eq!(timezone("+02:00"), None);
+    }
+}
@@ -0,0 +1,274 @@
+use crate::core::temporal::clock::{Clock, SystemClock};
+use crate::core::temporal::moment::Moment;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type Extensions = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+/// Request-scoped data of one validation run: the clock used by time-relative rules,
+/// the caller's locale and typed extensions (tenant, feature flags, ...). Cheap to clone.
+#[derive(Clone)]
+pub struct ValidationContext {
+    clock: Arc<dyn Clock>,
+    locale: Option<String>,
+    extensions: Arc<Extensions>,
+}
+
+impl Default for ValidationContext {
+    fn default() -> Self {
+        Self {
+            clock: Arc::new(SystemClock),
+            locale: None,
+            extensions: Arc::new(HashMap::new()),
+        }
+    }
+}
+
+impl ValidationContext {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    pub fn with_locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// Stores a value by its type; a second value of the same type replaces the first.
+    pub fn with<V: Any + Send + Sync>(mut self, value: V) -> Self {
+        Arc::make_mut(&mut self.extensions).insert(TypeId::of::<V>(), Arc::new(value));
+        self
+    }
+
+    pub fn clock(&self) -> &dyn Clock {
+        self.clock.as_ref()
+    }
+
+    pub fn now(&self) -> Moment {
+        self.clock.now()
+    }
+
+    pub fn locale(&self) -> Option<&str> {
+        self.locale.as_deref()
+    }
+
+    pub fn get<V: Any + Send + Sync>(&self) -> Option<&V> {
+        self.extensions
+            .get(&TypeId::of::<V>())
+            .and_then(|value| value.downcast_ref())
+    }
+
+    pub(crate) fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::errors::ValidationErrors;
+    use crate::core::temporal::clock::FixedClock;
+    use async_trait::async_trait;
+    use valida::prelude::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Tenant(&'static str);
+
+    #[test]
+    fn stores_typed_extensions() {
+        let ctx = ValidationContext::new()
+            .with(Tenant("acme"))
+            .with(42u32)
+            .with(Tenant("globex"));
+
+        assert_eq!(ctx.get::<Tenant>(), Some(&Tenant("globex")));
+        assert_eq!(ctx.get::<u32>(), Some(&42));
+        assert_eq!(ctx.get::<String>(), None);
+    }
+
+    #[test]
+    fn clones_share_extensions_without_leaking_changes() {
+        let base = ValidationContext::new().with(Tenant("acme"));
+        let derived = base.clone().with_locale("uk").with(Tenant("globex"));
+
+        assert_eq!(base.get::<Tenant>(), Some(&Tenant("acme")));
+        assert_eq!(base.locale(), None);
+        assert_eq!(derived.get::<Tenant>(), Some(&Tenant("globex")));
+        assert_eq!(derived.locale(), Some("uk"));
+    }
+
+    #[derive(Debug)]
+    struct Booking {
+        tenant: String,
+        starts_at: String,
+        guest: Guest,
+    }
+
+    #[derive(Debug)]
+    struct Guest {
+        birth_date: String,
+    }
+
+    struct SameTenant;
+
+    #[async_trait]
+    impl IValidatorRuleCustomAsync<String, std::io::Error> for SameTenant {
+        async fn validate(&self, _: &String) -> Result<Option<ValidationError>, std::io::Error> {
+            Ok(None)
+        }
+
+        async fn validate_with_context(
+            &self,
+            value: &String,
+            ctx: &ValidationContext,
+        ) -> Result<Option<ValidationError>, std::io::Error> {
+            match ctx.get::<Tenant>() {
+                Some(Tenant(tenant)) if tenant != value => {
+                    Ok(Some(ValidationError::new("tenant.mismatch")))
+                }
+                _ => Ok(None),
+            }
+        }
+    }
+
+    struct GuestValidator;
+
+    impl IValidate<Guest, std::io::Error> for GuestValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Guest, std::io::Error>,
+        ) -> RulesBuilder<Guest, std::io::Error> {
+            builder
+                .field("birth_date", |g| &g.birth_date)
+                .min_age(18)
+                .build();
+            builder
+        }
+    }
+
+    struct BookingValidator;
+
+    impl IValidate<Booking, std::io::Error> for BookingValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Booking, std::io::Error>,
+        ) -> RulesBuilder<Booking, std::io::Error> {
+            builder
+                .field("tenant", |b| &b.tenant)
+                .custom_async(SameTenant)
+                .build();
+            builder
+                .field("starts_at", |b| &b.starts_at)
+                .in_future()
+                .build();
+            builder
+                .field("guest", |b| &b.guest)
+                .nested(GuestValidator)
+                .build();
+            builder
+        }
+    }
+
+    fn booking() -> Booking {
+        Booking {
+            tenant: "acme".into(),
+            starts_at: "2024-06-01".into(),
+            guest: Guest {
+                birth_date: "2006-05-15".into(),
+            },
+        }
+    }
+
+    fn errors(result: Result<(), ValidatorFailure<std::io::Error>>) -> String {
+        match result {
+            Ok(_) => panic!("Should be invalid"),
+            Err(ValidatorFailure::Invalid(e)) => e.to_json_raw().to_string(),
+            Err(ValidatorFailure::System(e)) => panic!("System error: {e}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn threads_context_into_rules_and_nested_validators() {
+        let may = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        let ctx = ValidationContext::new()
+            .with_clock(FixedClock(may))
+            .with(Tenant("acme"));
+        let result = BookingValidator
+            .validate_with_context(&booking(), &ctx)
+            .await;
+        let json = errors(result);
+
+        assert!(json.contains("validator.min_age"));
+        assert!(!json.contains("validator.in_future"));
+        assert!(!json.contains("tenant.mismatch"));
+
+        let july = Moment::parse("2024-07-01T12:00:00Z").unwrap();
+        let ctx = ValidationContext::new()
+            .with_clock(FixedClock(july))
+            .with(Tenant("globex"));
+        let json = errors(
+            BookingValidator
+                .validate_with_context(&booking(), &ctx)
+                .await,
+        );
+
+        assert!(json.contains("validator.in_future"));
+        assert!(json.contains("tenant.mismatch"));
+    }
+
+    #[tokio::test]
+    async fn runs_hand_written_logic_of_nested_validators() {
+        struct ClosedGuestList;
+
+        #[async_trait]
+        impl IValidate<Guest, std::io::Error> for ClosedGuestList {
+            fn rules(
+                &self,
+                builder: RulesBuilder<Guest, std::io::Error>,
+            ) -> RulesBuilder<Guest, std::io::Error> {
+                builder
+            }
+
+            async fn validate_with_context(
+                &self,
+                _: &Guest,
+                _: &ValidationContext,
+            ) -> Result<(), ValidatorFailure<std::io::Error>> {
+                let mut errors = ValidationErrors::default();
+                errors.add(vec!["list".into()], ValidationError::new("guest.closed"));
+                Err(ValidatorFailure::Invalid(errors))
+            }
+        }
+
+        struct ClosedBookingValidator;
+
+        impl IValidate<Booking, std::io::Error> for ClosedBookingValidator {
+            fn rules(
+                &self,
+                mut builder: RulesBuilder<Booking, std::io::Error>,
+            ) -> RulesBuilder<Booking, std::io::Error> {
+                builder
+                    .field("guest", |b| &b.guest)
+                    .nested(ClosedGuestList)
+                    .build();
+                builder
+            }
+        }
+
+        let json = errors(ClosedBookingValidator.validate(&booking()).await);
+        assert!(json.contains("guest.closed"));
+    }
+
+    #[test]
+    fn uses_injected_clock() {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        let ctx = ValidationContext::new().with_clock(FixedClock(now));
+        assert_eq!(ctx.now(), now);
+    }
+}
@@ -1,11 +1,51 @@
 use crate::core::builder::{FieldRules, RulesBuilder};
-use crate::core::errors::{ValidationError, ValidationErrors};
+use crate::core::context::ValidationContext;
+use crate::core::errors::{RuleFailure, ValidationError, ValidationErrors};
+use crate::core::schema::descriptor::{
+    NestedShape, RuleDescriptor, ValidatorDescriptor, describe_validator,
+};
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::error::Error;
 
 pub trait IValidatorRule<T>: Send + Sync {
     fn validate(&self, value: &T) -> Result<(), ValidationError>;
+
+    /// Called by the builder; override to read the clock, locale or extensions of the run.
+    fn validate_with_context(
+        &self,
+        value: &T,
+        _ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        self.validate(value)
+    }
+
+    /// Called by the builder. Rules that check several requirements at once override this to
+    /// report every unmet one as a child of the field (`RuleFailure::Children`).
+    fn validate_all(&self, value: &T, ctx: &ValidationContext) -> Result<(), RuleFailure> {
+        self.validate_with_context(value, ctx)
+            .map_err(RuleFailure::Field)
+    }
+
+    /// `None` for rules that cannot describe themselves (they are counted as custom).
+    fn describe(&self) -> Option<RuleDescriptor> {
+        None
+    }
+}
+
+/// A rule that also sees the DTO the field belongs to, for checks against sibling fields
+/// (a password must not contain the username, ...). `T` is the DTO, `V` the field.
+pub trait IValidatorRuleDto<T, V>: Send + Sync {
+    fn validate(&self, dto: &T, value: &V, ctx: &ValidationContext) -> Result<(), ValidationError>;
+
+    /// Called by the builder; see `IValidatorRule::validate_all`.
+    fn validate_all(&self, dto: &T, value: &V, ctx: &ValidationContext) -> Result<(), RuleFailure> {
+        self.validate(dto, value, ctx).map_err(RuleFailure::Field)
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        None
+    }
 }
 
 #[async_trait]
@@ -14,6 +54,17 @@ where
     E: Error + Send + Sync + 'static,
 {
     async fn validate(&self, value: &T) -> Result<Option<ValidationError>, E>;
+
+    async fn validate_with_context(
+        &self,
+        value: &T,
+        _ctx: &ValidationContext,
+    ) -> Result<Option<ValidationError>, E>
+    where
+        T: Sync,
+    {
+        self.validate(value).await
+    }
 }
 
 #[async_trait]
@@ -22,6 +73,14 @@ where
     E: Error + Send + Sync + 'static,
 {
     fn validate(&self, value: &T) -> Result<Option<ValidationError>, E>;
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        _ctx: &ValidationContext,
+    ) -> Result<Option<ValidationError>, E> {
+        self.validate(value)
+    }
 }
 
 pub enum ValidatorFailure<E> {
@@ -43,8 +102,43 @@ where
 {
     fn rules(&self, builder: RulesBuilder<T, E>) -> RulesBuilder<T, E>;
 
+    /// `validate_with_context` with the default context.
     async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
-        let builder = RulesBuilder::new();
+        self.validate_with_context(dto, &ValidationContext::default())
+            .await
+    }
+
+    /// Applies the sanitizers declared in `rules` (`FieldBuilder::sanitize`) to `dto`, then
+    /// those of the nested validators declared with `nested_mut`.
+    fn sanitize(&self, dto: &mut T) {
+        self.rules(RulesBuilder::new()).sanitize(dto);
+    }
+
+    /// `sanitize_and_validate_with_context` with the default context.
+    async fn sanitize_and_validate(&self, dto: &mut T) -> Result<(), ValidatorFailure<E>> {
+        self.sanitize_and_validate_with_context(dto, &ValidationContext::default())
+            .await
+    }
+
+    /// `sanitize`, then `validate_with_context`: the DTO is left sanitized whatever the outcome.
+    async fn sanitize_and_validate_with_context(
+        &self,
+        dto: &mut T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
+        self.sanitize(dto);
+        self.validate_with_context(dto, ctx).await
+    }
+
+    /// `validate` and nested validation both call this. The context is passed to `rules`
+    /// through the builder and on to every rule and nested validator. Validators with their
+    /// own logic override this, not `validate`, so that it also runs when nested.
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
+        let builder = RulesBuilder::new_with_context(ctx.clone());
         match self.rules(builder).validate(dto).await {
             Ok(errors) => {
                 if errors.is_empty() {
@@ -56,6 +150,19 @@ where
             Err(err) => Err(ValidatorFailure::System(err)),
         }
     }
+
+    fn describe(&self) -> ValidatorDescriptor {
+        describe_validator(
+            std::any::type_name::<Self>(),
+            std::any::type_name::<T>(),
+            || self.rules(RulesBuilder::new()).describe(),
+        )
+    }
+
+    /// How a nested validator is applied to its field; overridden by the nested wrappers.
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::One
+    }
 }
 
 #[async_trait]
@@ -1,3 +1,4 @@
+use crate::core::path_case::PathCase;
 use serde::Serialize;
 use serde_json::{Map, Value};
 use std::collections::HashMap;
@@ -26,6 +27,29 @@ impl ValidationError {
     }
 }
 
+/// What a rule reports for a field, see `IValidatorRule::validate_all`.
+#[derive(Debug, Clone)]
+pub enum RuleFailure {
+    /// An error of the field itself.
+    Field(ValidationError),
+    /// Errors below the field, one per child name: `password.too_short`.
+    Children(Vec<(String, ValidationError)>),
+}
+
+impl RuleFailure {
+    /// The error to report where a single one fits: the field error or the first child's.
+    pub fn into_first(self) -> ValidationError {
+        match self {
+            RuleFailure::Field(error) => error,
+            RuleFailure::Children(children) => children
+                .into_iter()
+                .next()
+                .map(|(_, error)| error)
+                .expect("RuleFailure::Children without errors"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum ValidationNode {
@@ -89,6 +113,31 @@ impl ValidationErrors {
         self.errors.is_empty()
     }
 
+    /// Returns a copy of the error tree with every path segment converted to `case`.
+    /// Chain it before any renderer: `errors.with_path_case(PathCase::Camel).to_json_raw()`.
+    pub fn with_path_case(&self, case: PathCase) -> ValidationErrors {
+        fn convert(
+            node: &HashMap<String, ValidationNode>,
+            case: PathCase,
+        ) -> HashMap<String, ValidationNode> {
+            node.iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        ValidationNode::Leaf(error) => ValidationNode::Leaf(error.clone()),
+                        ValidationNode::Branch(children) => {
+                            ValidationNode::Branch(convert(children, case))
+                        }
+                    };
+                    (case.apply(key), value)
+                })
+                .collect()
+        }
+
+        ValidationErrors {
+            errors: convert(&self.errors, case),
+        }
+    }
+
     pub fn has_error_for_field(&self, field: &str) -> bool {
         self.errors.contains_key(field)
     }
@@ -471,6 +520,25 @@ mod tests {
         assert!(output.contains("    name: validator.min_length"));
     }
 
+    #[test]
+    fn test_with_path_case_renames_nested_segments() {
+        let mut errors = ValidationErrors::default();
+        errors.add(
+            vec!["home_address".into(), "0".into(), "zip_code".into()],
+            ValidationError::new("validator.required"),
+        );
+
+        let json = errors.with_path_case(PathCase::Camel).to_json_dot_raw();
+        let expected = json!({
+            "homeAddress.0.zipCode": {
+                "key": "validator.required",
+                "params": {}
+            }
+        });
+
+        assert_eq!(json, expected);
+    }
+
     #[test]
     fn test_display_trait_matches_pretty_print() {
         let mut errors = ValidationErrors::default();
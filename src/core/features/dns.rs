@@ -0,0 +1,46 @@
+use crate::core::net::dns::DnsResolver;
+use async_trait::async_trait;
+use hickory_resolver::ResolveError;
+use hickory_resolver::TokioResolver;
+
+/// `DnsResolver` backed by `hickory-resolver`, configured from the system (`/etc/resolv.conf`).
+pub struct HickoryResolver {
+    resolver: TokioResolver,
+}
+
+impl HickoryResolver {
+    pub fn from_system_conf() -> Result<Self, ResolveError> {
+        Ok(Self {
+            resolver: TokioResolver::builder_tokio()?.build(),
+        })
+    }
+
+    pub fn new(resolver: TokioResolver) -> Self {
+        Self { resolver }
+    }
+}
+
+fn is_empty_answer(err: &ResolveError) -> bool {
+    err.is_no_records_found() || err.is_nx_domain()
+}
+
+#[async_trait]
+impl DnsResolver for HickoryResolver {
+    type Error = ResolveError;
+
+    async fn mx_hosts(&self, domain: &str) -> Result<Vec<String>, ResolveError> {
+        match self.resolver.mx_lookup(domain).await {
+            Ok(lookup) => Ok(lookup.iter().map(|mx| mx.exchange().to_ascii()).collect()),
+            Err(err) if is_empty_answer(&err) => Ok(vec![]),
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn has_address(&self, domain: &str) -> Result<bool, ResolveError> {
+        match self.resolver.lookup_ip(domain).await {
+            Ok(lookup) => Ok(lookup.iter().next().is_some()),
+            Err(err) if is_empty_answer(&err) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
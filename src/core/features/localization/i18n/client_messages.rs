@@ -0,0 +1,21 @@
+use rust_i18n::t;
+use serde_json::{Map, Value};
+
+/// Raw message templates (`%{param}` placeholders kept) per locale; keys without a
+/// translation are skipped.
+pub(crate) fn client_messages(keys: &[String], locales: &[&str]) -> Value {
+    let mut messages = Map::new();
+
+    for &locale in locales {
+        let mut templates = Map::new();
+        for key in keys {
+            let text = t!(key.as_str(), locale = locale);
+            if text != key.as_str() {
+                templates.insert(key.clone(), Value::String(text.into()));
+            }
+        }
+        messages.insert(locale.to_string(), Value::Object(templates));
+    }
+
+    Value::Object(messages)
+}
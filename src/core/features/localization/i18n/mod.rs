@@ -1,3 +1,4 @@
+pub(crate) mod client_messages;
 pub(crate) mod pretty_print_i18n;
 pub(crate) mod to_json_dot_i18n;
 pub(crate) mod to_json_form_i18n;
@@ -1 +1,8 @@
+#[cfg(feature = "dns")]
+pub mod dns;
+#[cfg(feature = "i18n-localization")]
 pub mod localization;
+#[cfg(feature = "utoipa")]
+pub mod openapi;
+#[cfg(feature = "phone")]
+pub mod phone;
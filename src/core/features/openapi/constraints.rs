@@ -0,0 +1,328 @@
+use crate::core::contract::IValidate;
+use crate::core::schema::descriptor::ValidatorDescriptor;
+use crate::core::schema::json_schema::{field_keywords, is_required};
+use crate::core::valida_error::ValidaError;
+use serde_json::{Map, Value};
+use std::error::Error;
+use std::marker::PhantomData;
+use utoipa::openapi::schema::Schema;
+use utoipa::openapi::{OpenApi, RefOr};
+use utoipa::{Modify, ToSchema};
+
+/// `T::schema()` with the constraints of `validator` merged in.
+pub fn constrained_schema<T, E>(
+    validator: &impl IValidate<T, E>,
+) -> Result<RefOr<Schema>, ValidaError>
+where
+    T: ToSchema + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    apply_constraints(T::schema(), &validator.describe())
+}
+
+/// Merges `minLength`, `maximum`, `pattern`, `enum`, `required`, ... into an object schema.
+///
+/// OpenAPI 3.1 schemas are JSON Schema 2020-12, so the keywords are the same ones
+/// [`ValidatorDescriptor::to_json_schema`] emits; they are merged in JSON form, and a
+/// schema that does not survive the round trip is reported as `ValidaError::SerdeError`.
+pub fn apply_constraints(
+    schema: RefOr<Schema>,
+    descriptor: &ValidatorDescriptor,
+) -> Result<RefOr<Schema>, ValidaError> {
+    let mut json = serde_json::to_value(&schema)?;
+    merge_constraints(&mut json, descriptor);
+    Ok(serde_json::from_value(json)?)
+}
+
+/// Applies the validator to the component registered for its DTO (`T::name()`, which
+/// follows `#[schema(as = ...)]`), and nested validators to the components named after
+/// their DTOs.
+///
+/// Returns the validators whose component is not in the document (inline or renamed
+/// nested DTOs, schemas registered under another name).
+pub fn apply_validator<T, E>(
+    openapi: &mut OpenApi,
+    validator: &impl IValidate<T, E>,
+) -> Result<Vec<String>, ValidaError>
+where
+    T: ToSchema + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    apply_to_component(openapi, &T::name(), &validator.describe())
+}
+
+/// [`apply_validator`] for a descriptor, matched to the component named after its DTO.
+pub fn apply_to_openapi(
+    openapi: &mut OpenApi,
+    descriptor: &ValidatorDescriptor,
+) -> Result<Vec<String>, ValidaError> {
+    apply_to_component(openapi, &descriptor.target, descriptor)
+}
+
+/// [`apply_validator`] for a descriptor and an explicit component name.
+pub fn apply_to_component(
+    openapi: &mut OpenApi,
+    component: &str,
+    descriptor: &ValidatorDescriptor,
+) -> Result<Vec<String>, ValidaError> {
+    let mut unmatched = vec![];
+    let Some(components) = openapi.components.as_mut() else {
+        unmatched.push(component.to_string());
+        return Ok(unmatched);
+    };
+
+    let mut pending = vec![(component.to_string(), descriptor)];
+    while let Some((name, descriptor)) = pending.pop() {
+        if descriptor.recursive {
+            continue;
+        }
+        match components.schemas.get(&name) {
+            Some(schema) => {
+                let schema = apply_constraints(schema.clone(), descriptor)?;
+                components.schemas.insert(name, schema);
+            }
+            None => unmatched.push(format!("{} ({name})", descriptor.name)),
+        }
+
+        pending.extend(
+            descriptor
+                .fields
+                .values()
+                .filter_map(|field| field.nested.as_ref())
+                .map(|nested| (nested.validator.target.clone(), &nested.validator)),
+        );
+    }
+    Ok(unmatched)
+}
+
+/// Applies the descriptor via [`apply_to_openapi`]; validators generated by
+/// `#[Validatable]` implement `Modify` themselves (see [`crate::openapi_modifier`]).
+///
+/// `Modify` cannot report errors: call [`apply_to_openapi`] to see what was not applied.
+impl Modify for ValidatorDescriptor {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let _ = apply_to_openapi(openapi, self);
+    }
+}
+
+/// Component name of `T` for [`crate::openapi_modifier`]: `T::name()` when `T` has a
+/// schema, the DTO name otherwise (autoref specialization, resolved at the macro call site).
+#[doc(hidden)]
+pub struct ComponentOf<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait RegisteredComponent {
+    fn component(&self, _: &ValidatorDescriptor) -> String;
+}
+
+impl<T: ToSchema> RegisteredComponent for &&ComponentOf<T> {
+    fn component(&self, _: &ValidatorDescriptor) -> String {
+        T::name().into_owned()
+    }
+}
+
+#[doc(hidden)]
+pub trait DefaultComponent {
+    fn component(&self, descriptor: &ValidatorDescriptor) -> String;
+}
+
+impl<T> DefaultComponent for &ComponentOf<T> {
+    fn component(&self, descriptor: &ValidatorDescriptor) -> String {
+        descriptor.target.clone()
+    }
+}
+
+fn merge_constraints(schema: &mut Value, descriptor: &ValidatorDescriptor) {
+    let Some(object) = schema.as_object_mut() else {
+        return;
+    };
+
+    let mut required = vec![];
+    if let Some(Value::Object(properties)) = object.get_mut("properties") {
+        for (name, field) in &descriptor.fields {
+            let Some(property) = properties.get_mut(name) else {
+                continue;
+            };
+            if is_required(field) {
+                required.push(name.clone());
+            }
+            if let Value::Object(property) = property {
+                merge_keywords(property, field_keywords(field));
+            }
+        }
+    }
+
+    if required.is_empty() {
+        return;
+    }
+    let list = object
+        .entry("required")
+        .or_insert_with(|| Value::Array(vec![]));
+    if let Value::Array(list) = list {
+        for name in required {
+            if !list.iter().any(|v| v == name.as_str()) {
+                list.push(Value::String(name));
+            }
+        }
+    }
+}
+
+fn merge_keywords(target: &mut Map<String, Value>, keywords: Map<String, Value>) {
+    for (keyword, value) in keywords {
+        match (target.get_mut(&keyword), value) {
+            (Some(Value::Object(existing)), Value::Object(value)) => {
+                merge_keywords(existing, value)
+            }
+            (Some(Value::Array(existing)), Value::Array(value)) if keyword == "allOf" => {
+                existing.extend(value)
+            }
+            (_, value) => {
+                target.insert(keyword, value);
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use utoipa::OpenApi;
+    use valida::prelude::*;
+
+    #[Validatable(std::io::Error)]
+    #[derive(utoipa::ToSchema)]
+    pub struct Address {
+        #[validate(not_empty, max_length(40))]
+        pub city: String,
+    }
+
+    #[Validatable(std::io::Error)]
+    #[derive(utoipa::ToSchema)]
+    pub struct Account {
+        #[validate(email, max_length(64))]
+        pub email: String,
+
+        #[validate(min_length(3))]
+        pub nickname: Option<String>,
+
+        #[validate(range(18, 120))]
+        pub age: u8,
+
+        #[validate(min_items(1))]
+        pub tags: Vec<String>,
+
+        #[validate(nested(AddressValidator))]
+        pub address: Address,
+    }
+
+    #[Validatable(std::io::Error)]
+    #[derive(utoipa::ToSchema)]
+    #[schema(as = Profile)]
+    pub struct ProfileDto {
+        #[validate(min_length(2))]
+        pub name: String,
+
+        #[validate(nested(AddressValidator))]
+        pub address: Address,
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Account, Address)))]
+    struct ApiDoc;
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(ProfileDto)), modifiers(&ProfileDtoValidator))]
+    struct RenamedApiDoc;
+
+    #[derive(OpenApi)]
+    #[openapi(
+        components(schemas(Account, Address)),
+        modifiers(&AccountValidator)
+    )]
+    struct ModifiedApiDoc;
+
+    fn to_json(schema: &RefOr<Schema>) -> Value {
+        serde_json::to_value(schema).unwrap()
+    }
+
+    #[test]
+    fn merges_constraints_into_schema() {
+        let schema = to_json(&constrained_schema(&AccountValidator).unwrap());
+        let props = &schema["properties"];
+
+        assert_eq!(props["email"]["format"], "email");
+        assert_eq!(props["email"]["maxLength"], 64);
+        assert_eq!(props["nickname"]["minLength"], 3);
+        assert_eq!(props["age"]["minimum"], 18);
+        assert_eq!(props["age"]["maximum"], 120);
+        assert_eq!(props["tags"]["minItems"], 1);
+        assert_eq!(props["tags"]["items"]["type"], "string");
+    }
+
+    #[test]
+    fn keeps_schema_without_matching_fields() {
+        let original = <Account as utoipa::PartialSchema>::schema();
+        let descriptor = ValidatorDescriptor::default();
+        assert_eq!(
+            to_json(&apply_constraints(original.clone(), &descriptor).unwrap()),
+            to_json(&original)
+        );
+    }
+
+    #[test]
+    fn works_as_openapi_modifier() {
+        let doc = serde_json::to_value(ModifiedApiDoc::openapi()).unwrap();
+        assert_eq!(
+            doc["components"]["schemas"]["Account"]["properties"]["age"]["maximum"],
+            120
+        );
+    }
+
+    #[test]
+    fn modifies_openapi_components() {
+        let mut openapi = ApiDoc::openapi();
+        AccountValidator.describe().modify(&mut openapi);
+
+        let doc = serde_json::to_value(&openapi).unwrap();
+        let schemas = &doc["components"]["schemas"];
+
+        assert_eq!(schemas["Account"]["properties"]["email"]["maxLength"], 64);
+        assert_eq!(
+            schemas["Address"]["properties"]["city"],
+            json!({"type": "string", "minLength": 1, "maxLength": 40})
+        );
+        assert!(
+            schemas["Address"]["required"]
+                .as_array()
+                .unwrap()
+                .contains(&json!("city"))
+        );
+    }
+
+    #[test]
+    fn matches_renamed_components() {
+        let doc = serde_json::to_value(RenamedApiDoc::openapi()).unwrap();
+        let schemas = &doc["components"]["schemas"];
+
+        assert_eq!(schemas["Profile"]["properties"]["name"]["minLength"], 2);
+        assert!(schemas.get("ProfileDto").is_none());
+    }
+
+    #[test]
+    fn reports_unmatched_components() {
+        let mut openapi = ApiDoc::openapi();
+        let unmatched = apply_validator(&mut openapi, &ProfileDtoValidator).unwrap();
+        assert_eq!(unmatched, ["ProfileDtoValidator (Profile)"]);
+
+        let doc = serde_json::to_value(&openapi).unwrap();
+        assert_eq!(
+            doc["components"]["schemas"]["Address"]["properties"]["city"]["maxLength"],
+            40
+        );
+
+        let mut openapi = ApiDoc::openapi();
+        let unmatched = apply_to_openapi(&mut openapi, &AccountValidator.describe()).unwrap();
+        assert!(unmatched.is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::core::sanitize::Sanitize;
+use crate::core::sanitize::sanitizer::StrAsMut;
+use phonenumber::country::Id;
+use phonenumber::metadata::DATABASE;
+use phonenumber::{Mode, PhoneNumber, Type};
+
+/// Kind of line a number belongs to, from the bundled libphonenumber metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneType {
+    FixedLine,
+    Mobile,
+    TollFree,
+    PremiumRate,
+    SharedCost,
+    PersonalNumber,
+    Voip,
+    Pager,
+    Uan,
+    Voicemail,
+}
+
+impl PhoneType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PhoneType::FixedLine => "fixed_line",
+            PhoneType::Mobile => "mobile",
+            PhoneType::TollFree => "toll_free",
+            PhoneType::PremiumRate => "premium_rate",
+            PhoneType::SharedCost => "shared_cost",
+            PhoneType::PersonalNumber => "personal_number",
+            PhoneType::Voip => "voip",
+            PhoneType::Pager => "pager",
+            PhoneType::Uan => "uan",
+            PhoneType::Voicemail => "voicemail",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        [
+            PhoneType::FixedLine,
+            PhoneType::Mobile,
+            PhoneType::TollFree,
+            PhoneType::PremiumRate,
+            PhoneType::SharedCost,
+            PhoneType::PersonalNumber,
+            PhoneType::Voip,
+            PhoneType::Pager,
+            PhoneType::Uan,
+            PhoneType::Voicemail,
+        ]
+        .into_iter()
+        .find(|t| t.as_str() == name)
+    }
+
+    /// Some plans (US, CA) cannot tell fixed lines from mobiles: such numbers match both.
+    fn matches(&self, number_type: Type) -> bool {
+        matches!(
+            (self, number_type),
+            (
+                PhoneType::FixedLine | PhoneType::Mobile,
+                Type::FixedLineOrMobile
+            ) | (PhoneType::FixedLine, Type::FixedLine)
+                | (PhoneType::Mobile, Type::Mobile)
+                | (PhoneType::TollFree, Type::TollFree)
+                | (PhoneType::PremiumRate, Type::PremiumRate)
+                | (PhoneType::SharedCost, Type::SharedCost)
+                | (PhoneType::PersonalNumber, Type::PersonalNumber)
+                | (PhoneType::Voip, Type::Voip)
+                | (PhoneType::Pager, Type::Pager)
+                | (PhoneType::Uan, Type::Uan)
+                | (PhoneType::Voicemail, Type::Voicemail)
+        )
+    }
+}
+
+/// Policy for `phone_with`. Without a region only international numbers (`+380 ...`) parse;
+/// with one, national formats of that region (`050 123 4567`) are accepted too.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PhoneOptions {
+    pub(crate) region: Option<Id>,
+    pub(crate) region_only: bool,
+    pub(crate) allowed_types: Vec<PhoneType>,
+}
+
+impl PhoneOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Default region as an ISO 3166-1 alpha-2 code (`"UA"`). Panics on unknown codes.
+    pub fn region(mut self, region: &str) -> Self {
+        let id = region
+            .to_ascii_uppercase()
+            .parse()
+            .unwrap_or_else(|_| panic!("unknown phone region `{region}`"));
+        self.region = Some(id);
+        self
+    }
+
+    /// Rejects international numbers of other regions.
+    pub fn region_only(mut self, region_only: bool) -> Self {
+        self.region_only = region_only;
+        self
+    }
+
+    pub fn allow_types(mut self, types: &[PhoneType]) -> Self {
+        self.allowed_types = types.to_vec();
+        self
+    }
+
+    pub(crate) fn parse(&self, value: &str) -> Result<PhoneNumber, phonenumber::ParseError> {
+        phonenumber::parse(self.region, value.trim())
+    }
+
+    pub(crate) fn region_code(&self) -> Option<&str> {
+        self.region.as_ref().map(|id| id.as_ref())
+    }
+
+    /// `Ok` when the type of `number` is one of `allowed_types` (any type when empty),
+    /// otherwise the name of its actual type.
+    pub(crate) fn type_allowed(&self, number: &PhoneNumber) -> Result<(), &'static str> {
+        let number_type = number.number_type(&DATABASE);
+        if self.allowed_types.is_empty()
+            || self.allowed_types.iter().any(|t| t.matches(number_type))
+        {
+            return Ok(());
+        }
+        Err(type_name(number_type))
+    }
+
+    /// The E.164 form (`+380501234567`) of a valid number, e.g. to store it normalized.
+    pub fn to_e164(&self, value: &str) -> Option<String> {
+        let number = self.parse(value).ok().filter(PhoneNumber::is_valid)?;
+        Some(number.format().mode(Mode::E164).to_string())
+    }
+}
+
+/// Rewrites valid numbers to E.164: `.sanitize(|u| &mut u.phone, options.clone())`. Values that
+/// do not parse are left alone for `phone_with` to report.
+impl<V: StrAsMut> Sanitize<V> for PhoneOptions {
+    fn sanitize(&self, value: &mut V) {
+        if let Some(text) = value.as_string_mut()
+            && let Some(e164) = self.to_e164(text)
+        {
+            *text = e164;
+        }
+    }
+}
+
+fn type_name(number_type: Type) -> &'static str {
+    match number_type {
+        Type::FixedLine => "fixed_line",
+        Type::Mobile => "mobile",
+        Type::FixedLineOrMobile => "fixed_line_or_mobile",
+        Type::TollFree => "toll_free",
+        Type::PremiumRate => "premium_rate",
+        Type::SharedCost => "shared_cost",
+        Type::PersonalNumber => "personal_number",
+        Type::Voip => "voip",
+        Type::Pager => "pager",
+        Type::Uan => "uan",
+        Type::Emergency => "emergency",
+        Type::Voicemail => "voicemail",
+        Type::ShortCode => "short_code",
+        Type::StandardRate => "standard_rate",
+        Type::Carrier => "carrier",
+        Type::NoInternational => "no_international",
+        Type::Unknown => "unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_to_e164() {
+        let ua = PhoneOptions::new().region("ua");
+        assert_eq!(ua.to_e164("050 123 4567").as_deref(), Some("+380501234567"));
+        assert_eq!(
+            ua.to_e164("+49 151 23456789").as_deref(),
+            Some("+4915123456789")
+        );
+        assert_eq!(PhoneOptions::new().to_e164("050 123 4567"), None);
+        assert_eq!(ua.to_e164("not a phone"), None);
+    }
+
+    #[test]
+    fn sanitizes_to_e164() {
+        let ua = PhoneOptions::new().region("UA");
+        let mut phone = Some(" 050 123 4567 ".to_string());
+        ua.sanitize(&mut phone);
+        assert_eq!(phone.as_deref(), Some("+380501234567"));
+
+        let mut invalid = "050 123".to_string();
+        ua.sanitize(&mut invalid);
+        assert_eq!(invalid, "050 123");
+    }
+
+    #[test]
+    fn parses_type_names() {
+        assert_eq!(PhoneType::parse("mobile"), Some(PhoneType::Mobile));
+        assert_eq!(PhoneType::parse("fixed_line"), Some(PhoneType::FixedLine));
+        assert_eq!(PhoneType::parse("cell"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "unknown phone region `XX`")]
+    fn rejects_unknown_regions() {
+        let _ = PhoneOptions::new().region("XX");
+    }
+}
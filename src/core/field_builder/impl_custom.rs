@@ -1,4 +1,4 @@
-use crate::core::contract::{IValidatorRuleCustom, IValidatorRuleCustomAsync};
+use crate::core::contract::{IValidatorRuleCustom, IValidatorRuleCustomAsync, IValidatorRuleDto};
 use crate::core::field_builder::main::FieldBuilder;
 use std::error::Error;
 
@@ -30,6 +30,20 @@ where
     }
 }
 
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    /// A rule that receives the whole DTO next to the field value.
+    pub fn custom_dto<R>(mut self, rule: R) -> Self
+    where
+        R: IValidatorRuleDto<T, V> + 'static,
+    {
+        self.rules.rules_dto.push(Box::new(rule));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
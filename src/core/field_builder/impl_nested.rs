@@ -1,10 +1,14 @@
 use crate::core::contract::IValidate;
 use crate::core::field_builder::main::FieldBuilder;
+use crate::core::formats::document_options::DocumentFormat;
 use crate::core::nested_wrapper::{
-    NestedArcOptionValidatorWrapper, NestedArcValidatorWrapper, NestedMapValidatorWrapper,
-    NestedOptionValidatorWrapper, NestedValidatorWrapper, NestedVecValidatorWrapper,
+    NestedArcOptionValidatorWrapper, NestedArcValidatorWrapper, NestedDocumentValidatorWrapper,
+    NestedMapValidatorWrapper, NestedOptionValidatorWrapper, NestedValidatorWrapper,
+    NestedVecValidatorWrapper,
 };
-use crate::core::rules::nested::NestedField;
+use crate::core::rules::nested::{NestedField, NestedMutField};
+use crate::core::rules::str_ref::StrAsRef;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::error::Error;
 use std::hash::Hash;
@@ -24,6 +28,7 @@ where
         let wrapper = Box::new(NestedValidatorWrapper::<T, V, E> {
             field_name: self.rules.field_name,
             accessor: self.rules.accessor.clone(),
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -49,6 +54,7 @@ where
         let wrapper = Box::new(NestedVecValidatorWrapper::<T, V, E> {
             field_name: self.rules.field_name,
             accessor: self.rules.accessor.clone(),
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -75,6 +81,7 @@ where
         let wrapper = Box::new(NestedMapValidatorWrapper::<T, K, V, E> {
             field_name: self.rules.field_name,
             accessor: self.rules.accessor.clone(),
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -102,6 +109,7 @@ where
         let wrapper = Box::new(NestedOptionValidatorWrapper::<T, V, E> {
             field_name: self.rules.field_name,
             accessor,
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -127,6 +135,7 @@ where
         let wrapper = Box::new(NestedArcValidatorWrapper::<T, V, E> {
             field_name: self.rules.field_name,
             accessor: self.rules.accessor.clone(),
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -152,6 +161,7 @@ where
         let wrapper = Box::new(NestedArcOptionValidatorWrapper::<T, V, E> {
             field_name: self.rules.field_name,
             accessor: self.rules.accessor.clone(),
+            accessor_mut: None,
             inner: Box::new(validator),
             _phantom: PhantomData,
         });
@@ -163,3 +173,229 @@ where
         self
     }
 }
+
+impl<'a, T, V, E> NestedMutField<T, V, V, E> for FieldBuilder<'a, T, V, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut V + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, V, E> NestedMutField<T, Vec<V>, V, E> for FieldBuilder<'a, T, Vec<V>, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut Vec<V> + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedVecValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, K, V, E> NestedMutField<T, HashMap<K, V>, V, E>
+    for FieldBuilder<'a, T, HashMap<K, V>, E>
+where
+    T: Send + Sync + 'static,
+    K: Eq + Hash + ToString + Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut HashMap<K, V> + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedMapValidatorWrapper::<T, K, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, V, E> NestedMutField<T, Option<V>, V, E> for FieldBuilder<'a, T, Option<V>, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut Option<V> + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedOptionValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, V, E> NestedMutField<T, Arc<V>, V, E> for FieldBuilder<'a, T, Arc<V>, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut Arc<V> + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedArcValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, V, E> NestedMutField<T, Arc<Option<V>>, V, E> for FieldBuilder<'a, T, Arc<Option<V>>, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut Arc<Option<V>> + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static,
+    {
+        let wrapper = Box::new(NestedArcOptionValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            accessor_mut: Some(Arc::new(accessor)),
+            inner: Box::new(validator),
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    T: Send + Sync + 'static,
+    V: StrAsRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    /// Validates the JSON document stored in the field with a `ValueValidator` or
+    /// `JsonSchemaValidator`. Pair with `json()`: text that does not parse is skipped here.
+    pub fn json_nested<TValidator>(self, validator: TValidator) -> Self
+    where
+        TValidator: IValidate<Value, E> + 'static,
+    {
+        self.nested_document(DocumentFormat::Json, Box::new(validator))
+    }
+
+    /// Same for a YAML document; pair with `yaml()`.
+    pub fn yaml_nested<TValidator>(self, validator: TValidator) -> Self
+    where
+        TValidator: IValidate<Value, E> + 'static,
+    {
+        self.nested_document(DocumentFormat::Yaml, Box::new(validator))
+    }
+
+    fn nested_document(
+        self,
+        format: DocumentFormat,
+        validator: Box<dyn IValidate<Value, E>>,
+    ) -> Self {
+        let wrapper = Box::new(NestedDocumentValidatorWrapper::<T, V, E> {
+            field_name: self.rules.field_name,
+            accessor: self.rules.accessor.clone(),
+            inner: validator,
+            format,
+            _phantom: PhantomData,
+        });
+
+        self.rules_builder
+            .nested
+            .insert(self.rules.field_name.to_string(), wrapper);
+
+        self
+    }
+}
@@ -0,0 +1,90 @@
+use crate::core::field_builder::main::FieldBuilder;
+use crate::core::net::network::IpNetwork;
+use crate::core::rules::net::ip::{Ip, IpVersion};
+use crate::core::rules::net::ip_in_cidr::IpInCidr;
+use crate::core::rules::net::port::Port;
+use crate::core::rules::net::public_ip::PublicIp;
+use crate::core::rules::net::socket_addr::SocketAddress;
+use crate::core::rules::net_ref::{IpRef, PortRef, SocketAddrRef};
+use std::error::Error;
+
+fn parse_networks(networks: &[&str]) -> Vec<IpNetwork> {
+    networks
+        .iter()
+        .map(|n| IpNetwork::parse(n).unwrap_or_else(|e| panic!("{e}")))
+        .collect()
+}
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: IpRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn ip(mut self) -> Self {
+        self.rules.rules.push(Box::new(Ip {
+            version: IpVersion::Any,
+        }));
+        self
+    }
+
+    pub fn ipv4(mut self) -> Self {
+        self.rules.rules.push(Box::new(Ip {
+            version: IpVersion::V4,
+        }));
+        self
+    }
+
+    pub fn ipv6(mut self) -> Self {
+        self.rules.rules.push(Box::new(Ip {
+            version: IpVersion::V6,
+        }));
+        self
+    }
+
+    pub fn public_ip(mut self) -> Self {
+        self.rules.rules.push(Box::new(PublicIp {}));
+        self
+    }
+
+    /// # Panics
+    /// If one of `networks` is not a valid CIDR block.
+    pub fn ip_in_cidr(mut self, networks: &[&str]) -> Self {
+        self.rules.rules.push(Box::new(IpInCidr {
+            networks: parse_networks(networks),
+            deny: false,
+        }));
+        self
+    }
+
+    /// # Panics
+    /// If one of `networks` is not a valid CIDR block.
+    pub fn ip_not_in_cidr(mut self, networks: &[&str]) -> Self {
+        self.rules.rules.push(Box::new(IpInCidr {
+            networks: parse_networks(networks),
+            deny: true,
+        }));
+        self
+    }
+}
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: PortRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn port(mut self) -> Self {
+        self.rules.rules.push(Box::new(Port {}));
+        self
+    }
+}
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: SocketAddrRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn socket_addr(mut self) -> Self {
+        self.rules.rules.push(Box::new(SocketAddress {}));
+        self
+    }
+}
@@ -1,12 +1,17 @@
 use crate::core::field_builder::main::FieldBuilder;
+use crate::core::rules::number::Number;
+use crate::core::rules::numeric::finite::Finite;
 use crate::core::rules::numeric::greater_than::GreaterThan;
 use crate::core::rules::numeric::less_than::LessThan;
+use crate::core::rules::numeric::max_decimal_places::MaxDecimalPlaces;
 use crate::core::rules::numeric::max_value::MaxValue;
 use crate::core::rules::numeric::min_value::MinValue;
+use crate::core::rules::numeric::multiple_of::MultipleOf;
 use crate::core::rules::numeric::negative::Negative;
 use crate::core::rules::numeric::negative_or_zero::NegativeOrZero;
 use crate::core::rules::numeric::positive::Positive;
 use crate::core::rules::numeric::positive_or_zero::PositiveOrZero;
+use crate::core::rules::numeric::precision::Precision;
 use crate::core::rules::numeric::range::Range;
 use crate::core::rules::value_ref::ValueRef;
 use std::error::Error;
@@ -79,3 +84,36 @@ where
         self
     }
 }
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: ValueRef,
+    V::Target: Number + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    /// Rejects NaN and infinities, which pass `range`, `min_value` and the other comparisons.
+    pub fn finite(mut self) -> Self {
+        self.rules.rules.push(Box::new(Finite {}));
+        self
+    }
+
+    pub fn max_decimal_places(mut self, max: usize) -> Self {
+        self.rules.rules.push(Box::new(MaxDecimalPlaces { max }));
+        self
+    }
+
+    /// `multiple_of(0.05)` for prices in 5-cent steps; float quotients are compared with a
+    /// tolerance of a few ULPs.
+    pub fn multiple_of(mut self, step: V::Target) -> Self {
+        self.rules.rules.push(Box::new(MultipleOf { step }));
+        self
+    }
+
+    /// Fits SQL `DECIMAL(precision, scale)`, e.g. `precision(10, 2)`.
+    pub fn precision(mut self, precision: usize, scale: usize) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(Precision { precision, scale }));
+        self
+    }
+}
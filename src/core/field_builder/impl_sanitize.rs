@@ -0,0 +1,27 @@
+use crate::core::field_builder::main::FieldBuilder;
+use crate::core::sanitize::Sanitize;
+use std::error::Error;
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    T: Send + Sync + 'static,
+    V: Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    /// Rewrites the field through the mutable `accessor` before validation:
+    /// `.sanitize(|u| &mut u.email, [Sanitizer::Trim, Sanitizer::Lowercase])`.
+    /// Runs only from `IValidate::sanitize` / `sanitize_and_validate`, which take `&mut T`.
+    pub fn sanitize<S>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut V + Send + Sync + 'static,
+        sanitizer: S,
+    ) -> Self
+    where
+        S: Sanitize<V> + 'static,
+    {
+        self.rules_builder
+            .sanitizers
+            .push(Box::new(move |dto| sanitizer.sanitize(accessor(dto))));
+        self
+    }
+}
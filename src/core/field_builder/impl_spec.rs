@@ -0,0 +1,15 @@
+use crate::core::field_builder::main::FieldBuilder;
+use crate::core::spec::field_spec::FieldSpec;
+use serde::Serialize;
+use std::error::Error;
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: Serialize + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn spec(mut self, spec: &FieldSpec) -> Self {
+        self.rules.rules.push(Box::new(spec.clone()));
+        self
+    }
+}
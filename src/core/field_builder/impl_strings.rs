@@ -1,25 +1,74 @@
+use crate::core::codes::CodeCase;
+#[cfg(feature = "phone")]
+use crate::core::features::phone::PhoneOptions;
 use crate::core::field_builder::main::FieldBuilder;
+use crate::core::finance::card::CardBrand;
+use crate::core::formats::base64_options::Base64Options;
+use crate::core::formats::document_options::DocumentOptions;
+use crate::core::identifiers::national::{BUILT_IN, NationalIdRegistry};
+use crate::core::net::email_options::EmailOptions;
+use crate::core::net::url_options::UrlOptions;
+use crate::core::password::policy::PasswordPolicy;
+use crate::core::password::strength::StrengthLevel;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::string::base64::Base64;
+use crate::core::rules::string::bcp47_locale::Bcp47Locale;
+use crate::core::rules::string::bic::Bic;
 use crate::core::rules::string::charset::Charset;
 use crate::core::rules::string::cidr::Cidr;
+use crate::core::rules::string::credit_card::CreditCard;
+use crate::core::rules::string::css_color::CssColor;
+use crate::core::rules::string::decimal_precision::DecimalPrecision;
+use crate::core::rules::string::ean::Ean;
 use crate::core::rules::string::email::Email;
+use crate::core::rules::string::email_with_options::EmailWithOptions;
 use crate::core::rules::string::encoding_charset::EncodingCharset;
+use crate::core::rules::string::hex::Hex;
+use crate::core::rules::string::hex_color::HexColor;
 use crate::core::rules::string::hostname::Hostname;
+use crate::core::rules::string::iana_timezone::IanaTimezone;
+use crate::core::rules::string::iban::Iban;
+use crate::core::rules::string::isbn::Isbn;
+use crate::core::rules::string::iso639_language::Iso639Language;
+use crate::core::rules::string::iso3166_country::{CountryFormat, Iso3166Country};
+use crate::core::rules::string::iso4217_currency::Iso4217Currency;
+use crate::core::rules::string::issn::Issn;
 use crate::core::rules::string::json::Json;
+use crate::core::rules::string::json_with_options::JsonWithOptions;
+use crate::core::rules::string::jwt::Jwt;
 use crate::core::rules::string::lowercased::Lowercased;
 use crate::core::rules::string::mac_address::MacAddress;
 use crate::core::rules::string::max_length::MaxLength;
 use crate::core::rules::string::min_length::MinLength;
+use crate::core::rules::string::national_id::NationalId;
+use crate::core::rules::string::no_bidi_controls::NoBidiControls;
 use crate::core::rules::string::no_suspicious_characters::NoSuspiciousCharacters;
+use crate::core::rules::string::no_zero_width::NoZeroWidth;
+use crate::core::rules::string::not_confusable::NotConfusable;
 use crate::core::rules::string::one_of::OneOf;
-use crate::core::rules::string::password_strength::{PasswordStrength, StrengthLevel};
+use crate::core::rules::string::password_policy::{PasswordPolicyRule, PasswordPolicyWithInputs};
+use crate::core::rules::string::password_strength::{PasswordStrength, PasswordStrengthWithInputs};
+#[cfg(feature = "phone")]
+use crate::core::rules::string::phone::Phone;
 use crate::core::rules::string::regex_match::RegexMatch;
+use crate::core::rules::string::semver::Semver;
+use crate::core::rules::string::single_script::SingleScript;
+use crate::core::rules::string::slug::Slug;
 use crate::core::rules::string::trimmed::Trimmed;
+use crate::core::rules::string::ulid::Ulid;
+use crate::core::rules::string::unicode_normalized::UnicodeNormalized;
+use crate::core::rules::string::upc::Upc;
 use crate::core::rules::string::uppercased::Uppercased;
 use crate::core::rules::string::url::UrlValid;
+use crate::core::rules::string::url_with_options::UrlWithOptions;
 use crate::core::rules::string::uuid_valid::UuidValid;
 use crate::core::rules::string::uuid_version::{UuidVersion, UuidVersionValidator};
+use crate::core::rules::string::vat::Vat;
 use crate::core::rules::string::word_count::WordCount;
+use crate::core::rules::string::xml::Xml;
+use crate::core::rules::string::yaml::Yaml;
+use crate::core::text::length::{LengthUnit, WordBoundary};
+use crate::core::text::unicode::NormalizationForm;
 use regex::Regex;
 use std::collections::HashSet;
 use std::error::Error;
@@ -29,6 +78,32 @@ where
     V: StrAsRef + Send + Sync + 'static,
     E: Error + Send + Sync + 'static,
 {
+    /// Standard Base64 with padding.
+    pub fn base64(mut self) -> Self {
+        self.rules.rules.push(Box::new(Base64 {
+            options: Base64Options::new(),
+        }));
+        self
+    }
+
+    /// Base64 restricted by `Base64Options`: alphabet, padding, decoded size.
+    pub fn base64_with(mut self, options: Base64Options) -> Self {
+        self.rules.rules.push(Box::new(Base64 { options }));
+        self
+    }
+
+    /// BCP 47 language tag (`uk-UA`, `zh-Hant-TW`) built from known ISO codes.
+    pub fn bcp47_locale(mut self, case: CodeCase) -> Self {
+        self.rules.rules.push(Box::new(Bcp47Locale { case }));
+        self
+    }
+
+    /// SWIFT/BIC code (`DEUTDEFF`, `DEUTDEFF500`), uppercase.
+    pub fn bic(mut self) -> Self {
+        self.rules.rules.push(Box::new(Bic {}));
+        self
+    }
+
     pub fn charset(mut self, allowed: fn(char) -> bool) -> Self {
         self.rules.rules.push(Box::new(Charset { allowed }));
         self
@@ -39,26 +114,147 @@ where
         self
     }
 
+    /// Card number with a valid Luhn checksum, any brand.
+    pub fn credit_card(self) -> Self {
+        self.credit_card_brands(&[])
+    }
+
+    /// `credit_card` whose detected brand must be one of `brands`.
+    pub fn credit_card_brands(mut self, brands: &[CardBrand]) -> Self {
+        self.rules.rules.push(Box::new(CreditCard {
+            brands: brands.to_vec(),
+        }));
+        self
+    }
+
+    /// Any CSS color value: hex, named or functional (`rgb(0 0 0 / 50%)`).
+    pub fn css_color(mut self) -> Self {
+        self.rules.rules.push(Box::new(CssColor {}));
+        self
+    }
+
+    /// Decimal string fitting SQL `DECIMAL(precision, scale)`, e.g. `decimal_precision(10, 2)`.
+    pub fn decimal_precision(mut self, precision: usize, scale: usize) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(DecimalPrecision { precision, scale }));
+        self
+    }
+
+    /// EAN-8 or EAN-13 with a valid GS1 check digit.
+    pub fn ean(mut self) -> Self {
+        self.rules.rules.push(Box::new(Ean {}));
+        self
+    }
+
     pub fn email(mut self) -> Self {
         self.rules.rules.push(Box::new(Email {}));
         self
     }
 
+    pub fn email_with(mut self, options: EmailOptions) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(EmailWithOptions { options }));
+        self
+    }
+
     pub fn encoding_charset(mut self, charset: &'static str) -> Self {
         self.rules.rules.push(Box::new(EncodingCharset { charset }));
         self
     }
 
+    pub fn hex(mut self) -> Self {
+        self.rules.rules.push(Box::new(Hex {}));
+        self
+    }
+
+    /// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+    pub fn hex_color(mut self) -> Self {
+        self.rules.rules.push(Box::new(HexColor {}));
+        self
+    }
+
     pub fn hostname(mut self) -> Self {
         self.rules.rules.push(Box::new(Hostname {}));
         self
     }
 
+    /// IANA time zone name (`Europe/Kyiv`).
+    pub fn iana_timezone(mut self, case: CodeCase) -> Self {
+        self.rules.rules.push(Box::new(IanaTimezone { case }));
+        self
+    }
+
+    /// IBAN with the registered length of its country and a valid mod-97 checksum.
+    pub fn iban(mut self) -> Self {
+        self.rules.rules.push(Box::new(Iban {}));
+        self
+    }
+
+    pub fn isbn(mut self) -> Self {
+        self.rules.rules.push(Box::new(Isbn {}));
+        self
+    }
+
+    /// ISO 3166-1 alpha-2 country code (`UA`).
+    pub fn iso3166_alpha2(mut self, case: CodeCase) -> Self {
+        self.rules.rules.push(Box::new(Iso3166Country {
+            format: CountryFormat::Alpha2,
+            case,
+        }));
+        self
+    }
+
+    /// ISO 3166-1 alpha-3 country code (`UKR`).
+    pub fn iso3166_alpha3(mut self, case: CodeCase) -> Self {
+        self.rules.rules.push(Box::new(Iso3166Country {
+            format: CountryFormat::Alpha3,
+            case,
+        }));
+        self
+    }
+
+    pub fn iso4217_currency(mut self) -> Self {
+        self.rules.rules.push(Box::new(Iso4217Currency {}));
+        self
+    }
+
+    /// ISO 639 language code: two letters (`uk`) or three (`ukr`).
+    pub fn iso639_language(mut self, case: CodeCase) -> Self {
+        self.rules.rules.push(Box::new(Iso639Language { case }));
+        self
+    }
+
+    pub fn issn(mut self) -> Self {
+        self.rules.rules.push(Box::new(Issn {}));
+        self
+    }
+
     pub fn json(mut self) -> Self {
         self.rules.rules.push(Box::new(Json {}));
         self
     }
 
+    /// JSON within the size, depth and top-level type limits of `DocumentOptions`.
+    pub fn json_with(mut self, options: DocumentOptions) -> Self {
+        self.rules.rules.push(Box::new(JsonWithOptions { options }));
+        self
+    }
+
+    /// Structurally valid JWT; the signature is not verified.
+    pub fn jwt(self) -> Self {
+        self.jwt_algorithms(&[])
+    }
+
+    /// Same, with the `alg` of the header restricted to `algorithms` (`["RS256", "ES256"]`).
+    pub fn jwt_algorithms(mut self, algorithms: &[&str]) -> Self {
+        self.rules.rules.push(Box::new(Jwt {
+            algorithms: algorithms.iter().map(|a| a.to_string()).collect(),
+        }));
+        self
+    }
+
     pub fn lowercased(mut self) -> Self {
         self.rules.rules.push(Box::new(Lowercased {}));
         self
@@ -69,13 +265,45 @@ where
         self
     }
 
-    pub fn max_length(mut self, max: usize) -> Self {
-        self.rules.rules.push(Box::new(MaxLength { max }));
+    pub fn max_length(self, max: usize) -> Self {
+        self.max_length_in(max, LengthUnit::Chars)
+    }
+
+    /// `max_length` counted in `unit`: `LengthUnit::Bytes` for storage limits,
+    /// `LengthUnit::Graphemes` for limits shown to people.
+    pub fn max_length_in(mut self, max: usize, unit: LengthUnit) -> Self {
+        self.rules.rules.push(Box::new(MaxLength { max, unit }));
         self
     }
 
-    pub fn min_length(mut self, min: usize) -> Self {
-        self.rules.rules.push(Box::new(MinLength { min }));
+    pub fn min_length(self, min: usize) -> Self {
+        self.min_length_in(min, LengthUnit::Chars)
+    }
+
+    pub fn min_length_in(mut self, min: usize, unit: LengthUnit) -> Self {
+        self.rules.rules.push(Box::new(MinLength { min, unit }));
+        self
+    }
+
+    /// National identifier of a built-in `country` (`"UA"`, `"PL"`, `"US"`).
+    /// Panics on other countries; see `national_id_in`.
+    pub fn national_id(self, country: &str) -> Self {
+        self.national_id_in(country, &BUILT_IN)
+    }
+
+    /// National identifier of `country`, checked by the validator `registry` holds for it.
+    /// Panics when there is none.
+    pub fn national_id_in(mut self, country: &str, registry: &NationalIdRegistry) -> Self {
+        let rule = NationalId::resolve(registry, country)
+            .unwrap_or_else(|| panic!("no national id validator for `{country}`"));
+        self.rules.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Rejects directional formatting characters (`U+202E` and friends) used to disguise text,
+    /// e.g. `invoice\u{202E}fdp.exe` displayed as `invoiceexe.pdf`.
+    pub fn no_bidi_controls(mut self) -> Self {
+        self.rules.rules.push(Box::new(NoBidiControls {}));
         self
     }
 
@@ -86,26 +314,133 @@ where
         self
     }
 
+    /// Rejects zero-width spaces, joiners and the BOM. ZWJ/ZWNJ are legitimate in emoji
+    /// sequences and Persian text, so keep this to identifiers.
+    pub fn no_zero_width_chars(mut self) -> Self {
+        self.rules.rules.push(Box::new(NoZeroWidth {}));
+        self
+    }
+
+    /// Rejects values that look like one of `reserved` (`"аdmin"` with a Cyrillic `а` for
+    /// `"admin"`), compared by UTS #39 skeleton and ignoring case.
+    pub fn not_confusable_with(mut self, reserved: &[&str]) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(NotConfusable::new(reserved)));
+        self
+    }
+
     pub fn one_of(mut self, allowed: HashSet<String>) -> Self {
         self.rules.rules.push(Box::new(OneOf { allowed }));
         self
     }
 
+    /// Checks every requirement of `policy`; see `PasswordPolicy::check` for the full list.
+    pub fn password_policy(mut self, policy: PasswordPolicy) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(PasswordPolicyRule { policy }));
+        self
+    }
+
+    /// `password_policy` that also rejects passwords containing personal data of the DTO, e.g.
+    /// `|u| vec![u.username.clone()]`.
+    pub fn password_policy_with_inputs<F>(mut self, policy: PasswordPolicy, user_inputs: F) -> Self
+    where
+        T: 'static,
+        F: Fn(&T) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.rules
+            .rules_dto
+            .push(Box::new(PasswordPolicyWithInputs {
+                rule: PasswordPolicyRule { policy },
+                user_inputs: Box::new(user_inputs),
+            }));
+        self
+    }
+
     pub fn password_strength(mut self, level: StrengthLevel) -> Self {
         self.rules.rules.push(Box::new(PasswordStrength { level }));
         self
     }
 
+    /// `password_strength` that also penalizes personal data of the DTO, e.g.
+    /// `|u| vec![u.username.clone(), u.email.clone()]`.
+    pub fn password_strength_with_inputs<F>(mut self, level: StrengthLevel, user_inputs: F) -> Self
+    where
+        T: 'static,
+        F: Fn(&T) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.rules
+            .rules_dto
+            .push(Box::new(PasswordStrengthWithInputs {
+                strength: PasswordStrength { level },
+                user_inputs: Box::new(user_inputs),
+            }));
+        self
+    }
+
+    /// International number (`+380 50 123 4567`).
+    #[cfg(feature = "phone")]
+    pub fn phone(self) -> Self {
+        self.phone_with(PhoneOptions::new())
+    }
+
+    /// Number in the national format of `region` (`"UA"`: `050 123 4567`) or international.
+    #[cfg(feature = "phone")]
+    pub fn phone_region(self, region: &str) -> Self {
+        self.phone_with(PhoneOptions::new().region(region))
+    }
+
+    #[cfg(feature = "phone")]
+    pub fn phone_with(mut self, options: PhoneOptions) -> Self {
+        self.rules.rules.push(Box::new(Phone { options }));
+        self
+    }
+
     pub fn regex_match(mut self, pattern: Regex) -> Self {
         self.rules.rules.push(Box::new(RegexMatch { pattern }));
         self
     }
 
+    /// Semantic Versioning 2.0.0 (`1.4.0-rc.1+build.5`).
+    pub fn semver(mut self) -> Self {
+        self.rules.rules.push(Box::new(Semver {}));
+        self
+    }
+
+    /// All letters from one script, per UTS #39 (Han with Kana or Hangul counts as one).
+    pub fn single_script(mut self) -> Self {
+        self.rules.rules.push(Box::new(SingleScript {}));
+        self
+    }
+
+    /// URL slug: `hello-world-2`.
+    pub fn slug(mut self) -> Self {
+        self.rules.rules.push(Box::new(Slug {}));
+        self
+    }
+
     pub fn trimmed(mut self) -> Self {
         self.rules.rules.push(Box::new(Trimmed {}));
         self
     }
 
+    pub fn ulid(mut self) -> Self {
+        self.rules.rules.push(Box::new(Ulid {}));
+        self
+    }
+
+    pub fn unicode_normalized(mut self, form: NormalizationForm) -> Self {
+        self.rules.rules.push(Box::new(UnicodeNormalized { form }));
+        self
+    }
+
+    pub fn upc(mut self) -> Self {
+        self.rules.rules.push(Box::new(Upc {}));
+        self
+    }
+
     pub fn uppercased(mut self) -> Self {
         self.rules.rules.push(Box::new(Uppercased {}));
         self
@@ -116,6 +451,11 @@ where
         self
     }
 
+    pub fn url_with(mut self, options: UrlOptions) -> Self {
+        self.rules.rules.push(Box::new(UrlWithOptions { options }));
+        self
+    }
+
     pub fn uuid(mut self) -> Self {
         self.rules.rules.push(Box::new(UuidValid {}));
         self
@@ -128,8 +468,43 @@ where
         self
     }
 
-    pub fn word_count(mut self, min: Option<usize>, max: Option<usize>) -> Self {
-        self.rules.rules.push(Box::new(WordCount { min, max }));
+    /// EU VAT number with its country prefix (`DE136695976`).
+    pub fn vat(mut self) -> Self {
+        self.rules.rules.push(Box::new(Vat {}));
+        self
+    }
+
+    pub fn word_count(self, min: Option<usize>, max: Option<usize>) -> Self {
+        self.word_count_by(min, max, WordBoundary::Whitespace)
+    }
+
+    /// `word_count` with words split by `boundary`. Words are counted, not measured, so there
+    /// is no `LengthUnit` variant: the boundary is what decides what one word is.
+    pub fn word_count_by(
+        mut self,
+        min: Option<usize>,
+        max: Option<usize>,
+        boundary: WordBoundary,
+    ) -> Self {
+        self.rules
+            .rules
+            .push(Box::new(WordCount { min, max, boundary }));
+        self
+    }
+
+    /// Well-formed XML document; no schema or DTD validation.
+    pub fn xml(mut self) -> Self {
+        self.rules.rules.push(Box::new(Xml {}));
+        self
+    }
+
+    pub fn yaml(self) -> Self {
+        self.yaml_with(DocumentOptions::new())
+    }
+
+    /// YAML within the size, depth and top-level type limits of `DocumentOptions`.
+    pub fn yaml_with(mut self, options: DocumentOptions) -> Self {
+        self.rules.rules.push(Box::new(Yaml { options }));
         self
     }
 }
@@ -0,0 +1,80 @@
+use crate::core::field_builder::main::FieldBuilder;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::temporal::after::After;
+use crate::core::rules::temporal::before::Before;
+use crate::core::rules::temporal::between::Between;
+use crate::core::rules::temporal::business_day::BusinessDay;
+use crate::core::rules::temporal::date_format::DateFormatRule;
+use crate::core::rules::temporal::in_future::InFuture;
+use crate::core::rules::temporal::in_past::InPast;
+use crate::core::rules::temporal::max_age::MaxAge;
+use crate::core::rules::temporal::min_age::MinAge;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::temporal::bound::TimeBound;
+use crate::core::temporal::calendar::BusinessCalendar;
+use crate::core::temporal::format::DateFormat;
+use std::error::Error;
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: StrAsRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn date_format(mut self, format: DateFormat) -> Self {
+        self.rules.rules.push(Box::new(DateFormatRule { format }));
+        self
+    }
+}
+
+impl<'a, T, V, E> FieldBuilder<'a, T, V, E>
+where
+    V: TemporalRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    pub fn after(mut self, bound: impl Into<TimeBound>) -> Self {
+        self.rules.rules.push(Box::new(After {
+            bound: bound.into(),
+        }));
+        self
+    }
+
+    pub fn before(mut self, bound: impl Into<TimeBound>) -> Self {
+        self.rules.rules.push(Box::new(Before {
+            bound: bound.into(),
+        }));
+        self
+    }
+
+    pub fn between(mut self, min: impl Into<TimeBound>, max: impl Into<TimeBound>) -> Self {
+        self.rules.rules.push(Box::new(Between {
+            min: min.into(),
+            max: max.into(),
+        }));
+        self
+    }
+
+    pub fn business_day(mut self, calendar: BusinessCalendar) -> Self {
+        self.rules.rules.push(Box::new(BusinessDay { calendar }));
+        self
+    }
+
+    pub fn in_future(mut self) -> Self {
+        self.rules.rules.push(Box::new(InFuture {}));
+        self
+    }
+
+    pub fn in_past(mut self) -> Self {
+        self.rules.rules.push(Box::new(InPast {}));
+        self
+    }
+
+    pub fn max_age(mut self, years: u32) -> Self {
+        self.rules.rules.push(Box::new(MaxAge { years }));
+        self
+    }
+
+    pub fn min_age(mut self, years: u32) -> Self {
+        self.rules.rules.push(Box::new(MinAge { years }));
+        self
+    }
+}
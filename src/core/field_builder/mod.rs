@@ -1,7 +1,11 @@
 pub(crate) mod impl_common;
 pub(crate) mod impl_custom;
 pub(crate) mod impl_nested;
+pub(crate) mod impl_net;
 pub(crate) mod impl_numeric;
+pub(crate) mod impl_sanitize;
 pub(crate) mod impl_slice;
+pub(crate) mod impl_spec;
 pub(crate) mod impl_strings;
+pub(crate) mod impl_temporal;
 pub(crate) mod main;
@@ -0,0 +1,182 @@
+/// Payment card network, detected from the issuer identification number (leading digits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    Discover,
+    DinersClub,
+    Jcb,
+    UnionPay,
+    Maestro,
+    Mir,
+}
+
+struct BrandRule {
+    brand: CardBrand,
+    /// IIN ranges, inclusive, compared on as many leading digits as the bound has.
+    prefixes: &'static [(u32, u32)],
+    lengths: &'static [usize],
+}
+
+/// Checked in order: more specific ranges first.
+const BRANDS: &[BrandRule] = &[
+    BrandRule {
+        brand: CardBrand::Amex,
+        prefixes: &[(34, 34), (37, 37)],
+        lengths: &[15],
+    },
+    BrandRule {
+        brand: CardBrand::Mir,
+        prefixes: &[(2200, 2204)],
+        lengths: &[16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::Mastercard,
+        prefixes: &[(51, 55), (2221, 2720)],
+        lengths: &[16],
+    },
+    BrandRule {
+        brand: CardBrand::Jcb,
+        prefixes: &[(3528, 3589)],
+        lengths: &[16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::DinersClub,
+        prefixes: &[(300, 305), (36, 36), (38, 39)],
+        lengths: &[14, 15, 16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::Discover,
+        prefixes: &[(6011, 6011), (644, 649), (65, 65)],
+        lengths: &[16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::UnionPay,
+        prefixes: &[(62, 62)],
+        lengths: &[16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::Maestro,
+        prefixes: &[(50, 50), (56, 58), (6, 6)],
+        lengths: &[12, 13, 14, 15, 16, 17, 18, 19],
+    },
+    BrandRule {
+        brand: CardBrand::Visa,
+        prefixes: &[(4, 4)],
+        lengths: &[13, 16, 19],
+    },
+];
+
+impl CardBrand {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "visa",
+            CardBrand::Mastercard => "mastercard",
+            CardBrand::Amex => "amex",
+            CardBrand::Discover => "discover",
+            CardBrand::DinersClub => "diners_club",
+            CardBrand::Jcb => "jcb",
+            CardBrand::UnionPay => "unionpay",
+            CardBrand::Maestro => "maestro",
+            CardBrand::Mir => "mir",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        BRANDS
+            .iter()
+            .map(|rule| rule.brand)
+            .find(|brand| brand.as_str() == name)
+    }
+
+    /// Brand of a card number given as digits only, when prefix and length match one.
+    pub fn detect(digits: &str) -> Option<Self> {
+        BRANDS
+            .iter()
+            .find(|rule| {
+                rule.lengths.contains(&digits.len())
+                    && rule
+                        .prefixes
+                        .iter()
+                        .any(|&(low, high)| in_range(digits, low, high))
+            })
+            .map(|rule| rule.brand)
+    }
+}
+
+fn in_range(digits: &str, low: u32, high: u32) -> bool {
+    let width = low.to_string().len();
+    digits
+        .get(..width)
+        .and_then(|prefix| prefix.parse::<u32>().ok())
+        .is_some_and(|prefix| (low..=high).contains(&prefix))
+}
+
+/// Luhn (mod 10) checksum over ASCII digits.
+pub(crate) fn luhn_valid(digits: &str) -> bool {
+    let sum: u32 = digits
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(index, b)| {
+            let digit = (b - b'0') as u32;
+            match index % 2 {
+                0 => digit,
+                _ if digit > 4 => digit * 2 - 9,
+                _ => digit * 2,
+            }
+        })
+        .sum();
+    sum.is_multiple_of(10)
+}
+
+/// Card number without the usual space or dash separators, if it is 12-19 digits.
+pub(crate) fn card_digits(value: &str) -> Option<String> {
+    let digits: String = value.chars().filter(|c| *c != ' ' && *c != '-').collect();
+    let valid = (12..=19).contains(&digits.len()) && digits.bytes().all(|b| b.is_ascii_digit());
+    valid.then_some(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_luhn_checksum() {
+        assert!(luhn_valid("4111111111111111"));
+        assert!(luhn_valid("79927398713"));
+        assert!(!luhn_valid("4111111111111112"));
+    }
+
+    #[test]
+    fn detects_brands() {
+        let cases = [
+            ("4111111111111111", CardBrand::Visa),
+            ("5555555555554444", CardBrand::Mastercard),
+            ("2223003122003222", CardBrand::Mastercard),
+            ("378282246310005", CardBrand::Amex),
+            ("6011111111111117", CardBrand::Discover),
+            ("30569309025904", CardBrand::DinersClub),
+            ("3530111333300000", CardBrand::Jcb),
+            ("6200000000000005", CardBrand::UnionPay),
+            ("6759649826438453", CardBrand::Maestro),
+            ("2200000000000004", CardBrand::Mir),
+        ];
+        for (number, brand) in cases {
+            assert_eq!(CardBrand::detect(number), Some(brand), "{number}");
+        }
+        assert_eq!(CardBrand::detect("9111111111111111"), None);
+        assert_eq!(CardBrand::detect("41111111111111"), None);
+    }
+
+    #[test]
+    fn strips_separators() {
+        assert_eq!(
+            card_digits("4111 1111-1111 1111").as_deref(),
+            Some("4111111111111111")
+        );
+        assert_eq!(card_digits("4111 1111 1111 111a"), None);
+        assert_eq!(card_digits("4111"), None);
+    }
+}
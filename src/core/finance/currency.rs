@@ -0,0 +1,29 @@
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+static CURRENCIES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    include_str!("../../../data/iso4217_currencies.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+});
+
+/// Alphabetic ISO 4217 code, uppercase as published (`UAH`, `EUR`).
+pub(crate) fn is_currency(code: &str) -> bool {
+    CURRENCIES.contains(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_iso_4217_codes() {
+        assert!(is_currency("UAH"));
+        assert!(is_currency("EUR"));
+        assert!(is_currency("XAU"));
+        assert!(!is_currency("eur"));
+        assert!(!is_currency("ABC"));
+    }
+}
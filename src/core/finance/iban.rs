@@ -0,0 +1,99 @@
+/// Country code and IBAN length, from the SWIFT IBAN registry.
+const LENGTHS: &str = "AD24 AE23 AL28 AT20 AZ28 BA20 BE16 BG22 BH22 BI27 BR29 BY28 CH21 CR22 CY28 CZ24 \
+    DE22 DJ27 DK18 DO28 EE20 EG29 ES24 FI18 FK18 FO18 FR27 GB22 GE22 GI23 GL18 GR27 GT28 HN28 \
+    HR21 HU28 IE22 IL23 IQ23 IS26 IT27 JO30 KW30 KZ20 LB28 LC32 LI21 LT20 LU20 LV21 LY25 MC27 \
+    MD24 ME22 MK19 MN20 MR27 MT31 MU30 NI28 NL18 NO15 OM23 PK24 PL28 PS29 PT25 QA29 RO24 RS22 \
+    RU33 SA24 SC31 SD18 SE24 SI19 SK24 SM27 SO23 ST25 SV28 TL23 TN24 TR26 UA29 VA22 VG24 XK20 \
+    YE30";
+
+fn expected_length(country: &str) -> Option<usize> {
+    LENGTHS
+        .split_whitespace()
+        .find(|entry| &entry[..2] == country)
+        .and_then(|entry| entry[2..].parse().ok())
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum IbanFailure {
+    Format,
+    Country(String),
+    Length { country: String, expected: usize },
+    Checksum,
+}
+
+/// Validates an IBAN written in print (`UA21 3223 ...`) or electronic form. Letters must be
+/// uppercase, as required by ISO 13616.
+pub(crate) fn validate_iban(value: &str) -> Result<(), IbanFailure> {
+    let iban: String = value.chars().filter(|c| *c != ' ').collect();
+    let bytes = iban.as_bytes();
+    let well_formed = bytes.len() >= 4
+        && bytes[..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..4].iter().all(u8::is_ascii_digit)
+        && bytes
+            .iter()
+            .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit());
+    if !well_formed {
+        return Err(IbanFailure::Format);
+    }
+
+    let country = &iban[..2];
+    let Some(expected) = expected_length(country) else {
+        return Err(IbanFailure::Country(country.to_string()));
+    };
+    if iban.len() != expected {
+        return Err(IbanFailure::Length {
+            country: country.to_string(),
+            expected,
+        });
+    }
+
+    // ISO 7064 mod 97-10 over the rearranged number, letters as 10..=35.
+    let remainder = iban[4..]
+        .bytes()
+        .chain(iban[..4].bytes())
+        .fold(0u32, |acc, b| match b {
+            b'0'..=b'9' => (acc * 10 + (b - b'0') as u32) % 97,
+            _ => (acc * 100 + (b - b'A' + 10) as u32) % 97,
+        });
+    if remainder != 1 {
+        return Err(IbanFailure::Checksum);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_ibans() {
+        assert_eq!(validate_iban("DE89370400440532013000"), Ok(()));
+        assert_eq!(validate_iban("GB29 NWBK 6016 1331 9268 19"), Ok(()));
+        assert_eq!(validate_iban("UA213223130000026007233566001"), Ok(()));
+        assert_eq!(validate_iban("NO9386011117947"), Ok(()));
+    }
+
+    #[test]
+    fn reports_why_an_iban_is_rejected() {
+        assert_eq!(
+            validate_iban("de89370400440532013000"),
+            Err(IbanFailure::Format)
+        );
+        assert_eq!(validate_iban("DE89-3704"), Err(IbanFailure::Format));
+        assert_eq!(
+            validate_iban("ZZ89370400440532013000"),
+            Err(IbanFailure::Country("ZZ".into()))
+        );
+        assert_eq!(
+            validate_iban("DE8937040044053201300"),
+            Err(IbanFailure::Length {
+                country: "DE".into(),
+                expected: 22
+            })
+        );
+        assert_eq!(
+            validate_iban("DE88370400440532013000"),
+            Err(IbanFailure::Checksum)
+        );
+    }
+}
@@ -0,0 +1,3 @@
+pub mod card;
+pub(crate) mod currency;
+pub(crate) mod iban;
@@ -0,0 +1,113 @@
+use base64::Engine;
+use base64::alphabet::{Alphabet, STANDARD, URL_SAFE};
+use base64::engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig};
+
+/// `=` padding at the end of Base64 text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Base64Padding {
+    #[default]
+    Required,
+    Optional,
+    Forbidden,
+}
+
+impl Base64Padding {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Base64Padding::Required => "required",
+            Base64Padding::Optional => "optional",
+            Base64Padding::Forbidden => "forbidden",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "required" => Some(Base64Padding::Required),
+            "optional" => Some(Base64Padding::Optional),
+            "forbidden" => Some(Base64Padding::Forbidden),
+            _ => None,
+        }
+    }
+}
+
+/// Policy for `base64_with`. `Base64Options::new()` is what `base64()` checks: the standard
+/// alphabet (`+/`) with padding and any decoded size.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Base64Options {
+    pub(crate) url_safe: bool,
+    pub(crate) padding: Base64Padding,
+    pub(crate) min_decoded: Option<usize>,
+    pub(crate) max_decoded: Option<usize>,
+}
+
+impl Base64Options {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The URL-safe alphabet (`-_`, RFC 4648 §5) instead of the standard one.
+    pub fn url_safe(mut self, url_safe: bool) -> Self {
+        self.url_safe = url_safe;
+        self
+    }
+
+    pub fn padding(mut self, padding: Base64Padding) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Minimum number of decoded bytes.
+    pub fn min_decoded_size(mut self, min: usize) -> Self {
+        self.min_decoded = Some(min);
+        self
+    }
+
+    /// Maximum number of decoded bytes, e.g. to bound uploaded avatars.
+    pub fn max_decoded_size(mut self, max: usize) -> Self {
+        self.max_decoded = Some(max);
+        self
+    }
+
+    /// The decoded bytes, or `None` when `value` is not Base64 of this kind.
+    pub fn decode(&self, value: &str) -> Option<Vec<u8>> {
+        self.engine().decode(value).ok()
+    }
+
+    fn engine(&self) -> GeneralPurpose {
+        let alphabet: &Alphabet = if self.url_safe { &URL_SAFE } else { &STANDARD };
+        let padding = match self.padding {
+            Base64Padding::Required => DecodePaddingMode::RequireCanonical,
+            Base64Padding::Optional => DecodePaddingMode::Indifferent,
+            Base64Padding::Forbidden => DecodePaddingMode::RequireNone,
+        };
+        GeneralPurpose::new(
+            alphabet,
+            GeneralPurposeConfig::new().with_decode_padding_mode(padding),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_per_alphabet_and_padding() {
+        let standard = Base64Options::new();
+        assert_eq!(standard.decode("aGk/").as_deref(), Some(&b"hi?"[..]));
+        assert_eq!(standard.decode("aGk=").as_deref(), Some(&b"hi"[..]));
+        assert_eq!(standard.decode("aGk"), None);
+        assert_eq!(standard.decode("aGk_"), None);
+
+        let url_safe = Base64Options::new()
+            .url_safe(true)
+            .padding(Base64Padding::Forbidden);
+        assert_eq!(url_safe.decode("aGk_").as_deref(), Some(&b"hi?"[..]));
+        assert_eq!(url_safe.decode("aGk").as_deref(), Some(&b"hi"[..]));
+        assert_eq!(url_safe.decode("aGk="), None);
+
+        let optional = Base64Options::new().padding(Base64Padding::Optional);
+        assert!(optional.decode("aGk").is_some());
+        assert!(optional.decode("aGk=").is_some());
+    }
+}
@@ -0,0 +1,152 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+pub(crate) const HEX_COLOR_PATTERN: &str =
+    r"^#(?:[0-9a-fA-F]{3}|[0-9a-fA-F]{4}|[0-9a-fA-F]{6}|[0-9a-fA-F]{8})$";
+
+static HEX_COLOR: Lazy<Regex> = Lazy::new(|| Regex::new(HEX_COLOR_PATTERN).unwrap());
+
+static NUMBER: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[+-]?(?:\d+\.?\d*|\.\d+)(?:[eE][+-]?\d+)?$").unwrap());
+
+/// CSS Color Level 4 named colors, plus `transparent` and `currentcolor`.
+const NAMED: &str = "\
+    aliceblue antiquewhite aqua aquamarine azure beige bisque black blanchedalmond blue \
+    blueviolet brown burlywood cadetblue chartreuse chocolate coral cornflowerblue cornsilk \
+    crimson cyan darkblue darkcyan darkgoldenrod darkgray darkgreen darkgrey darkkhaki \
+    darkmagenta darkolivegreen darkorange darkorchid darkred darksalmon darkseagreen \
+    darkslateblue darkslategray darkslategrey darkturquoise darkviolet deeppink deepskyblue \
+    dimgray dimgrey dodgerblue firebrick floralwhite forestgreen fuchsia gainsboro ghostwhite \
+    gold goldenrod gray green greenyellow grey honeydew hotpink indianred indigo ivory khaki \
+    lavender lavenderblush lawngreen lemonchiffon lightblue lightcoral lightcyan \
+    lightgoldenrodyellow lightgray lightgreen lightgrey lightpink lightsalmon lightseagreen \
+    lightskyblue lightslategray lightslategrey lightsteelblue lightyellow lime limegreen linen \
+    magenta maroon mediumaquamarine mediumblue mediumorchid mediumpurple mediumseagreen \
+    mediumslateblue mediumspringgreen mediumturquoise mediumvioletred midnightblue mintcream \
+    mistyrose moccasin navajowhite navy oldlace olive olivedrab orange orangered orchid \
+    palegoldenrod palegreen paleturquoise palevioletred papayawhip peachpuff peru pink plum \
+    powderblue purple rebeccapurple red rosybrown royalblue saddlebrown salmon sandybrown \
+    seagreen seashell sienna silver skyblue slateblue slategray slategrey snow springgreen \
+    steelblue tan teal thistle tomato turquoise violet wheat white whitesmoke yellow yellowgreen \
+    transparent currentcolor";
+
+#[derive(Clone, Copy, PartialEq)]
+enum Component {
+    /// Number or percentage (`255`, `50%`).
+    Value,
+    /// Number or angle (`120`, `120deg`, `0.5turn`).
+    Hue,
+}
+
+pub(crate) fn is_hex_color(value: &str) -> bool {
+    HEX_COLOR.is_match(value)
+}
+
+/// A hex color, a named color or one of the `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`,
+/// `oklab()` and `oklch()` functions in legacy (comma) or modern (space, `/ alpha`) syntax.
+/// Component values are checked for form, not range.
+pub(crate) fn is_css_color(value: &str) -> bool {
+    let value = value.trim().to_ascii_lowercase();
+    if is_hex_color(&value) || NAMED.split_whitespace().any(|name| name == value) {
+        return true;
+    }
+    let Some((function, arguments)) = value
+        .strip_suffix(')')
+        .and_then(|rest| rest.split_once('('))
+    else {
+        return false;
+    };
+    let (components, legacy) = match function.trim() {
+        "rgb" | "rgba" => ([Component::Value; 3], true),
+        "hsl" | "hsla" => ([Component::Hue, Component::Value, Component::Value], true),
+        "hwb" => ([Component::Hue, Component::Value, Component::Value], false),
+        "lab" | "oklab" => ([Component::Value; 3], false),
+        "lch" | "oklch" => ([Component::Value, Component::Value, Component::Hue], false),
+        _ => return false,
+    };
+    arguments_valid(arguments, &components, legacy)
+}
+
+fn arguments_valid(arguments: &str, components: &[Component; 3], legacy: bool) -> bool {
+    if legacy && arguments.contains(',') {
+        let parts: Vec<&str> = arguments.split(',').map(str::trim).collect();
+        return matches!(parts.len(), 3 | 4)
+            && parts
+                .iter()
+                .zip(components.iter().chain([&Component::Value]))
+                .all(|(part, component)| component_valid(part, *component, false));
+    }
+
+    let (channels, alpha) = match arguments.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (arguments, None),
+    };
+    let channels: Vec<&str> = channels.split_whitespace().collect();
+    channels.len() == 3
+        && channels
+            .iter()
+            .zip(components)
+            .all(|(channel, component)| component_valid(channel, *component, true))
+        && alpha.is_none_or(|alpha| component_valid(alpha, Component::Value, true))
+}
+
+fn component_valid(text: &str, component: Component, allow_none: bool) -> bool {
+    if allow_none && text == "none" {
+        return true;
+    }
+    let number = match component {
+        Component::Value => text.strip_suffix('%').unwrap_or(text),
+        Component::Hue => ["deg", "grad", "rad", "turn"]
+            .iter()
+            .find_map(|unit| text.strip_suffix(unit))
+            .unwrap_or(text),
+    };
+    NUMBER.is_match(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_css_colors() {
+        for color in [
+            "#fff",
+            "#FFFA",
+            "#1e90ff",
+            "#1e90ff80",
+            "RebeccaPurple",
+            "transparent",
+            "rgb(255, 0, 0)",
+            "rgba(255,0,0,0.5)",
+            "rgb(100% 0% 0% / 50%)",
+            "hsl(120deg 100% 50%)",
+            "hsla(120, 100%, 50%, .3)",
+            "hwb(0.5turn 10% 20%)",
+            "oklch(62.8% 0.25 29.23)",
+            "lab(52% 40 -20 / none)",
+        ] {
+            assert!(is_css_color(color), "{color}");
+        }
+    }
+
+    #[test]
+    fn rejects_other_values() {
+        for color in [
+            "",
+            "#ff",
+            "#fffff",
+            "blurple",
+            "rgb(255, 0)",
+            "rgb(255 0 0 0)",
+            "rgb(255, 0 0)",
+            "rgb(red, 0, 0)",
+            "hsl(120deg, 100%, 50%, 1, 1)",
+            "lch(50% 40deg 30)",
+            "hwb(0, 10%, 20%)",
+            "url(#fff)",
+        ] {
+            assert!(!is_css_color(color), "{color}");
+        }
+    }
+}
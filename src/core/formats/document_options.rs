@@ -0,0 +1,182 @@
+use crate::core::errors::ValidationError;
+use crate::core::schema::descriptor::RuleDescriptor;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Kind of value a JSON or YAML document must have at its top level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentType {
+    Object,
+    Array,
+}
+
+impl DocumentType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DocumentType::Object => "object",
+            DocumentType::Array => "array",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name {
+            "object" => Some(DocumentType::Object),
+            "array" => Some(DocumentType::Array),
+            _ => None,
+        }
+    }
+}
+
+/// Syntax of a structured document stored in a string field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DocumentFormat {
+    Json,
+    Yaml,
+}
+
+impl DocumentFormat {
+    /// The document as JSON; YAML that has no JSON equivalent (non-string keys, tags) is `None`.
+    pub(crate) fn parse(&self, source: &str) -> Option<Value> {
+        match self {
+            DocumentFormat::Json => serde_json::from_str(source).ok(),
+            DocumentFormat::Yaml => serde_yaml::from_str(source).ok(),
+        }
+    }
+}
+
+/// Limits for `json_with` and `yaml_with`. `DocumentOptions::new()` accepts any document that
+/// parses.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DocumentOptions {
+    pub(crate) max_size: Option<usize>,
+    pub(crate) max_depth: Option<usize>,
+    pub(crate) top_level: Option<DocumentType>,
+}
+
+impl DocumentOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum size of the source text in bytes, checked before parsing.
+    pub fn max_size(mut self, max: usize) -> Self {
+        self.max_size = Some(max);
+        self
+    }
+
+    /// Maximum nesting of objects and arrays: `{"a": [1]}` has depth 2, a scalar 0.
+    pub fn max_depth(mut self, max: usize) -> Self {
+        self.max_depth = Some(max);
+        self
+    }
+
+    pub fn top_level(mut self, top_level: DocumentType) -> Self {
+        self.top_level = Some(top_level);
+        self
+    }
+}
+
+impl DocumentOptions {
+    pub(crate) fn check_size(&self, source: &str) -> Result<(), ValidationError> {
+        match self.max_size.filter(|&max| source.len() > max) {
+            Some(max) => Err(ValidationError::new_with_params(
+                "validator.document.too_large",
+                HashMap::from([("max".into(), max.to_string())]),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Checks a parsed document by its depth and top-level type (`None` for scalars).
+    pub(crate) fn check_shape(
+        &self,
+        depth: usize,
+        top_level: Option<DocumentType>,
+    ) -> Result<(), ValidationError> {
+        if let Some(max) = self.max_depth.filter(|&max| depth > max) {
+            return Err(ValidationError::new_with_params(
+                "validator.document.too_deep",
+                HashMap::from([("max".into(), max.to_string())]),
+            ));
+        }
+        match self
+            .top_level
+            .filter(|&expected| top_level != Some(expected))
+        {
+            Some(expected) => Err(ValidationError::new_with_params(
+                "validator.document.top_level",
+                HashMap::from([("expected".into(), expected.as_str().to_string())]),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn describe(&self, mut descriptor: RuleDescriptor) -> RuleDescriptor {
+        if let Some(max) = self.max_size {
+            descriptor = descriptor.param("max_size", max);
+        }
+        if let Some(max) = self.max_depth {
+            descriptor = descriptor.param("max_depth", max);
+        }
+        if let Some(top_level) = self.top_level {
+            descriptor = descriptor.param("top_level", top_level.as_str());
+        }
+        descriptor
+    }
+}
+
+/// `line` and `column` params of a parse error, both 1-based.
+pub(crate) fn parse_error(key: &str, line: usize, column: usize) -> ValidationError {
+    ValidationError::new_with_params(
+        key,
+        HashMap::from([
+            ("line".into(), line.to_string()),
+            ("column".into(), column.to_string()),
+        ]),
+    )
+}
+
+pub(crate) fn json_depth(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(json_depth).max().unwrap_or(0),
+        Value::Object(map) => 1 + map.values().map(json_depth).max().unwrap_or(0),
+        _ => 0,
+    }
+}
+
+pub(crate) fn yaml_depth(value: &serde_yaml::Value) -> usize {
+    match value {
+        serde_yaml::Value::Sequence(items) => 1 + items.iter().map(yaml_depth).max().unwrap_or(0),
+        serde_yaml::Value::Mapping(map) => {
+            let keys = map.keys().map(yaml_depth);
+            1 + keys.chain(map.values().map(yaml_depth)).max().unwrap_or(0)
+        }
+        serde_yaml::Value::Tagged(tagged) => yaml_depth(&tagged.value),
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn measures_nesting() {
+        assert_eq!(json_depth(&json!(1)), 0);
+        assert_eq!(json_depth(&json!({})), 1);
+        assert_eq!(json_depth(&json!({"a": [1, {"b": []}]})), 4);
+
+        let yaml: serde_yaml::Value = serde_yaml::from_str("a:\n  - 1\n  - b: []\n").unwrap();
+        assert_eq!(yaml_depth(&yaml), 4);
+    }
+
+    #[test]
+    fn parses_yaml_as_json() {
+        assert_eq!(
+            DocumentFormat::Yaml.parse("name: Ann\ntags: [a, b]\n"),
+            Some(json!({"name": "Ann", "tags": ["a", "b"]}))
+        );
+        assert_eq!(DocumentFormat::Json.parse("{"), None);
+    }
+}
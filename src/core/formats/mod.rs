@@ -0,0 +1,3 @@
+pub mod base64_options;
+pub(crate) mod css_color;
+pub mod document_options;
@@ -0,0 +1,42 @@
+use crate::core::identifiers::digits;
+
+/// GS1 check digit: weights 3 and 1 alternate from the rightmost data digit.
+fn gs1_valid(code: &str) -> bool {
+    let Some(digits) = digits(code) else {
+        return false;
+    };
+    let (check, data) = digits.split_last().expect("caller checks the length");
+    let sum: u32 = data
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(index, d)| if index % 2 == 0 { d * 3 } else { *d })
+        .sum();
+    (10 - sum % 10) % 10 == *check
+}
+
+/// EAN-8 or EAN-13.
+pub(crate) fn is_ean(code: &str) -> bool {
+    matches!(code.len(), 8 | 13) && gs1_valid(code)
+}
+
+/// UPC-A.
+pub(crate) fn is_upc(code: &str) -> bool {
+    code.len() == 12 && gs1_valid(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_gs1_digits() {
+        assert!(is_ean("4006381333931"));
+        assert!(is_ean("96385074"));
+        assert!(!is_ean("4006381333932"));
+        assert!(!is_ean("036000291452"));
+        assert!(is_upc("036000291452"));
+        assert!(!is_upc("036000291453"));
+        assert!(!is_upc("03600029145a"));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::core::identifiers::{compact, digits};
+
+/// ISBN-10 or ISBN-13, with optional hyphens or spaces.
+pub(crate) fn is_isbn(value: &str) -> bool {
+    let code = compact(value, &['-', ' ']);
+    match code.len() {
+        10 => mod11_valid(&code, 10),
+        13 => {
+            (code.starts_with("978") || code.starts_with("979"))
+                && digits(&code).is_some_and(|d| {
+                    let sum: u32 = d
+                        .iter()
+                        .enumerate()
+                        .map(|(index, d)| if index % 2 == 0 { *d } else { d * 3 })
+                        .sum();
+                    sum.is_multiple_of(10)
+                })
+        }
+        _ => false,
+    }
+}
+
+/// ISSN, `0317-8471` or `03178471`.
+pub(crate) fn is_issn(value: &str) -> bool {
+    let code = match value.split_once('-') {
+        Some((head, tail)) if head.len() == 4 => format!("{head}{tail}"),
+        Some(_) => return false,
+        None => value.to_string(),
+    };
+    code.len() == 8 && mod11_valid(&code, 8)
+}
+
+/// Weighted mod 11 with weights `weight..=1`; `X` stands for 10 as the last character.
+fn mod11_valid(code: &str, weight: u32) -> bool {
+    let (data, check) = code.split_at(code.len() - 1);
+    let check = match check {
+        "X" => 10,
+        _ => match check.parse::<u32>() {
+            Ok(check) => check,
+            Err(_) => return false,
+        },
+    };
+    let Some(data) = digits(data) else {
+        return false;
+    };
+    let sum: u32 = data
+        .iter()
+        .zip((2..=weight).rev())
+        .map(|(d, w)| d * w)
+        .sum();
+    (sum + check).is_multiple_of(11)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_isbns() {
+        assert!(is_isbn("0-306-40615-2"));
+        assert!(is_isbn("080442957X"));
+        assert!(is_isbn("978-3-16-148410-0"));
+        assert!(!is_isbn("978-3-16-148410-1"));
+        assert!(!is_isbn("0-306-40615-3"));
+        assert!(!is_isbn("123-3-16-148410-0"));
+    }
+
+    #[test]
+    fn validates_issns() {
+        assert!(is_issn("0317-8471"));
+        assert!(is_issn("2434-561X"));
+        assert!(is_issn("03178471"));
+        assert!(!is_issn("0317-8472"));
+        assert!(!is_issn("031-78471"));
+    }
+}
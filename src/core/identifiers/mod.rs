@@ -0,0 +1,13 @@
+pub(crate) mod gtin;
+pub(crate) mod isbn;
+pub mod national;
+pub(crate) mod vat;
+
+/// The value without the separators people type between groups of digits.
+pub(crate) fn compact(value: &str, separators: &[char]) -> String {
+    value.chars().filter(|c| !separators.contains(c)).collect()
+}
+
+pub(crate) fn digits(value: &str) -> Option<Vec<u32>> {
+    value.chars().map(|c| c.to_digit(10)).collect()
+}
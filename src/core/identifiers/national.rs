@@ -0,0 +1,201 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::{compact, digits};
+use crate::core::rules::str_ref::StrAsRef;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub(crate) type IdRule = Arc<dyn for<'a> IValidatorRule<&'a str>>;
+
+pub(crate) static BUILT_IN: Lazy<NationalIdRegistry> = Lazy::new(NationalIdRegistry::new);
+
+/// National identifier validators by country code, used by `national_id(country)`.
+///
+/// Built in: `UA` (РНОКПП, individual tax number), `PL` (PESEL) and `US` (SSN, format only).
+/// Register other countries and check them with `national_id_in(country, &registry)`.
+#[derive(Clone)]
+pub struct NationalIdRegistry {
+    rules: HashMap<String, IdRule>,
+}
+
+impl Default for NationalIdRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NationalIdRegistry {
+    pub fn new() -> Self {
+        Self {
+            rules: HashMap::new(),
+        }
+        .register("UA", Rnokpp)
+        .register("PL", Pesel)
+        .register("US", Ssn)
+    }
+
+    /// Adds or replaces the validator of `country` (ISO 3166-1 alpha-2, case-insensitive).
+    pub fn register<R>(mut self, country: &str, rule: R) -> Self
+    where
+        R: for<'a> IValidatorRule<&'a str> + 'static,
+    {
+        self.rules
+            .insert(country.to_ascii_uppercase(), Arc::new(rule));
+        self
+    }
+
+    pub fn contains(&self, country: &str) -> bool {
+        self.rules.contains_key(&country.to_ascii_uppercase())
+    }
+
+    pub(crate) fn get(&self, country: &str) -> Option<IdRule> {
+        self.rules.get(&country.to_ascii_uppercase()).cloned()
+    }
+}
+
+fn invalid(country: &str) -> ValidationError {
+    ValidationError::new_with_params(
+        "validator.national_id",
+        HashMap::from([("country".into(), country.into())]),
+    )
+}
+
+/// Ukrainian РНОКПП (ІПН): 10 digits, the last one a weighted mod 11 check digit.
+struct Rnokpp;
+
+impl<T: StrAsRef> IValidatorRule<T> for Rnokpp {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(d) = value
+            .as_str_ref()
+            .and_then(digits)
+            .filter(|d| d.len() == 10)
+        else {
+            return Err(invalid("UA"));
+        };
+        let sum: i64 = d[..9]
+            .iter()
+            .zip([-1, 5, 7, 9, 4, 6, 10, 5, 7])
+            .map(|(digit, weight)| *digit as i64 * weight)
+            .sum();
+        match sum.rem_euclid(11) % 10 == d[9] as i64 {
+            true => Ok(()),
+            false => Err(invalid("UA")),
+        }
+    }
+}
+
+/// Polish PESEL: 11 digits encoding the birth date, with a weighted mod 10 check digit.
+struct Pesel;
+
+impl<T: StrAsRef> IValidatorRule<T> for Pesel {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(d) = value
+            .as_str_ref()
+            .and_then(digits)
+            .filter(|d| d.len() == 11)
+        else {
+            return Err(invalid("PL"));
+        };
+        let sum: u32 = d[..10]
+            .iter()
+            .zip([1, 3, 7, 9, 1, 3, 7, 9, 1, 3])
+            .map(|(digit, weight)| digit * weight)
+            .sum();
+
+        // Months 1-12 are the 1900s; +20, +40, +60, +80 shift to 2000, 2100, 2200, 1800.
+        let month = (d[2] * 10 + d[3]) % 20;
+        let day = d[4] * 10 + d[5];
+        let date_valid = (1..=12).contains(&month) && (1..=31).contains(&day);
+        match date_valid && (10 - sum % 10) % 10 == d[10] {
+            true => Ok(()),
+            false => Err(invalid("PL")),
+        }
+    }
+}
+
+/// US Social Security number, `123-45-6789` or `123456789`: never-issued areas (000, 666,
+/// 900-999), group 00 and serial 0000 are rejected. SSNs carry no check digit.
+struct Ssn;
+
+impl<T: StrAsRef> IValidatorRule<T> for Ssn {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Err(invalid("US"));
+        };
+        let dashed =
+            value.len() == 11 && value.as_bytes()[3] == b'-' && value.as_bytes()[6] == b'-';
+        let code = if dashed {
+            compact(value, &['-'])
+        } else {
+            value.to_string()
+        };
+        let valid = code.len() == 9
+            && code.bytes().all(|b| b.is_ascii_digit())
+            && !matches!(&code[..3], "000" | "666")
+            && !code.starts_with('9')
+            && &code[3..5] != "00"
+            && &code[5..] != "0000";
+        match valid {
+            true => Ok(()),
+            false => Err(invalid("US")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid(country: &str, value: &str) -> bool {
+        BUILT_IN.get(country).unwrap().validate(&value).is_ok()
+    }
+
+    #[test]
+    fn validates_ukrainian_tax_numbers() {
+        assert!(valid("UA", "3184710691"));
+        assert!(!valid("UA", "3184710692"));
+        assert!(!valid("ua", "318471069"));
+    }
+
+    #[test]
+    fn validates_pesel() {
+        assert!(valid("PL", "44051401359"));
+        assert!(valid("PL", "02270803624"));
+        assert!(!valid("PL", "44051401358"));
+        assert!(!valid("PL", "44151401357"));
+    }
+
+    #[test]
+    fn validates_ssn_format() {
+        assert!(valid("US", "123-45-6789"));
+        assert!(valid("US", "123456789"));
+        assert!(!valid("US", "666-45-6789"));
+        assert!(!valid("US", "912-45-6789"));
+        assert!(!valid("US", "123-00-6789"));
+        assert!(!valid("US", "123-45-0000"));
+        assert!(!valid("US", "12-345-6789"));
+    }
+
+    struct Cpf;
+
+    impl<T: StrAsRef> IValidatorRule<T> for Cpf {
+        fn validate(&self, value: &T) -> Result<(), ValidationError> {
+            match value.as_str_ref().is_some_and(|v| v.len() == 14) {
+                true => Ok(()),
+                false => Err(ValidationError::new("validator.cpf")),
+            }
+        }
+    }
+
+    #[test]
+    fn registers_more_countries() {
+        let registry = NationalIdRegistry::new().register("br", Cpf);
+        assert!(registry.contains("BR"));
+        let cpf = registry.get("BR").unwrap();
+        assert!(cpf.validate(&"123.456.789-09").is_ok());
+        assert_eq!(cpf.validate(&"123").unwrap_err().key, "validator.cpf");
+        assert!(registry.get("DE").is_none());
+        assert!(!BUILT_IN.contains("BR"));
+    }
+}
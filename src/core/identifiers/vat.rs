@@ -0,0 +1,207 @@
+use crate::core::finance::card::luhn_valid;
+use crate::core::identifiers::{compact, digits};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashMap;
+
+/// VAT number formats of the EU member states (and Northern Ireland, `XI`) without the prefix.
+/// Greece uses `EL`, as in VIES.
+const FORMATS: &[(&str, &str)] = &[
+    ("AT", r"U\d{8}"),
+    ("BE", r"[01]\d{9}"),
+    ("BG", r"\d{9,10}"),
+    ("CY", r"\d{8}[A-Z]"),
+    ("CZ", r"\d{8,10}"),
+    ("DE", r"\d{9}"),
+    ("DK", r"\d{8}"),
+    ("EE", r"\d{9}"),
+    ("EL", r"\d{9}"),
+    ("ES", r"[A-Z0-9]\d{7}[A-Z0-9]"),
+    ("FI", r"\d{8}"),
+    ("FR", r"[A-HJ-NP-Z0-9]{2}\d{9}"),
+    ("HR", r"\d{11}"),
+    ("HU", r"\d{8}"),
+    ("IE", r"\d{7}[A-W][A-IW]?|\d[A-Z+*]\d{5}[A-W]"),
+    ("IT", r"\d{11}"),
+    ("LT", r"\d{9}|\d{12}"),
+    ("LU", r"\d{8}"),
+    ("LV", r"\d{11}"),
+    ("MT", r"\d{8}"),
+    ("NL", r"\d{9}B\d{2}"),
+    ("PL", r"\d{10}"),
+    ("PT", r"\d{9}"),
+    ("RO", r"[1-9]\d{1,9}"),
+    ("SE", r"\d{10}01"),
+    ("SI", r"[1-9]\d{7}"),
+    ("SK", r"[1-9]\d{9}"),
+    ("XI", r"\d{9}|\d{12}|GD\d{3}|HA\d{3}"),
+];
+
+static PATTERNS: Lazy<HashMap<&'static str, Regex>> = Lazy::new(|| {
+    FORMATS
+        .iter()
+        .map(|(country, format)| (*country, Regex::new(&format!("^(?:{format})$")).unwrap()))
+        .collect()
+});
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum VatFailure {
+    Country(String),
+    Format(String),
+    Checksum(String),
+}
+
+/// EU VAT identification number with its country prefix (`DE136695976`). Spaces, dots and
+/// dashes are ignored. Check digits are verified for the countries with a published
+/// algorithm; the others are checked for format only.
+pub(crate) fn validate_vat(value: &str) -> Result<(), VatFailure> {
+    let code = compact(value, &[' ', '.', '-']).to_uppercase();
+    let Some((country, number)) = code.split_at_checked(2) else {
+        return Err(VatFailure::Country(code));
+    };
+    let Some(pattern) = PATTERNS.get(country) else {
+        return Err(VatFailure::Country(country.to_string()));
+    };
+    if !pattern.is_match(number) {
+        return Err(VatFailure::Format(country.to_string()));
+    }
+    if !checksum_valid(country, number) {
+        return Err(VatFailure::Checksum(country.to_string()));
+    }
+    Ok(())
+}
+
+fn weighted_sum(digits: &[u32], weights: &[u32]) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// ISO 7064 MOD 11,10 (Germany, Croatia).
+fn mod11_10_valid(d: &[u32]) -> bool {
+    let (check, data) = d.split_last().unwrap();
+    let mut product = 10;
+    for digit in data {
+        let sum = match (digit + product) % 10 {
+            0 => 10,
+            sum => sum,
+        };
+        product = (2 * sum) % 11;
+    }
+    (11 - product) % 10 == *check
+}
+
+fn checksum_valid(country: &str, number: &str) -> bool {
+    let numeric = digits(number);
+    let d = numeric.as_deref().unwrap_or_default();
+    match country {
+        "AT" => {
+            let d = digits(&number[1..]).unwrap_or_default();
+            let sum: u32 = d[..7]
+                .iter()
+                .enumerate()
+                .map(|(index, digit)| match index % 2 {
+                    0 => *digit,
+                    _ => digit * 2 / 10 + digit * 2 % 10,
+                })
+                .sum();
+            (10 - (sum + 4) % 10) % 10 == d[7]
+        }
+        "BE" => {
+            let head: u64 = number[..8].parse().unwrap();
+            let tail: u64 = number[8..].parse().unwrap();
+            97 - head % 97 == tail
+        }
+        "DE" | "HR" => mod11_10_valid(d),
+        "DK" => weighted_sum(d, &[2, 7, 6, 5, 4, 3, 2, 1]).is_multiple_of(11),
+        "EL" => weighted_sum(d, &[256, 128, 64, 32, 16, 8, 4, 2]) % 11 % 10 == d[8],
+        "FI" => match weighted_sum(d, &[7, 9, 10, 5, 8, 4, 2]) % 11 {
+            0 => d[7] == 0,
+            1 => false,
+            r => 11 - r == d[7],
+        },
+        "FR" => match number[..2].parse::<u64>() {
+            Ok(key) => {
+                let siren: u64 = number[2..].parse().unwrap();
+                key == (12 + 3 * (siren % 97)) % 97
+            }
+            Err(_) => true,
+        },
+        "IT" => luhn_valid(number),
+        "LU" => number[..6].parse::<u32>().unwrap() % 89 == number[6..].parse::<u32>().unwrap(),
+        "NL" => {
+            let d = digits(&number[..9]).unwrap();
+            let legacy = weighted_sum(&d, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 == d[8];
+            // Sole traders since 2020: ISO 7064 mod 97 over the whole identifier.
+            let modern = format!("NL{number}")
+                .chars()
+                .fold(0u32, |acc, c| match c.to_digit(10) {
+                    Some(digit) => (acc * 10 + digit) % 97,
+                    None => (acc * 100 + (c as u32 - 'A' as u32 + 10)) % 97,
+                })
+                == 1;
+            legacy || modern
+        }
+        "PL" => weighted_sum(d, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9],
+        "PT" => match 11 - weighted_sum(d, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11 {
+            10 | 11 => d[8] == 0,
+            check => check == d[8],
+        },
+        "SE" => luhn_valid(&number[..10]),
+        "SI" => match 11 - weighted_sum(d, &[8, 7, 6, 5, 4, 3, 2]) % 11 {
+            10 => d[7] == 0,
+            11 => false,
+            check => check == d[7],
+        },
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_vat_numbers() {
+        for vat in [
+            "ATU13585627",
+            "BE0776091951",
+            "DE136695976",
+            "DK13585628",
+            "EL094259216",
+            "FI20774740",
+            "FR40303265045",
+            "HR33392005961",
+            "IT00743110157",
+            "LU15027442",
+            "NL004495445B01",
+            "PL5260250274",
+            "PT501964843",
+            "SE556188840401",
+            "SI50223054",
+            "ESA28015865",
+            "de 136.695.976",
+        ] {
+            assert_eq!(validate_vat(vat), Ok(()), "{vat}");
+        }
+    }
+
+    #[test]
+    fn reports_country_format_and_checksum() {
+        assert_eq!(
+            validate_vat("US123456789"),
+            Err(VatFailure::Country("US".into()))
+        );
+        assert_eq!(validate_vat("D"), Err(VatFailure::Country("D".into())));
+        assert_eq!(
+            validate_vat("DE12345678"),
+            Err(VatFailure::Format("DE".into()))
+        );
+        assert_eq!(
+            validate_vat("DE136695975"),
+            Err(VatFailure::Checksum("DE".into()))
+        );
+        assert_eq!(
+            validate_vat("PL5260250275"),
+            Err(VatFailure::Checksum("PL".into()))
+        );
+    }
+}
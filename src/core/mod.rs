@@ -1,11 +1,30 @@
 pub mod builder;
+pub mod codes;
+pub mod context;
 pub mod contract;
 pub mod errors;
 pub(crate) mod field_builder;
+pub mod finance;
+pub mod formats;
+pub mod identifiers;
 pub(crate) mod nested_wrapper;
+pub mod net;
+pub mod password;
+pub mod path_case;
 pub(crate) mod primitive;
 pub mod rules;
+pub mod sanitize;
+pub mod schema;
+pub mod spec;
+pub mod temporal;
+pub mod text;
 pub mod valida_error;
+pub mod value_validator;
 
-#[cfg(feature = "i18n-localization")]
+#[cfg(any(
+    feature = "i18n-localization",
+    feature = "utoipa",
+    feature = "dns",
+    feature = "phone"
+))]
 pub mod features;
@@ -1,6 +1,11 @@
+use crate::core::context::ValidationContext;
 use crate::core::errors::ValidationErrors;
+use crate::core::formats::document_options::DocumentFormat;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::{NestedShape, ValidatorDescriptor};
 use crate::prelude::{IValidate, RulesBuilder, ValidatorFailure};
 use async_trait::async_trait;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::error::Error;
 use std::hash::Hash;
@@ -8,6 +13,7 @@ use std::marker::PhantomData;
 use std::sync::Arc;
 
 type Accessor<T, V> = Arc<dyn Fn(&T) -> &V + Send + Sync>;
+type AccessorMut<T, V> = Arc<dyn Fn(&mut T) -> &mut V + Send + Sync>;
 
 pub struct NestedArcOptionValidatorWrapper<T, V, E>
 where
@@ -17,6 +23,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, Arc<Option<V>>>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, Arc<Option<V>>>>,
     pub inner: Box<dyn IValidate<V, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -32,9 +40,29 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut
+            && let Some(Some(inner)) = Arc::get_mut(accessor(dto)).map(Option::as_mut)
+        {
+            self.inner.sanitize(inner);
+        }
+    }
+
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::Optional
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         match (self.accessor)(dto).as_ref() {
-            Some(inner) => self.inner.validate(inner).await,
+            Some(inner) => self.inner.validate_with_context(inner, ctx).await,
             None => Ok(()),
         }
     }
@@ -48,6 +76,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, Arc<V>>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, Arc<V>>>,
     pub inner: Box<dyn IValidate<V, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -63,9 +93,27 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut
+            && let Some(inner) = Arc::get_mut(accessor(dto))
+        {
+            self.inner.sanitize(inner);
+        }
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         let arc_ref = (self.accessor)(dto);
-        self.inner.validate(arc_ref.as_ref()).await
+        self.inner
+            .validate_with_context(arc_ref.as_ref(), ctx)
+            .await
     }
 }
 
@@ -77,6 +125,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, Vec<U>>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, Vec<U>>>,
     pub inner: Box<dyn IValidate<U, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -92,12 +142,32 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut {
+            accessor(dto)
+                .iter_mut()
+                .for_each(|item| self.inner.sanitize(item));
+        }
+    }
+
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::List
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         let list = (self.accessor)(dto);
         let mut all_errors = ValidationErrors::default();
 
         for (i, item) in list.iter().enumerate() {
-            match self.inner.validate(item).await {
+            match self.inner.validate_with_context(item, ctx).await {
                 Ok(_) => {}
                 Err(ValidatorFailure::Invalid(nested)) => {
                     all_errors.add_nested(vec![i.to_string()], nested);
@@ -122,6 +192,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, Option<V>>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, Option<V>>>,
     pub inner: Box<dyn IValidate<V, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -136,9 +208,29 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut
+            && let Some(inner) = accessor(dto).as_mut()
+        {
+            self.inner.sanitize(inner);
+        }
+    }
+
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::Optional
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         match (self.accessor)(dto) {
-            Some(inner_value) => match self.inner.validate(inner_value).await {
+            Some(inner_value) => match self.inner.validate_with_context(inner_value, ctx).await {
                 Ok(_) => Ok(()),
                 Err(e) => Err(e),
             },
@@ -156,6 +248,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, HashMap<K, U>>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, HashMap<K, U>>>,
     pub inner: Box<dyn IValidate<U, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -172,12 +266,32 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut {
+            accessor(dto)
+                .values_mut()
+                .for_each(|value| self.inner.sanitize(value));
+        }
+    }
+
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::Map
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         let map = (self.accessor)(dto);
         let mut all_errors = ValidationErrors::default();
 
         for (key, value) in map {
-            match self.inner.validate(value).await {
+            match self.inner.validate_with_context(value, ctx).await {
                 Ok(_) => {}
                 Err(ValidatorFailure::Invalid(nested)) => {
                     all_errors.add_nested(vec![key.to_string()], nested);
@@ -201,6 +315,8 @@ where
     #[allow(dead_code)]
     pub field_name: &'static str,
     pub accessor: Accessor<T, U>,
+    /// Set by `nested_mut`; without it `sanitize` leaves the nested value alone.
+    pub accessor_mut: Option<AccessorMut<T, U>>,
     pub inner: Box<dyn IValidate<U, E>>,
     pub _phantom: PhantomData<T>,
 }
@@ -216,8 +332,70 @@ where
         builder
     }
 
-    async fn validate(&self, dto: &T) -> Result<(), ValidatorFailure<E>> {
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn sanitize(&self, dto: &mut T) {
+        if let Some(accessor) = &self.accessor_mut {
+            self.inner.sanitize(accessor(dto));
+        }
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
         let value = (self.accessor)(dto);
-        self.inner.validate(value).await
+        self.inner.validate_with_context(value, ctx).await
+    }
+}
+
+/// Validates the document parsed from a string field. Values that do not parse are skipped:
+/// reporting them is up to the `json`/`yaml` rule of the field.
+pub struct NestedDocumentValidatorWrapper<T, V, E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[allow(dead_code)]
+    pub field_name: &'static str,
+    pub accessor: Accessor<T, V>,
+    pub inner: Box<dyn IValidate<Value, E>>,
+    pub(crate) format: DocumentFormat,
+    pub _phantom: PhantomData<T>,
+}
+
+#[async_trait]
+impl<T, V, E> IValidate<T, E> for NestedDocumentValidatorWrapper<T, V, E>
+where
+    T: Send + Sync + 'static,
+    V: StrAsRef + Send + Sync + 'static,
+    E: Error + Send + Sync + 'static,
+{
+    fn rules(&self, builder: RulesBuilder<T, E>) -> RulesBuilder<T, E> {
+        builder
+    }
+
+    fn describe(&self) -> ValidatorDescriptor {
+        self.inner.describe()
+    }
+
+    fn nested_shape(&self) -> NestedShape {
+        NestedShape::Document
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
+        let document = (self.accessor)(dto)
+            .as_str_ref()
+            .and_then(|source| self.format.parse(source));
+        match document {
+            Some(document) => self.inner.validate_with_context(&document, ctx).await,
+            None => Ok(()),
+        }
     }
 }
@@ -0,0 +1,281 @@
+use crate::core::contract::IValidatorRuleCustomAsync;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use async_trait::async_trait;
+use idna::domain_to_ascii;
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+const MAX_CACHED_DOMAINS: usize = 4096;
+
+/// DNS lookups needed by `EmailDeliverable`. A missing domain or record set is `Ok` with an
+/// empty answer; `Err` is reserved for failures (timeouts, unreachable servers).
+#[async_trait]
+pub trait DnsResolver: Send + Sync {
+    type Error: Error + Send + Sync + 'static;
+
+    /// Exchange hosts of the MX records of `domain` (ASCII form).
+    async fn mx_hosts(&self, domain: &str) -> Result<Vec<String>, Self::Error>;
+
+    /// Whether `domain` has an A or AAAA record.
+    async fn has_address(&self, domain: &str) -> Result<bool, Self::Error>;
+}
+
+/// Async rule: the domain of an email address must accept mail, i.e. have an MX record
+/// or, failing that, an address record (RFC 5321 §5.1). A null MX (RFC 7505) is rejected.
+///
+/// Answers are cached per domain for `ttl` (5 minutes by default); resolver errors are not.
+/// Validators build their rules on every run, so keep one `EmailDeliverable` and clone it
+/// into `custom_async`: clones share the resolver and the cache.
+///
+/// Values that are not email addresses are skipped; combine it with `email()`.
+pub struct EmailDeliverable<R> {
+    resolver: Arc<R>,
+    cache: Arc<Mutex<HashMap<String, (bool, Instant)>>>,
+    ttl: Duration,
+}
+
+impl<R> Clone for EmailDeliverable<R> {
+    fn clone(&self) -> Self {
+        Self {
+            resolver: self.resolver.clone(),
+            cache: self.cache.clone(),
+            ttl: self.ttl,
+        }
+    }
+}
+
+impl<R: DnsResolver> EmailDeliverable<R> {
+    pub fn new(resolver: R) -> Self {
+        Self {
+            resolver: Arc::new(resolver),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            ttl: Duration::from_secs(300),
+        }
+    }
+
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Resolves (or reads from the cache) whether `domain` accepts mail.
+    pub async fn accepts_mail(&self, domain: &str) -> Result<bool, R::Error> {
+        if let Some(&(accepts, at)) = self.cache.lock().unwrap().get(domain)
+            && at.elapsed() < self.ttl
+        {
+            return Ok(accepts);
+        }
+
+        let hosts = self.resolver.mx_hosts(domain).await?;
+        let accepts = if hosts.is_empty() {
+            self.resolver.has_address(domain).await?
+        } else {
+            !hosts
+                .iter()
+                .all(|host| host.trim_end_matches('.').is_empty())
+        };
+
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= MAX_CACHED_DOMAINS && !cache.contains_key(domain) {
+            let ttl = self.ttl;
+            cache.retain(|_, (_, at)| at.elapsed() < ttl);
+        }
+        // still full of live answers: make room by dropping the oldest one
+        if cache.len() >= MAX_CACHED_DOMAINS && !cache.contains_key(domain) {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, (_, at))| *at)
+                .map(|(domain, _)| domain.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(domain.to_string(), (accepts, Instant::now()));
+        Ok(accepts)
+    }
+}
+
+fn email_domain(email: &str) -> Option<String> {
+    let (_, domain) = email.trim().rsplit_once('@')?;
+    let domain = domain_to_ascii(domain).ok()?;
+    (!domain.is_empty()).then(|| domain.trim_end_matches('.').to_string())
+}
+
+#[async_trait]
+impl<T, E, R> IValidatorRuleCustomAsync<T, E> for EmailDeliverable<R>
+where
+    T: StrAsRef + Sync,
+    R: DnsResolver,
+    E: Error + From<R::Error> + Send + Sync + 'static,
+{
+    async fn validate(&self, value: &T) -> Result<Option<ValidationError>, E> {
+        let Some(domain) = value.as_str_ref().and_then(email_domain) else {
+            return Ok(None);
+        };
+        if self.accepts_mail(&domain).await? {
+            return Ok(None);
+        }
+        Ok(Some(ValidationError::new_with_params(
+            "validator.email.no_mx",
+            HashMap::from([("domain".into(), domain)]),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use valida::prelude::*;
+
+    #[derive(Default)]
+    struct StaticResolver {
+        mx: HashMap<&'static str, Vec<&'static str>>,
+        addresses: Vec<&'static str>,
+        lookups: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl DnsResolver for StaticResolver {
+        type Error = io::Error;
+
+        async fn mx_hosts(&self, domain: &str) -> Result<Vec<String>, io::Error> {
+            self.lookups.fetch_add(1, Ordering::SeqCst);
+            if domain == "timeout.test" {
+                return Err(io::Error::new(io::ErrorKind::TimedOut, "dns timeout"));
+            }
+            let hosts = self.mx.get(domain).cloned().unwrap_or_default();
+            Ok(hosts.into_iter().map(String::from).collect())
+        }
+
+        async fn has_address(&self, domain: &str) -> Result<bool, io::Error> {
+            Ok(self.addresses.contains(&domain))
+        }
+    }
+
+    fn rule() -> EmailDeliverable<StaticResolver> {
+        EmailDeliverable::new(StaticResolver {
+            mx: HashMap::from([
+                ("example.com", vec!["mx1.example.com."]),
+                (
+                    "xn--80aikifvh.xn--j1amh",
+                    vec!["mx.xn--80aikifvh.xn--j1amh."],
+                ),
+                ("nomail.test", vec!["."]),
+            ]),
+            addresses: vec!["a-only.test"],
+            ..Default::default()
+        })
+    }
+
+    async fn key(rule: &EmailDeliverable<StaticResolver>, email: &str) -> Option<String> {
+        let result: Result<_, io::Error> = rule.validate(&email.to_string()).await;
+        result.unwrap().map(|err| err.key)
+    }
+
+    #[tokio::test]
+    async fn accepts_domains_with_mx_or_address_records() {
+        let rule = rule();
+        assert_eq!(key(&rule, "user@example.com").await, None);
+        assert_eq!(key(&rule, "user@EXAMPLE.com").await, None);
+        assert_eq!(key(&rule, "юзер@приклад.укр").await, None);
+        assert_eq!(key(&rule, "user@a-only.test").await, None);
+        assert_eq!(key(&rule, "not an email").await, None);
+    }
+
+    #[tokio::test]
+    async fn rejects_domains_without_mail_hosts() {
+        let rule = rule();
+        let err: Result<_, io::Error> = rule.validate(&"user@gone.test".to_string()).await;
+        let err = err.unwrap().unwrap();
+        assert_eq!(err.key, "validator.email.no_mx");
+        assert_eq!(err.params["domain"], "gone.test");
+        assert_eq!(
+            key(&rule, "user@nomail.test").await.as_deref(),
+            Some("validator.email.no_mx")
+        );
+    }
+
+    #[tokio::test]
+    async fn caches_answers_until_ttl_expires() {
+        let rule = rule();
+        let shared = rule.clone();
+        key(&rule, "a@example.com").await;
+        key(&shared, "b@example.com").await;
+        assert_eq!(rule.resolver.lookups.load(Ordering::SeqCst), 1);
+
+        let expired = rule.clone().ttl(Duration::ZERO);
+        key(&expired, "a@example.com").await;
+        assert_eq!(rule.resolver.lookups.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn caps_the_cache_by_evicting_the_oldest_answer() {
+        let rule = rule();
+        for i in 0..MAX_CACHED_DOMAINS + 2 {
+            rule.accepts_mail(&format!("d{i}.test")).await.unwrap();
+        }
+
+        let cache = rule.cache.lock().unwrap();
+        assert_eq!(cache.len(), MAX_CACHED_DOMAINS);
+        assert!(!cache.contains_key("d0.test"));
+        assert!(!cache.contains_key("d1.test"));
+        assert!(cache.contains_key(&format!("d{}.test", MAX_CACHED_DOMAINS + 1)));
+    }
+
+    #[tokio::test]
+    async fn reports_resolver_failures_as_system_errors() {
+        let rule = rule();
+        let result: Result<_, io::Error> = rule.validate(&"user@timeout.test".to_string()).await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::TimedOut);
+        assert!(rule.cache.lock().unwrap().is_empty());
+    }
+
+    struct Signup {
+        email: String,
+    }
+
+    struct SignupValidator {
+        deliverable: EmailDeliverable<StaticResolver>,
+    }
+
+    impl IValidate<Signup, io::Error> for SignupValidator {
+        fn rules(&self, mut b: RulesBuilder<Signup, io::Error>) -> RulesBuilder<Signup, io::Error> {
+            b.field("email", |s| &s.email)
+                .email()
+                .custom_async(self.deliverable.clone())
+                .build();
+            b
+        }
+    }
+
+    #[tokio::test]
+    async fn works_as_custom_async_rule() {
+        let validator = SignupValidator {
+            deliverable: rule(),
+        };
+        let ok = Signup {
+            email: "user@example.com".into(),
+        };
+        assert!(validator.validate(&ok).await.is_ok());
+
+        let bad = Signup {
+            email: "user@gone.test".into(),
+        };
+        match validator.validate(&bad).await {
+            Err(ValidatorFailure::Invalid(errors)) => {
+                assert!(
+                    errors
+                        .to_json_raw()
+                        .to_string()
+                        .contains("validator.email.no_mx")
+                )
+            }
+            _ => panic!("Should be invalid"),
+        }
+    }
+}
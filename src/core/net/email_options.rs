@@ -0,0 +1,108 @@
+use crate::core::net::url_options::{host_matches, normalize_pattern};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
+static DISPOSABLE_DOMAINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    include_str!("../../../data/disposable_domains.txt")
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+});
+
+/// Policy for `email_with`. `EmailOptions::new()` accepts everything `email()` does.
+///
+/// Domain patterns follow `UrlOptions`: exact (`example.com`) or subdomains only (`*.example.com`).
+/// The disposable list is bundled with the crate and matches subdomains of listed providers.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EmailOptions {
+    pub(crate) ascii_only: bool,
+    pub(crate) allowed_domains: Vec<String>,
+    pub(crate) denied_domains: Vec<String>,
+    pub(crate) block_disposable: bool,
+    pub(crate) require_tld: bool,
+    pub(crate) allow_display_name: bool,
+}
+
+impl EmailOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rejects internationalized addresses such as `юзер@приклад.укр`.
+    pub fn ascii_only(mut self, ascii_only: bool) -> Self {
+        self.ascii_only = ascii_only;
+        self
+    }
+
+    pub fn allow_domains(mut self, domains: &[&str]) -> Self {
+        self.allowed_domains = domains.iter().map(|d| normalize_pattern(d)).collect();
+        self
+    }
+
+    pub fn deny_domains(mut self, domains: &[&str]) -> Self {
+        self.denied_domains = domains.iter().map(|d| normalize_pattern(d)).collect();
+        self
+    }
+
+    /// Rejects throwaway providers such as `mailinator.com`.
+    pub fn block_disposable(mut self, block: bool) -> Self {
+        self.block_disposable = block;
+        self
+    }
+
+    /// Requires an alphabetic top-level domain of at least two characters.
+    pub fn require_tld(mut self, require: bool) -> Self {
+        self.require_tld = require;
+        self
+    }
+
+    /// Accepts `Jane Doe <jane@example.com>` and validates the address inside the brackets.
+    pub fn allow_display_name(mut self, allow: bool) -> Self {
+        self.allow_display_name = allow;
+        self
+    }
+
+    pub(crate) fn domain_allowed(&self, domain: &str) -> bool {
+        let matches = |patterns: &[String]| patterns.iter().any(|p| host_matches(p, domain));
+        (self.allowed_domains.is_empty() || matches(&self.allowed_domains))
+            && !matches(&self.denied_domains)
+    }
+}
+
+/// True when `domain` (ASCII, lowercase) or one of its parents is a known disposable provider.
+pub(crate) fn is_disposable(domain: &str) -> bool {
+    let mut rest = domain.trim_end_matches('.');
+    loop {
+        if DISPOSABLE_DOMAINS.contains(rest) {
+            return true;
+        }
+        match rest.split_once('.') {
+            Some((_, parent)) => rest = parent,
+            None => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_disposable_domains_and_subdomains() {
+        assert!(is_disposable("mailinator.com"));
+        assert!(is_disposable("eu.mailinator.com"));
+        assert!(!is_disposable("example.com"));
+        assert!(!is_disposable("notmailinator.com"));
+    }
+
+    #[test]
+    fn deny_list_wins() {
+        let options = EmailOptions::new()
+            .allow_domains(&["*.corp.example"])
+            .deny_domains(&["old.corp.example"]);
+        assert!(options.domain_allowed("mail.corp.example"));
+        assert!(!options.domain_allowed("old.corp.example"));
+        assert!(!options.domain_allowed("gmail.com"));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod dns;
+pub mod email_options;
+pub mod network;
+pub mod pwned;
+pub mod special;
+pub mod url_options;
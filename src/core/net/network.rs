@@ -0,0 +1,186 @@
+use crate::core::valida_error::ValidaError;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// An IPv4 or IPv6 CIDR block such as `10.0.0.0/8`. Host bits are cleared on construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpNetwork {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    pub fn new(addr: IpAddr, prefix: u8) -> Result<Self, ValidaError> {
+        let max = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        if prefix > max {
+            return Err(ValidaError::InvalidCidr(format!("{addr}/{prefix}")));
+        }
+        Ok(Self {
+            addr: mask(addr, prefix),
+            prefix,
+        })
+    }
+
+    pub(crate) const fn v4(a: u8, b: u8, c: u8, d: u8, prefix: u8) -> Self {
+        Self {
+            addr: IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+            prefix,
+        }
+    }
+
+    pub(crate) const fn v6(segments: [u16; 8], prefix: u8) -> Self {
+        let [a, b, c, d, e, f, g, h] = segments;
+        Self {
+            addr: IpAddr::V6(Ipv6Addr::new(a, b, c, d, e, f, g, h)),
+            prefix,
+        }
+    }
+
+    /// `10.0.0.0/8`, `2001:db8::/32`; a bare address is a single-host network.
+    pub fn parse(value: &str) -> Result<Self, ValidaError> {
+        let invalid = || ValidaError::InvalidCidr(value.to_string());
+
+        let (addr, prefix) = match value.split_once('/') {
+            Some((addr, prefix)) => (addr, Some(prefix)),
+            None => (value, None),
+        };
+        let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+        let prefix = match prefix {
+            Some(prefix) if prefix.bytes().all(|b| b.is_ascii_digit()) => {
+                prefix.parse().map_err(|_| invalid())?
+            }
+            Some(_) => return Err(invalid()),
+            None if addr.is_ipv4() => 32,
+            None => 128,
+        };
+
+        Self::new(addr, prefix).map_err(|_| invalid())
+    }
+
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// IPv4-mapped IPv6 addresses (`::ffff:10.0.0.1`) match IPv4 networks.
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        let ip = match ip {
+            IpAddr::V6(v6) if self.addr.is_ipv4() => match v6.to_ipv4_mapped() {
+                Some(v4) => IpAddr::V4(v4),
+                None => return false,
+            },
+            ip => *ip,
+        };
+
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                prefix_eq(&net.octets(), &ip.octets(), self.prefix)
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                prefix_eq(&net.octets(), &ip.octets(), self.prefix)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn prefix_eq(net: &[u8], ip: &[u8], prefix: u8) -> bool {
+    let full = (prefix / 8) as usize;
+    let rest = prefix % 8;
+
+    if net[..full] != ip[..full] {
+        return false;
+    }
+    if rest == 0 {
+        return true;
+    }
+    let bits = 0xffu8 << (8 - rest);
+    net[full] & bits == ip[full] & bits
+}
+
+fn mask(addr: IpAddr, prefix: u8) -> IpAddr {
+    match addr {
+        IpAddr::V4(v4) => {
+            let bits = u32::MAX.checked_shl(32 - prefix as u32).unwrap_or(0);
+            IpAddr::V4(Ipv4Addr::from(u32::from(v4) & bits))
+        }
+        IpAddr::V6(v6) => {
+            let bits = u128::MAX.checked_shl(128 - prefix as u32).unwrap_or(0);
+            IpAddr::V6(Ipv6Addr::from(u128::from(v6) & bits))
+        }
+    }
+}
+
+impl FromStr for IpNetwork {
+    type Err = ValidaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for IpNetwork {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_normalizes() {
+        let net = IpNetwork::parse("192.168.1.77/24").unwrap();
+        assert_eq!(net.to_string(), "192.168.1.0/24");
+        assert_eq!(IpNetwork::parse("10.0.0.1").unwrap().prefix(), 32);
+        assert_eq!(
+            IpNetwork::parse("2001:db8::1/32").unwrap().to_string(),
+            "2001:db8::/32"
+        );
+        assert_eq!(IpNetwork::parse("::/0").unwrap().prefix(), 0);
+    }
+
+    #[test]
+    fn rejects_malformed() {
+        assert!(IpNetwork::parse("10.0.0.0/33").is_err());
+        assert!(IpNetwork::parse("10.0.0.0/+8").is_err());
+        assert!(IpNetwork::parse("10.0.0/8").is_err());
+        assert!(IpNetwork::parse("::/129").is_err());
+        assert_eq!(
+            IpNetwork::parse("nope/8").unwrap_err().to_string(),
+            "Invalid CIDR block: nope/8"
+        );
+    }
+
+    #[test]
+    fn contains_addresses() {
+        let net = IpNetwork::parse("172.16.0.0/12").unwrap();
+        assert!(net.contains(&ip("172.31.255.255")));
+        assert!(!net.contains(&ip("172.32.0.0")));
+        assert!(net.contains(&ip("::ffff:172.16.0.1")));
+        assert!(!net.contains(&ip("2001:db8::1")));
+
+        let net = IpNetwork::parse("fe80::/10").unwrap();
+        assert!(net.contains(&ip("febf::1")));
+        assert!(!net.contains(&ip("fec0::1")));
+        assert!(!net.contains(&ip("10.0.0.1")));
+
+        assert!(
+            IpNetwork::parse("0.0.0.0/0")
+                .unwrap()
+                .contains(&ip("8.8.8.8"))
+        );
+    }
+}
@@ -0,0 +1,284 @@
+use crate::core::contract::IValidatorRuleCustomAsync;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use async_trait::async_trait;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Write as _;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const PREFIX_LEN: usize = 5;
+
+/// Source of the Pwned Passwords range API: for a 5-character SHA-1 prefix it returns
+/// `SUFFIX:COUNT` lines, one per breached hash starting with that prefix.
+///
+/// Online fetchers call `GET https://api.pwnedpasswords.com/range/{prefix}`; only the prefix
+/// leaves the process (k-anonymity).
+#[async_trait]
+pub trait RangeFetcher: Send + Sync {
+    type Error: Error + Send + Sync + 'static;
+
+    async fn fetch_range(&self, prefix: &str) -> Result<String, Self::Error>;
+}
+
+/// Offline `RangeFetcher` over a local copy of the hash list: one uppercase SHA-1 per line,
+/// optionally followed by `:COUNT`, sorted by hash (the format of the official download).
+/// Lookups binary-search the file and block the current thread while reading it.
+pub struct LocalHashFile {
+    path: PathBuf,
+}
+
+impl LocalHashFile {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Position of the first line starting at or after `pos`, and that line.
+    fn line_at(reader: &mut BufReader<File>, pos: u64) -> io::Result<(u64, String)> {
+        let mut start = pos;
+        reader.seek(SeekFrom::Start(pos.saturating_sub(1)))?;
+        if pos > 0 {
+            let mut skipped = Vec::new();
+            start = pos - 1 + reader.read_until(b'\n', &mut skipped)? as u64;
+        }
+        let mut line = String::new();
+        reader.read_line(&mut line)?;
+        Ok((start, line))
+    }
+
+    fn read_range(&self, prefix: &str) -> io::Result<String> {
+        let file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        let mut reader = BufReader::new(file);
+
+        // Smallest position whose next line is >= prefix (or EOF).
+        let (mut lo, mut hi) = (0, len);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let (_, line) = Self::line_at(&mut reader, mid)?;
+            if !line.is_empty() && *line_key(&line) < *prefix {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+
+        let (start, _) = Self::line_at(&mut reader, lo)?;
+        reader.seek(SeekFrom::Start(start))?;
+        let mut range = String::new();
+        for line in reader.lines() {
+            let line = line?;
+            let key = line_key(&line);
+            if key != prefix {
+                break;
+            }
+            let (hash, count) = line.trim().split_once(':').unwrap_or((line.trim(), "1"));
+            let _ = writeln!(range, "{}:{count}", &hash[PREFIX_LEN..]);
+        }
+        Ok(range)
+    }
+}
+
+fn line_key(line: &str) -> String {
+    line.chars()
+        .take(PREFIX_LEN)
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+#[async_trait]
+impl RangeFetcher for LocalHashFile {
+    type Error = io::Error;
+
+    async fn fetch_range(&self, prefix: &str) -> Result<String, io::Error> {
+        self.read_range(prefix)
+    }
+}
+
+/// Async rule: rejects passwords found in the Pwned Passwords corpus at least `threshold`
+/// times (1 by default) with `validator.password_pwned`. Fetch errors are system errors.
+pub struct PasswordNotPwned<F> {
+    fetcher: Arc<F>,
+    threshold: u64,
+}
+
+impl<F> Clone for PasswordNotPwned<F> {
+    fn clone(&self) -> Self {
+        Self {
+            fetcher: self.fetcher.clone(),
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl<F: RangeFetcher> PasswordNotPwned<F> {
+    pub fn new(fetcher: F) -> Self {
+        Self {
+            fetcher: Arc::new(fetcher),
+            threshold: 1,
+        }
+    }
+
+    pub fn threshold(mut self, threshold: u64) -> Self {
+        self.threshold = threshold.max(1);
+        self
+    }
+
+    /// How many times the password appears in the corpus.
+    pub async fn breach_count(&self, password: &str) -> Result<u64, F::Error> {
+        let hash = Sha1::digest(password.as_bytes()).iter().fold(
+            String::with_capacity(40),
+            |mut hex, byte| {
+                let _ = write!(hex, "{byte:02X}");
+                hex
+            },
+        );
+        let (prefix, suffix) = hash.split_at(PREFIX_LEN);
+
+        let range = self.fetcher.fetch_range(prefix).await?;
+        Ok(range
+            .lines()
+            .filter_map(|line| line.trim().split_once(':'))
+            .find(|(candidate, _)| candidate.eq_ignore_ascii_case(suffix))
+            .and_then(|(_, count)| count.trim().parse().ok())
+            .unwrap_or(0))
+    }
+}
+
+#[async_trait]
+impl<T, E, F> IValidatorRuleCustomAsync<T, E> for PasswordNotPwned<F>
+where
+    T: StrAsRef + Sync,
+    F: RangeFetcher,
+    E: Error + From<F::Error> + Send + Sync + 'static,
+{
+    async fn validate(&self, value: &T) -> Result<Option<ValidationError>, E> {
+        let Some(password) = value.as_str_ref().filter(|p| !p.is_empty()) else {
+            return Ok(None);
+        };
+        let count = self.breach_count(password).await?;
+        if count < self.threshold {
+            return Ok(None);
+        }
+        Ok(Some(ValidationError::new_with_params(
+            "validator.password_pwned",
+            HashMap::from([("count".into(), count.to_string())]),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    // SHA-1("password") = 5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8
+    struct StaticRanges(HashMap<&'static str, &'static str>);
+
+    #[async_trait]
+    impl RangeFetcher for StaticRanges {
+        type Error = io::Error;
+
+        async fn fetch_range(&self, prefix: &str) -> Result<String, io::Error> {
+            match self.0.get(prefix) {
+                Some(body) => Ok(body.to_string()),
+                None => Err(io::Error::new(io::ErrorKind::NotFound, "unexpected prefix")),
+            }
+        }
+    }
+
+    fn online() -> PasswordNotPwned<StaticRanges> {
+        PasswordNotPwned::new(StaticRanges(HashMap::from([
+            (
+                "5BAA6",
+                "003D68EB55068C33ACE09247EE4C639306B:3\r\n1E4C9B93F3F0682250B6CF8331B7EE68FD8:9659365\r\n",
+            ),
+            // SHA-1("correct horse battery staple") = ABF7AAD6438836DBE526AA231ABDE2D0EEF74D42
+            ("ABF7A", "0000000000000000000000000000000000A:0\n"),
+        ])))
+    }
+
+    async fn key<F: RangeFetcher<Error = io::Error>>(
+        rule: &PasswordNotPwned<F>,
+        password: &str,
+    ) -> Option<String> {
+        let result: Result<_, io::Error> = rule.validate(&password.to_string()).await;
+        result.unwrap().map(|err| err.key)
+    }
+
+    #[tokio::test]
+    async fn rejects_breached_passwords() {
+        let rule = online();
+        assert_eq!(rule.breach_count("password").await.unwrap(), 9659365);
+
+        let result: Result<_, io::Error> = rule.validate(&"password".to_string()).await;
+        let err = result.unwrap().unwrap();
+        assert_eq!(err.key, "validator.password_pwned");
+        assert_eq!(err.params["count"], "9659365");
+    }
+
+    #[tokio::test]
+    async fn accepts_unknown_passwords_and_respects_threshold() {
+        let rule = online();
+        assert_eq!(key(&rule, "correct horse battery staple").await, None);
+        assert_eq!(key(&rule, "").await, None);
+        assert_eq!(key(&rule.threshold(10_000_000), "password").await, None);
+    }
+
+    #[tokio::test]
+    async fn reports_fetch_failures_as_system_errors() {
+        let result: Result<_, io::Error> = online().validate(&"hunter2".to_string()).await;
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    fn hash_file(name: &str, lines: &[&str]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("valida-{name}-{}.txt", std::process::id()));
+        let mut file = File::create(&path).unwrap();
+        for line in lines {
+            writeln!(file, "{line}").unwrap();
+        }
+        path
+    }
+
+    #[tokio::test]
+    async fn reads_ranges_from_sorted_local_file() {
+        let path = hash_file(
+            "range",
+            &[
+                "000000005AD76BD555C1D6D771DE417A4B87E4B4:10",
+                "5BAA5FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF:1",
+                "5BAA61E4C9B93F3F0682250B6CF8331B7EE68FD8:9659365",
+                "5baa6ffffffffffffffffffffffffffffffffff0",
+                "5BAA7000000000000000000000000000000000000:2",
+                "FFFFFFF8A0382AA9C8D9536EFBA77F261815334D:1",
+            ],
+        );
+        let local = LocalHashFile::new(&path);
+        assert_eq!(
+            local.read_range("5BAA6").unwrap(),
+            "1E4C9B93F3F0682250B6CF8331B7EE68FD8:9659365\nffffffffffffffffffffffffffffffffff0:1\n"
+        );
+        assert_eq!(local.read_range("00000").unwrap().lines().count(), 1);
+        assert_eq!(local.read_range("FFFFF").unwrap().lines().count(), 1);
+        assert_eq!(local.read_range("ABCDE").unwrap(), "");
+
+        let rule = PasswordNotPwned::new(local);
+        assert_eq!(
+            key(&rule, "password").await.as_deref(),
+            Some("validator.password_pwned")
+        );
+        assert_eq!(key(&rule, "correct horse battery staple").await, None);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn missing_local_file_is_a_system_error() {
+        let rule = PasswordNotPwned::new(LocalHashFile::new("/nonexistent/pwned.txt"));
+        let result: Result<_, io::Error> = rule.validate(&"password".to_string()).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use crate::core::net::network::IpNetwork;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+/// IANA special-purpose IPv4 ranges that are not globally reachable.
+const V4_NON_PUBLIC: [IpNetwork; 15] = [
+    IpNetwork::v4(0, 0, 0, 0, 8),       // "this" network
+    IpNetwork::v4(10, 0, 0, 0, 8),      // private
+    IpNetwork::v4(100, 64, 0, 0, 10),   // shared address space (CGNAT)
+    IpNetwork::v4(127, 0, 0, 0, 8),     // loopback
+    IpNetwork::v4(169, 254, 0, 0, 16),  // link-local
+    IpNetwork::v4(172, 16, 0, 0, 12),   // private
+    IpNetwork::v4(192, 0, 0, 0, 24),    // IETF protocol assignments
+    IpNetwork::v4(192, 0, 2, 0, 24),    // documentation
+    IpNetwork::v4(192, 88, 99, 0, 24),  // 6to4 relay anycast
+    IpNetwork::v4(192, 168, 0, 0, 16),  // private
+    IpNetwork::v4(198, 18, 0, 0, 15),   // benchmarking
+    IpNetwork::v4(198, 51, 100, 0, 24), // documentation
+    IpNetwork::v4(203, 0, 113, 0, 24),  // documentation
+    IpNetwork::v4(224, 0, 0, 0, 4),     // multicast
+    IpNetwork::v4(240, 0, 0, 0, 4),     // reserved, broadcast
+];
+
+/// IANA special-purpose IPv6 ranges that are not globally reachable.
+const V6_NON_PUBLIC: [IpNetwork; 13] = [
+    IpNetwork::v6([0, 0, 0, 0, 0, 0, 0, 0], 128), // unspecified
+    IpNetwork::v6([0, 0, 0, 0, 0, 0, 0, 1], 128), // loopback
+    IpNetwork::v6([0, 0, 0, 0, 0, 0, 0, 0], 96),  // IPv4-compatible (deprecated)
+    IpNetwork::v6([0x64, 0xff9b, 0x1, 0, 0, 0, 0, 0], 48), // local-use NAT64
+    IpNetwork::v6([0x100, 0, 0, 0, 0, 0, 0, 0], 64), // discard-only
+    IpNetwork::v6([0x2001, 0, 0, 0, 0, 0, 0, 0], 32), // Teredo
+    IpNetwork::v6([0x2001, 0x2, 0, 0, 0, 0, 0, 0], 48), // benchmarking
+    IpNetwork::v6([0x2001, 0x10, 0, 0, 0, 0, 0, 0], 28), // ORCHID
+    IpNetwork::v6([0x2001, 0xdb8, 0, 0, 0, 0, 0, 0], 32), // documentation
+    IpNetwork::v6([0xfc00, 0, 0, 0, 0, 0, 0, 0], 7), // unique local
+    IpNetwork::v6([0xfe80, 0, 0, 0, 0, 0, 0, 0], 10), // link-local
+    IpNetwork::v6([0xfec0, 0, 0, 0, 0, 0, 0, 0], 10), // site-local (deprecated)
+    IpNetwork::v6([0xff00, 0, 0, 0, 0, 0, 0, 0], 8), // multicast
+];
+
+/// `false` for private, loopback, link-local, documentation, multicast and other reserved
+/// addresses. IPv6 addresses that embed an IPv4 one (mapped, NAT64, 6to4) are judged by it;
+/// IPv4-compatible (`::a.b.c.d`) and local-use NAT64 addresses are never public.
+pub fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_public_v4(v4),
+        IpAddr::V6(v6) => match embedded_v4(v6) {
+            Some(v4) => is_public_v4(&v4),
+            None => !V6_NON_PUBLIC.iter().any(|net| net.contains(ip)),
+        },
+    }
+}
+
+fn is_public_v4(ip: &Ipv4Addr) -> bool {
+    let ip = IpAddr::V4(*ip);
+    !V4_NON_PUBLIC.iter().any(|net| net.contains(&ip))
+}
+
+fn embedded_v4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return Some(v4);
+    }
+
+    let s = ip.segments();
+    let low = |hi: u16, lo: u16| Ipv4Addr::from(((hi as u32) << 16) | lo as u32);
+    match s {
+        // NAT64 well-known prefix 64:ff9b::/96
+        [0x64, 0xff9b, 0, 0, 0, 0, hi, lo] => Some(low(hi, lo)),
+        // 6to4 2002::/16
+        [0x2002, hi, lo, ..] => Some(low(hi, lo)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn public(value: &str) -> bool {
+        is_public_ip(&value.parse().unwrap())
+    }
+
+    #[test]
+    fn accepts_global_addresses() {
+        assert!(public("8.8.8.8"));
+        assert!(public("1.1.1.1"));
+        assert!(public("172.32.0.1"));
+        assert!(public("2606:4700:4700::1111"));
+        assert!(public("::ffff:8.8.8.8"));
+    }
+
+    #[test]
+    fn rejects_ssrf_targets() {
+        for ip in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.0.10",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "224.0.0.1",
+            "192.0.2.1",
+            "::1",
+            "::",
+            "fe80::1",
+            "fd00::1",
+            "ff02::1",
+            "2001:db8::1",
+            "::ffff:127.0.0.1",
+            "64:ff9b::a00:1",
+            "2002:c0a8:1::1",
+            "::10.0.0.1",
+            "::8.8.8.8",
+            "64:ff9b:1::a00:1",
+            "64:ff9b:1::808:808",
+        ] {
+            assert!(!public(ip), "{ip} must not be public");
+        }
+    }
+}
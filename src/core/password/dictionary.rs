@@ -0,0 +1,39 @@
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+pub(crate) static PASSWORDS: Lazy<HashMap<&'static str, usize>> =
+    Lazy::new(|| ranked(include_str!("../../../data/common_passwords.txt")));
+
+pub(crate) static WORDS: Lazy<HashMap<&'static str, usize>> =
+    Lazy::new(|| ranked(include_str!("../../../data/english_words.txt")));
+
+/// Rank 1 is the most common entry; duplicates keep their first rank.
+fn ranked(list: &'static str) -> HashMap<&'static str, usize> {
+    let mut ranks = HashMap::new();
+    let entries = list
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    for (index, entry) in entries.enumerate() {
+        ranks.entry(entry).or_insert(index + 1);
+    }
+    ranks
+}
+
+/// Personal data of the user (username, email, name): each value and its alphanumeric parts.
+pub(crate) fn user_input_ranks(inputs: &[&str]) -> HashMap<String, usize> {
+    let mut ranks = HashMap::new();
+    let words = inputs.iter().flat_map(|input| {
+        let input = input.trim().to_lowercase();
+        let parts: Vec<String> = input
+            .split(|c: char| !c.is_alphanumeric())
+            .map(String::from)
+            .collect();
+        std::iter::once(input).chain(parts)
+    });
+    for word in words.filter(|word| word.chars().count() >= 3) {
+        let rank = ranks.len() + 1;
+        ranks.entry(word).or_insert(rank);
+    }
+    ranks
+}
@@ -0,0 +1,108 @@
+use crate::core::password::dictionary::user_input_ranks;
+use crate::core::password::feedback::{Feedback, feedback};
+use crate::core::password::matching::omnimatch;
+use crate::core::password::scoring::most_guessable;
+use crate::core::password::strength::StrengthLevel;
+use crate::core::temporal::clock::{Clock, SystemClock};
+
+/// Longer passwords are scored on their first 100 chars; they are very strong either way.
+const MAX_ANALYZED_CHARS: usize = 100;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PasswordEstimate {
+    /// log10 of the estimated number of guesses an attacker needs.
+    pub guesses_log10: f64,
+    pub strength: StrengthLevel,
+    pub feedback: Feedback,
+}
+
+/// Pattern-aware strength estimate in the spirit of zxcvbn: common passwords and words
+/// (also reversed or with l33t substitutions), keyboard walks, repeats, sequences, dates,
+/// and `user_inputs` such as the username or email of the account.
+pub fn estimate(password: &str, user_inputs: &[&str]) -> PasswordEstimate {
+    estimate_at(password, user_inputs, SystemClock.now().date().year)
+}
+
+/// Like `estimate`, with recent years measured from `reference_year`.
+pub fn estimate_at(password: &str, user_inputs: &[&str], reference_year: i32) -> PasswordEstimate {
+    let chars: Vec<char> = password.chars().take(MAX_ANALYZED_CHARS).collect();
+    let inputs = user_input_ranks(user_inputs);
+    let matches = omnimatch(&chars, &inputs, reference_year);
+    let (guesses_log10, sequence) = most_guessable(&chars, matches);
+    let strength = StrengthLevel::from_guesses_log10(guesses_log10);
+
+    PasswordEstimate {
+        guesses_log10,
+        strength,
+        feedback: feedback(strength, &sequence),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strength(password: &str) -> StrengthLevel {
+        estimate_at(password, &[], 2024).strength
+    }
+
+    #[test]
+    fn rates_guessable_passwords_low() {
+        for password in [
+            "",
+            "password",
+            "qwerty123",
+            "Password1!Password1!",
+            "P@ssw0rd",
+            "abc123",
+            "aaaaaaaaaaaa",
+            "15.05.1990",
+            "zxcvbnm,./",
+        ] {
+            assert!(strength(password) <= StrengthLevel::Weak, "{password}");
+        }
+    }
+
+    #[test]
+    fn rates_random_and_passphrase_passwords_high() {
+        for password in [
+            "Rust4Life!Secure2024",
+            "tk9#Vq2!mZp7&Lw",
+            "staple orbit lantern quietly",
+        ] {
+            assert!(strength(password) >= StrengthLevel::Strong, "{password}");
+        }
+    }
+
+    #[test]
+    fn penalizes_user_inputs() {
+        let alone = estimate_at("jdoe_kyiv1987", &[], 2024);
+        let with_inputs = estimate_at("jdoe_kyiv1987", &["jdoe", "jane.doe@example.com"], 2024);
+        assert!(with_inputs.guesses_log10 < alone.guesses_log10);
+
+        let estimate = estimate_at("janedoe", &["jane.doe@example.com"], 2024);
+        assert_eq!(estimate.feedback.warning, Some("user_input"));
+    }
+
+    #[test]
+    fn explains_weaknesses() {
+        let top = estimate_at("password", &[], 2024).feedback;
+        assert_eq!(top.warning, Some("top10"));
+        assert_eq!(top.suggestions, ["add_word"]);
+
+        let walk = estimate_at("wertyu", &[], 2024).feedback;
+        assert_eq!(walk.warning, Some("straight_row"));
+
+        let leet = estimate_at("P@ssw0rd", &[], 2024).feedback;
+        assert!(leet.suggestions.contains(&"capitalization"));
+        assert!(leet.suggestions.contains(&"substitutions"));
+
+        let year = estimate_at("1999", &[], 2024).feedback;
+        assert_eq!(year.warning, Some("recent_year"));
+
+        assert_eq!(
+            estimate_at("Rust4Life!Secure2024", &[], 2024).feedback,
+            Feedback::default()
+        );
+    }
+}
@@ -0,0 +1,144 @@
+use crate::core::password::matching::{Match, Pattern, Source};
+use crate::core::password::strength::StrengthLevel;
+
+/// Why a password is weak and how to improve it, as codes (`repeat`, `add_word`) whose
+/// messages are `validator.password_strength.warning.<code>` and
+/// `validator.password_strength.suggestion.<code>` in `valida.yml`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Feedback {
+    pub warning: Option<&'static str>,
+    pub suggestions: Vec<&'static str>,
+}
+
+/// The `password_strength` error key and the message keys of every feedback code.
+pub(crate) const KEYS: &[&str] = &[
+    "validator.password_strength",
+    "validator.password_strength.warning.user_input",
+    "validator.password_strength.warning.top10",
+    "validator.password_strength.warning.top100",
+    "validator.password_strength.warning.very_common",
+    "validator.password_strength.warning.similar_to_common",
+    "validator.password_strength.warning.single_word",
+    "validator.password_strength.warning.straight_row",
+    "validator.password_strength.warning.short_keyboard_pattern",
+    "validator.password_strength.warning.repeat",
+    "validator.password_strength.warning.repeat_group",
+    "validator.password_strength.warning.sequence",
+    "validator.password_strength.warning.recent_year",
+    "validator.password_strength.warning.date",
+    "validator.password_strength.suggestion.use_words",
+    "validator.password_strength.suggestion.no_symbols_needed",
+    "validator.password_strength.suggestion.add_word",
+    "validator.password_strength.suggestion.all_uppercase",
+    "validator.password_strength.suggestion.capitalization",
+    "validator.password_strength.suggestion.reversed_words",
+    "validator.password_strength.suggestion.substitutions",
+    "validator.password_strength.suggestion.longer_keyboard_pattern",
+    "validator.password_strength.suggestion.avoid_repeats",
+    "validator.password_strength.suggestion.avoid_sequences",
+    "validator.password_strength.suggestion.avoid_recent_years",
+    "validator.password_strength.suggestion.avoid_associated_years",
+    "validator.password_strength.suggestion.avoid_associated_dates",
+];
+
+pub(crate) fn feedback(strength: StrengthLevel, sequence: &[Match]) -> Feedback {
+    if strength >= StrengthLevel::Strong {
+        return Feedback::default();
+    }
+
+    let Some(longest) = sequence
+        .iter()
+        .filter(|m| m.pattern != Pattern::Bruteforce)
+        .max_by_key(|m| m.len())
+    else {
+        return Feedback {
+            warning: None,
+            suggestions: vec!["use_words", "no_symbols_needed"],
+        };
+    };
+
+    let mut suggestions = vec!["add_word"];
+    let warning = match &longest.pattern {
+        Pattern::Dictionary {
+            source,
+            rank,
+            reversed,
+            l33t,
+        } => {
+            let sole = sequence.len() == 1;
+            let chars: Vec<char> = longest.token.chars().collect();
+            let upper = chars.iter().filter(|c| c.is_uppercase()).count();
+            if upper > 0 && upper == chars.iter().filter(|c| c.is_alphabetic()).count() {
+                suggestions.push("all_uppercase");
+            } else if chars[0].is_uppercase() {
+                suggestions.push("capitalization");
+            }
+            if *reversed && chars.len() >= 4 {
+                suggestions.push("reversed_words");
+            }
+            if *l33t {
+                suggestions.push("substitutions");
+            }
+            match source {
+                Source::UserInput => Some("user_input"),
+                Source::Password if sole && !reversed && !l33t && *rank <= 10 => Some("top10"),
+                Source::Password if sole && !reversed && !l33t && *rank <= 100 => Some("top100"),
+                Source::Password if sole => Some("very_common"),
+                Source::Password => Some("similar_to_common"),
+                Source::Word if sole => Some("single_word"),
+                Source::Word => None,
+            }
+        }
+        Pattern::Spatial { turns } => {
+            suggestions.push("longer_keyboard_pattern");
+            if *turns == 1 {
+                Some("straight_row")
+            } else {
+                Some("short_keyboard_pattern")
+            }
+        }
+        Pattern::Repeat { base_len } => {
+            suggestions.push("avoid_repeats");
+            if *base_len == 1 {
+                Some("repeat")
+            } else {
+                Some("repeat_group")
+            }
+        }
+        Pattern::Sequence => {
+            suggestions.push("avoid_sequences");
+            Some("sequence")
+        }
+        Pattern::Year => {
+            suggestions.push("avoid_recent_years");
+            suggestions.push("avoid_associated_years");
+            Some("recent_year")
+        }
+        Pattern::Date => {
+            suggestions.push("avoid_associated_dates");
+            Some("date")
+        }
+        Pattern::Bruteforce => None,
+    };
+
+    Feedback {
+        warning,
+        suggestions,
+    }
+}
+
+#[cfg(all(test, feature = "i18n-localization"))]
+mod tests {
+    use super::KEYS;
+    use rust_i18n::t;
+
+    #[test]
+    fn every_code_is_translated() {
+        for locale in ["uk", "en", "de", "es", "pl", "hi", "fr", "pt", "ja"] {
+            for key in KEYS {
+                let text = t!(*key, locale = locale);
+                assert_ne!(text, *key, "missing `{key}` in `{locale}`");
+            }
+        }
+    }
+}
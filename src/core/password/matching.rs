@@ -0,0 +1,592 @@
+use crate::core::password::dictionary::{PASSWORDS, WORDS};
+use crate::core::password::scoring::most_guessable;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+const MAX_WORD_LEN: usize = 32;
+const MIN_YEAR_SPACE: f64 = 20.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Source {
+    Password,
+    Word,
+    UserInput,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Pattern {
+    Dictionary {
+        source: Source,
+        rank: usize,
+        reversed: bool,
+        l33t: bool,
+    },
+    Spatial {
+        turns: usize,
+    },
+    Repeat {
+        base_len: usize,
+    },
+    Sequence,
+    Date,
+    Year,
+    Bruteforce,
+}
+
+/// A guessable span `i..=j` (char indices) of the password.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Match {
+    pub i: usize,
+    pub j: usize,
+    pub token: String,
+    pub pattern: Pattern,
+    pub guesses_log10: f64,
+}
+
+impl Match {
+    fn new(chars: &[char], i: usize, j: usize, pattern: Pattern, guesses: f64) -> Self {
+        Self {
+            i,
+            j,
+            token: chars[i..=j].iter().collect(),
+            pattern,
+            guesses_log10: guesses.log10(),
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.j - self.i + 1
+    }
+}
+
+pub(crate) fn omnimatch(
+    chars: &[char],
+    user_inputs: &HashMap<String, usize>,
+    reference_year: i32,
+) -> Vec<Match> {
+    let mut matches = vec![];
+    dictionary_matches(chars, user_inputs, &mut matches);
+    spatial_matches(chars, &mut matches);
+    repeat_matches(chars, user_inputs, reference_year, &mut matches);
+    sequence_matches(chars, &mut matches);
+    date_matches(chars, reference_year, &mut matches);
+    matches
+}
+
+fn n_choose_k(n: usize, k: usize) -> f64 {
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+/// Σ C(a + b, i) for i in 1..=min(a, b): ways to place `a` variants among `a + b` slots.
+fn variations(a: usize, b: usize) -> f64 {
+    if a == 0 || b == 0 {
+        return 2.0;
+    }
+    (1..=a.min(b)).map(|i| n_choose_k(a + b, i)).sum()
+}
+
+fn lowercase(chars: &[char]) -> Vec<char> {
+    chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect()
+}
+
+// ───── dictionary ─────
+
+fn lookup(word: &str, user_inputs: &HashMap<String, usize>) -> Option<(Source, usize)> {
+    [
+        user_inputs.get(word).map(|rank| (Source::UserInput, *rank)),
+        PASSWORDS.get(word).map(|rank| (Source::Password, *rank)),
+        WORDS.get(word).map(|rank| (Source::Word, *rank)),
+    ]
+    .into_iter()
+    .flatten()
+    .min_by_key(|(_, rank)| *rank)
+}
+
+/// Dictionary hits in `chars` (already lowercase).
+fn dictionary_spans(
+    chars: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> Vec<(usize, usize, Source, usize)> {
+    let mut spans = vec![];
+    for i in 0..chars.len() {
+        let mut word = String::new();
+        for (j, c) in chars.iter().enumerate().skip(i).take(MAX_WORD_LEN) {
+            word.push(*c);
+            if let Some((source, rank)) = lookup(&word, user_inputs) {
+                spans.push((i, j, source, rank));
+            }
+        }
+    }
+    spans
+}
+
+fn uppercase_variations(token: &[char]) -> f64 {
+    let upper = token.iter().filter(|c| c.is_uppercase()).count();
+    let lower = token.iter().filter(|c| c.is_lowercase()).count();
+    if upper == 0 {
+        return 1.0;
+    }
+    let first_only = token[0].is_uppercase() && upper == 1;
+    let last_only = token[token.len() - 1].is_uppercase() && upper == 1;
+    if first_only || last_only || lower == 0 {
+        return 2.0;
+    }
+    variations(upper, lower)
+}
+
+const L33T: &[(char, &[char])] = &[
+    ('4', &['a']),
+    ('@', &['a']),
+    ('8', &['b']),
+    ('(', &['c']),
+    ('{', &['c']),
+    ('[', &['c']),
+    ('<', &['c']),
+    ('3', &['e']),
+    ('6', &['g']),
+    ('9', &['g']),
+    ('1', &['i', 'l']),
+    ('!', &['i']),
+    ('|', &['i', 'l']),
+    ('0', &['o']),
+    ('$', &['s']),
+    ('5', &['s']),
+    ('+', &['t']),
+    ('7', &['t']),
+    ('%', &['x']),
+    ('2', &['z']),
+];
+
+fn l33t_target(c: char, choice: usize) -> Option<char> {
+    L33T.iter()
+        .find(|(sub, _)| *sub == c)
+        .map(|(_, letters)| letters[choice.min(letters.len() - 1)])
+}
+
+fn l33t_variations(original: &[char], unleeted: &[char], subbed: &[bool]) -> f64 {
+    let mut letters: Vec<char> = (0..original.len())
+        .filter(|&k| subbed[k])
+        .map(|k| unleeted[k])
+        .collect();
+    letters.sort_unstable();
+    letters.dedup();
+    letters
+        .into_iter()
+        .map(|letter| {
+            let subs = (0..original.len())
+                .filter(|&k| subbed[k] && unleeted[k] == letter)
+                .count();
+            let kept = original.iter().filter(|c| **c == letter).count();
+            variations(subs, kept)
+        })
+        .product()
+}
+
+fn dictionary_matches(chars: &[char], user_inputs: &HashMap<String, usize>, out: &mut Vec<Match>) {
+    let lower = lowercase(chars);
+    let n = chars.len();
+    let dictionary = |source, rank, reversed, l33t| Pattern::Dictionary {
+        source,
+        rank,
+        reversed,
+        l33t,
+    };
+
+    for (i, j, source, rank) in dictionary_spans(&lower, user_inputs) {
+        let guesses = rank as f64 * uppercase_variations(&chars[i..=j]);
+        out.push(Match::new(
+            chars,
+            i,
+            j,
+            dictionary(source, rank, false, false),
+            guesses,
+        ));
+    }
+
+    let reversed: Vec<char> = lower.iter().rev().copied().collect();
+    for (ri, rj, source, rank) in dictionary_spans(&reversed, user_inputs) {
+        let (i, j) = (n - 1 - rj, n - 1 - ri);
+        let guesses = rank as f64 * uppercase_variations(&chars[i..=j]) * 2.0;
+        out.push(Match::new(
+            chars,
+            i,
+            j,
+            dictionary(source, rank, true, false),
+            guesses,
+        ));
+    }
+
+    for choice in 0..2 {
+        if choice == 1 && !lower.iter().any(|c| matches!(c, '1' | '|')) {
+            break;
+        }
+        let mut subbed = vec![false; n];
+        let unleeted: Vec<char> = lower
+            .iter()
+            .enumerate()
+            .map(|(k, c)| match l33t_target(*c, choice) {
+                Some(letter) => {
+                    subbed[k] = true;
+                    letter
+                }
+                None => *c,
+            })
+            .collect();
+        if !subbed.contains(&true) {
+            return;
+        }
+        for (i, j, source, rank) in dictionary_spans(&unleeted, user_inputs) {
+            if !subbed[i..=j].contains(&true) {
+                continue;
+            }
+            let guesses = rank as f64
+                * uppercase_variations(&chars[i..=j])
+                * l33t_variations(&lower[i..=j], &unleeted[i..=j], &subbed[i..=j]);
+            out.push(Match::new(
+                chars,
+                i,
+                j,
+                dictionary(source, rank, false, true),
+                guesses,
+            ));
+        }
+    }
+}
+
+// ───── keyboard patterns ─────
+
+const QWERTY: [(&str, &str); 4] = [
+    ("`1234567890-=", "~!@#$%^&*()_+"),
+    ("qwertyuiop[]\\", "QWERTYUIOP{}|"),
+    ("asdfghjkl;'", "ASDFGHJKL:\""),
+    ("zxcvbnm,./", "ZXCVBNM<>?"),
+];
+
+/// (row, column, shifted) of every key; rows are staggered like a physical keyboard.
+static KEYS: Lazy<HashMap<char, (i32, i32, bool)>> = Lazy::new(|| {
+    let mut keys = HashMap::new();
+    for (row, (plain, shifted)) in QWERTY.iter().enumerate() {
+        for (col, (p, s)) in plain.chars().zip(shifted.chars()).enumerate() {
+            keys.insert(p, (row as i32, col as i32, false));
+            keys.insert(s, (row as i32, col as i32, true));
+        }
+    }
+    keys
+});
+
+const KEYBOARD_STARTING_POSITIONS: f64 = 47.0;
+const KEYBOARD_AVERAGE_DEGREE: f64 = 4.6;
+
+/// Direction (0..6) from key `a` to an adjacent key `b`.
+fn direction(a: (i32, i32), b: (i32, i32)) -> Option<u8> {
+    // Row 1 starts half a key right of the digit row's second key, so its offset is 1.
+    let offset = |lower_row: i32| if lower_row == 1 { 1 } else { 0 };
+    match b.0 - a.0 {
+        0 if b.1 == a.1 - 1 => Some(0),
+        0 if b.1 == a.1 + 1 => Some(3),
+        -1 if b.1 == a.1 + offset(a.0) => Some(1),
+        -1 if b.1 == a.1 + offset(a.0) + 1 => Some(2),
+        1 if a.1 == b.1 + offset(b.0) => Some(4),
+        1 if a.1 == b.1 + offset(b.0) + 1 => Some(5),
+        _ => None,
+    }
+}
+
+fn spatial_guesses(len: usize, turns: usize, shifted: usize) -> f64 {
+    let mut guesses = 0.0;
+    for i in 2..=len {
+        for j in 1..=turns.min(i - 1) {
+            guesses += n_choose_k(i - 1, j - 1)
+                * KEYBOARD_STARTING_POSITIONS
+                * KEYBOARD_AVERAGE_DEGREE.powi(j as i32);
+        }
+    }
+    if shifted > 0 {
+        guesses *= variations(shifted, len - shifted);
+    }
+    guesses
+}
+
+fn spatial_matches(chars: &[char], out: &mut Vec<Match>) {
+    let key = |c: &char| KEYS.get(c).copied();
+    let mut i = 0;
+    while i + 2 < chars.len() {
+        let mut j = i;
+        let mut turns = 0;
+        let mut last_direction = None;
+        while j + 1 < chars.len() {
+            let (Some(a), Some(b)) = (key(&chars[j]), key(&chars[j + 1])) else {
+                break;
+            };
+            let Some(dir) = direction((a.0, a.1), (b.0, b.1)) else {
+                break;
+            };
+            if last_direction != Some(dir) {
+                turns += 1;
+                last_direction = Some(dir);
+            }
+            j += 1;
+        }
+        if j - i >= 2 {
+            let shifted = chars[i..=j]
+                .iter()
+                .filter(|c| key(c).is_some_and(|k| k.2))
+                .count();
+            let guesses = spatial_guesses(j - i + 1, turns, shifted);
+            out.push(Match::new(chars, i, j, Pattern::Spatial { turns }, guesses));
+        }
+        i = if j > i { j } else { i + 1 };
+    }
+}
+
+// ───── repeats and sequences ─────
+
+fn repeat_matches(
+    chars: &[char],
+    user_inputs: &HashMap<String, usize>,
+    reference_year: i32,
+    out: &mut Vec<Match>,
+) {
+    let n = chars.len();
+    let mut i = 0;
+    while i < n {
+        // Base unit covering the longest run of whole repetitions from `i`.
+        let mut best: Option<(usize, usize)> = None;
+        for unit in 1..=(n - i) / 2 {
+            let base = &chars[i..i + unit];
+            let count = (0..)
+                .take_while(|k| {
+                    let start = i + k * unit;
+                    start + unit <= n && &chars[start..start + unit] == base
+                })
+                .count();
+            if count >= 2 && best.is_none_or(|(u, c)| unit * count > u * c) {
+                best = Some((unit, count));
+            }
+        }
+        let Some((unit, count)) = best else {
+            i += 1;
+            continue;
+        };
+        let base = &chars[i..i + unit];
+        let (base_log10, _) = most_guessable(base, omnimatch(base, user_inputs, reference_year));
+        let j = i + unit * count - 1;
+        let mut repeat = Match::new(chars, i, j, Pattern::Repeat { base_len: unit }, 1.0);
+        repeat.guesses_log10 = base_log10 + (count as f64).log10();
+        out.push(repeat);
+        i = j + 1;
+    }
+}
+
+fn sequence_matches(chars: &[char], out: &mut Vec<Match>) {
+    let code = |k: usize| chars[k] as i64;
+    let mut i = 0;
+    while i + 2 < chars.len() {
+        let delta = code(i + 1) - code(i);
+        let mut j = i + 1;
+        while j + 1 < chars.len() && code(j + 1) - code(j) == delta {
+            j += 1;
+        }
+        if delta != 0 && delta.abs() <= 5 && j - i >= 2 {
+            let first = chars[i];
+            let base = if "aAzZ019".contains(first) {
+                4.0
+            } else if first.is_ascii_digit() {
+                10.0
+            } else {
+                26.0
+            };
+            let direction = if delta > 0 { 1.0 } else { 2.0 };
+            let guesses = base * (j - i + 1) as f64 * direction;
+            out.push(Match::new(chars, i, j, Pattern::Sequence, guesses));
+        }
+        i = j;
+    }
+}
+
+// ───── dates ─────
+
+fn year_space(year: i32, reference_year: i32) -> f64 {
+    ((year - reference_year).abs() as f64).max(MIN_YEAR_SPACE)
+}
+
+fn two_to_four_digit_year(year: i32) -> i32 {
+    match year {
+        y if y > 99 => y,
+        y if y > 50 => 1900 + y,
+        y => 2000 + y,
+    }
+}
+
+/// Reads `parts` as day/month/year in any common order; returns the year closest to today.
+fn as_date(parts: &[&str], reference_year: i32) -> Option<i32> {
+    let ints: Vec<i32> = parts
+        .iter()
+        .map(|p| p.parse().ok())
+        .collect::<Option<_>>()?;
+    let is_day_month = |a: i32, b: i32| {
+        ((1..=31).contains(&a) && (1..=12).contains(&b))
+            || ((1..=12).contains(&a) && (1..=31).contains(&b))
+    };
+    let year_of = |index: usize| {
+        let len = parts[index].len();
+        let year = two_to_four_digit_year(ints[index]);
+        (len == 2 || len == 4)
+            .then_some(year)
+            .filter(|y| (1000..=2050).contains(y))
+    };
+    let candidates = [
+        year_of(2).filter(|_| is_day_month(ints[0], ints[1])),
+        year_of(0).filter(|_| is_day_month(ints[1], ints[2])),
+    ];
+    candidates
+        .into_iter()
+        .flatten()
+        .min_by_key(|year| (year - reference_year).abs())
+}
+
+const DATE_SPLITS: [&[(usize, usize)]; 5] = [
+    &[(1, 2), (2, 3)],
+    &[(1, 3), (2, 3)],
+    &[(1, 2), (2, 4), (4, 5)],
+    &[(1, 3), (2, 3), (4, 5), (4, 6)],
+    &[(2, 4), (4, 6)],
+];
+
+fn date_matches(chars: &[char], reference_year: i32, out: &mut Vec<Match>) {
+    let n = chars.len();
+    for i in 0..n {
+        for j in i + 3..n.min(i + 10) {
+            let token: String = chars[i..=j].iter().collect();
+            let len = j - i + 1;
+
+            if token.chars().all(|c| c.is_ascii_digit()) {
+                if len == 4
+                    && let Ok(year) = token.parse::<i32>()
+                    && (1900..=2099).contains(&year)
+                {
+                    let guesses = year_space(year, reference_year);
+                    out.push(Match::new(chars, i, j, Pattern::Year, guesses));
+                }
+                if len > 8 {
+                    continue;
+                }
+                let year = DATE_SPLITS[len - 4]
+                    .iter()
+                    .filter_map(|(a, b)| {
+                        as_date(
+                            &[&token[..*a], &token[*a..*b], &token[*b..]],
+                            reference_year,
+                        )
+                    })
+                    .min_by_key(|year| (year - reference_year).abs());
+                if let Some(year) = year {
+                    let guesses = year_space(year, reference_year) * 365.0;
+                    out.push(Match::new(chars, i, j, Pattern::Date, guesses));
+                }
+                continue;
+            }
+
+            if len < 6 {
+                continue;
+            }
+            let Some(separator) = token.chars().find(|c| !c.is_ascii_digit()) else {
+                continue;
+            };
+            let parts: Vec<&str> = token.split(separator).collect();
+            let well_formed = " -/\\_.".contains(separator)
+                && parts.len() == 3
+                && parts
+                    .iter()
+                    .all(|p| (1..=4).contains(&p.len()) && p.chars().all(|c| c.is_ascii_digit()));
+            if well_formed && let Some(year) = as_date(&parts, reference_year) {
+                let guesses = year_space(year, reference_year) * 365.0 * 4.0;
+                out.push(Match::new(chars, i, j, Pattern::Date, guesses));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patterns(password: &str) -> Vec<(String, Pattern)> {
+        let chars: Vec<char> = password.chars().collect();
+        omnimatch(&chars, &HashMap::new(), 2024)
+            .into_iter()
+            .map(|m| (m.token, m.pattern))
+            .collect()
+    }
+
+    fn has(password: &str, token: &str, check: impl Fn(&Pattern) -> bool) -> bool {
+        patterns(password)
+            .iter()
+            .any(|(t, pattern)| t == token && check(pattern))
+    }
+
+    #[test]
+    fn finds_dictionary_words_reversed_and_l33t() {
+        assert!(has("xxPassWordxx", "PassWord", |p| matches!(
+            p,
+            Pattern::Dictionary {
+                source: Source::Password,
+                rank: 2,
+                ..
+            }
+        )));
+        assert!(has("drowssap", "drowssap", |p| matches!(
+            p,
+            Pattern::Dictionary { reversed: true, .. }
+        )));
+        assert!(has("P@ssw0rd!", "P@ssw0rd", |p| matches!(
+            p,
+            Pattern::Dictionary { l33t: true, .. }
+        )));
+    }
+
+    #[test]
+    fn finds_keyboard_walks() {
+        assert!(has("qwerty", "qwerty", |p| *p == Pattern::Spatial { turns: 1 }));
+        assert!(has("zxcvfr", "zxcvfr", |p| *p == Pattern::Spatial { turns: 2 }));
+        assert!(has("1qaz", "1qaz", |p| matches!(
+            p,
+            Pattern::Spatial { .. }
+        )));
+        assert!(!has("qgm", "qgm", |p| matches!(p, Pattern::Spatial { .. })));
+    }
+
+    #[test]
+    fn finds_repeats_and_sequences() {
+        assert!(has("aaaa", "aaaa", |p| *p == Pattern::Repeat { base_len: 1 }));
+        assert!(has("abcabcabc", "abcabcabc", |p| *p
+            == Pattern::Repeat { base_len: 3 }));
+        assert!(has("x6543x", "6543", |p| *p == Pattern::Sequence));
+        assert!(has("acegi", "acegi", |p| *p == Pattern::Sequence));
+    }
+
+    #[test]
+    fn finds_years_and_dates() {
+        assert!(has("tom1987", "1987", |p| *p == Pattern::Year));
+        assert!(has("15.05.1990", "15.05.1990", |p| *p == Pattern::Date));
+        assert!(has("19900515", "19900515", |p| *p == Pattern::Date));
+        assert!(has("120590", "120590", |p| *p == Pattern::Date));
+        assert!(!has("15.05-1990", "15.05-1990", |p| *p == Pattern::Date));
+    }
+
+    #[test]
+    fn counts_case_and_substitution_variations() {
+        let chars = |s: &str| s.chars().collect::<Vec<_>>();
+        assert_eq!(uppercase_variations(&chars("password")), 1.0);
+        assert_eq!(uppercase_variations(&chars("Password")), 2.0);
+        assert_eq!(uppercase_variations(&chars("PASSWORD")), 2.0);
+        assert_eq!(uppercase_variations(&chars("PaSsword")), 8.0 + 28.0);
+        assert_eq!(
+            l33t_variations(&chars("p4ss"), &chars("pass"), &[false, true, false, false]),
+            2.0
+        );
+    }
+}
@@ -0,0 +1,7 @@
+pub(crate) mod dictionary;
+pub mod estimate;
+pub mod feedback;
+pub(crate) mod matching;
+pub mod policy;
+pub(crate) mod scoring;
+pub mod strength;
@@ -0,0 +1,275 @@
+use crate::core::errors::ValidationError;
+use crate::core::password::dictionary::PASSWORDS;
+use std::collections::{HashMap, HashSet};
+
+/// Composition rules for `password_policy`, e.g. "at least 12 characters, one uppercase letter,
+/// one digit and one symbol, no character repeated more than twice in a row".
+///
+/// Lengths and counts are in characters. Symbols are characters that are neither alphanumeric
+/// nor whitespace. `PasswordPolicy::new()` accepts every password.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PasswordPolicy {
+    pub(crate) min_length: Option<usize>,
+    pub(crate) max_length: Option<usize>,
+    pub(crate) min_uppercase: usize,
+    pub(crate) min_lowercase: usize,
+    pub(crate) min_digits: usize,
+    pub(crate) min_symbols: usize,
+    pub(crate) max_repeated: Option<usize>,
+    pub(crate) min_unique: usize,
+    pub(crate) forbid_common: bool,
+}
+
+impl PasswordPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.min_length = Some(min);
+        self
+    }
+
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    pub fn require_uppercase(mut self, count: usize) -> Self {
+        self.min_uppercase = count;
+        self
+    }
+
+    pub fn require_lowercase(mut self, count: usize) -> Self {
+        self.min_lowercase = count;
+        self
+    }
+
+    pub fn require_digit(mut self, count: usize) -> Self {
+        self.min_digits = count;
+        self
+    }
+
+    pub fn require_symbol(mut self, count: usize) -> Self {
+        self.min_symbols = count;
+        self
+    }
+
+    /// Longest allowed run of the same character: with `2`, `aab` passes and `aaab` fails.
+    pub fn max_repeated(mut self, max: usize) -> Self {
+        self.max_repeated = Some(max.max(1));
+        self
+    }
+
+    /// Minimum number of distinct characters.
+    pub fn min_unique(mut self, count: usize) -> Self {
+        self.min_unique = count;
+        self
+    }
+
+    /// Rejects passwords from the bundled common-password list (case-insensitive).
+    pub fn forbid_common(mut self, forbid: bool) -> Self {
+        self.forbid_common = forbid;
+        self
+    }
+
+    /// Every unmet requirement, in declaration order, each under its own
+    /// `validator.password.*` key. `user_inputs` (username, email, ...) must not appear in
+    /// the password; parts shorter than 3 characters are ignored.
+    pub fn check(&self, password: &str, user_inputs: &[&str]) -> Vec<ValidationError> {
+        let mut errors = vec![];
+        let mut fail = |key: &str, param: Option<(&str, String)>| {
+            let params = param
+                .map(|(name, value)| HashMap::from([(name.to_string(), value)]))
+                .unwrap_or_default();
+            errors.push(ValidationError::new_with_params(key, params));
+        };
+
+        let length = password.chars().count();
+        if let Some(min) = self.min_length.filter(|&min| length < min) {
+            fail(
+                "validator.password.too_short",
+                Some(("min", min.to_string())),
+            );
+        }
+        if let Some(max) = self.max_length.filter(|&max| length > max) {
+            fail(
+                "validator.password.too_long",
+                Some(("max", max.to_string())),
+            );
+        }
+
+        let count = |matches: fn(char) -> bool| password.chars().filter(|&c| matches(c)).count();
+        let classes = [
+            ("uppercase", self.min_uppercase, count(char::is_uppercase)),
+            ("lowercase", self.min_lowercase, count(char::is_lowercase)),
+            ("digit", self.min_digits, count(char::is_numeric)),
+            ("symbol", self.min_symbols, count(is_symbol)),
+        ];
+        for (class, min, found) in classes {
+            if found < min {
+                fail(
+                    &format!("validator.password.missing_{class}"),
+                    Some(("min", min.to_string())),
+                );
+            }
+        }
+
+        if let Some(max) = self.max_repeated.filter(|&max| longest_run(password) > max) {
+            fail(
+                "validator.password.repeated_chars",
+                Some(("max", max.to_string())),
+            );
+        }
+        if password.chars().collect::<HashSet<_>>().len() < self.min_unique {
+            fail(
+                "validator.password.unique_chars",
+                Some(("min", self.min_unique.to_string())),
+            );
+        }
+        if self.forbid_common && PASSWORDS.contains_key(password.to_lowercase().as_str()) {
+            fail("validator.password.common", None);
+        }
+
+        let lowered = password.to_lowercase();
+        if let Some(input) = input_parts(user_inputs).find(|input| lowered.contains(input.as_str()))
+        {
+            fail(
+                "validator.password.contains_user_input",
+                Some(("input", input)),
+            );
+        }
+
+        errors
+    }
+}
+
+/// Each input and its alphanumeric parts, lowercased; emails contribute their local part only.
+fn input_parts<'a>(inputs: &'a [&str]) -> impl Iterator<Item = String> + 'a {
+    inputs
+        .iter()
+        .flat_map(|input| {
+            let input = input.trim().to_lowercase();
+            let input = match input.rsplit_once('@') {
+                Some((local, _)) => local.to_string(),
+                None => input,
+            };
+            let parts: Vec<String> = input
+                .split(|c: char| !c.is_alphanumeric())
+                .map(String::from)
+                .collect();
+            std::iter::once(input).chain(parts)
+        })
+        .filter(|part| part.chars().count() >= 3)
+}
+
+fn is_symbol(c: char) -> bool {
+    !c.is_alphanumeric() && !c.is_whitespace()
+}
+
+fn longest_run(password: &str) -> usize {
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for c in password.chars() {
+        run = if previous == Some(c) { run + 1 } else { 1 };
+        longest = longest.max(run);
+        previous = Some(c);
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(policy: &PasswordPolicy, password: &str, inputs: &[&str]) -> Vec<String> {
+        policy
+            .check(password, inputs)
+            .into_iter()
+            .map(|err| err.key)
+            .collect()
+    }
+
+    fn compliance() -> PasswordPolicy {
+        PasswordPolicy::new()
+            .min_length(12)
+            .require_uppercase(1)
+            .require_digit(1)
+            .require_symbol(1)
+            .max_repeated(2)
+    }
+
+    #[test]
+    fn accepts_compliant_passwords() {
+        assert!(keys(&compliance(), "Tr0ub4dor&3xyz", &[]).is_empty());
+        assert!(keys(&PasswordPolicy::new(), "", &[]).is_empty());
+    }
+
+    #[test]
+    fn reports_every_unmet_requirement() {
+        assert_eq!(
+            keys(&compliance(), "aaab", &[]),
+            [
+                "validator.password.too_short",
+                "validator.password.missing_uppercase",
+                "validator.password.missing_digit",
+                "validator.password.missing_symbol",
+                "validator.password.repeated_chars",
+            ]
+        );
+
+        let errors = compliance().check("Short1!", &[]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].params["min"], "12");
+    }
+
+    #[test]
+    fn counts_characters_not_bytes() {
+        let policy = PasswordPolicy::new()
+            .min_length(4)
+            .max_length(4)
+            .require_lowercase(2)
+            .require_uppercase(2);
+        assert!(keys(&policy, "ÄÖüß", &[]).is_empty());
+        assert_eq!(
+            keys(&policy, "ÄÖü", &[]),
+            [
+                "validator.password.too_short",
+                "validator.password.missing_lowercase"
+            ]
+        );
+    }
+
+    #[test]
+    fn checks_uniqueness_and_common_passwords() {
+        let policy = PasswordPolicy::new().min_unique(5).forbid_common(true);
+        assert_eq!(
+            keys(&policy, "abab", &[]),
+            ["validator.password.unique_chars"]
+        );
+        assert_eq!(
+            keys(&policy, "PASSWORD", &[]),
+            ["validator.password.common"]
+        );
+    }
+
+    #[test]
+    fn rejects_user_inputs() {
+        let errors = PasswordPolicy::new().check("MyNameIsJdoe!", &["jdoe", "jdoe@example.com"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].key, "validator.password.contains_user_input");
+        assert_eq!(errors[0].params["input"], "jdoe");
+
+        assert!(
+            PasswordPolicy::new()
+                .check("ed-is-fine", &["ed"])
+                .is_empty()
+        );
+        assert!(
+            PasswordPolicy::new()
+                .check("Welcome-2-Tokyo", &["kate@example.com"])
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use crate::core::password::matching::{Match, Pattern};
+
+const MIN_GUESSES_BEFORE_GROWING_SEQUENCE_LOG10: f64 = 4.0;
+const MIN_SUBMATCH_GUESSES_SINGLE_CHAR: f64 = 10.0;
+const MIN_SUBMATCH_GUESSES_MULTI_CHAR: f64 = 50.0;
+
+#[derive(Clone, Copy)]
+enum Step {
+    Match(usize),
+    Bruteforce(usize),
+}
+
+/// log10(10^a + 10^b)
+fn log10_sum(a: f64, b: f64) -> f64 {
+    let (high, low) = if a > b { (a, b) } else { (b, a) };
+    high + (1.0 + 10f64.powf(low - high)).log10()
+}
+
+fn log10_factorial(n: usize) -> f64 {
+    (2..=n).map(|k| (k as f64).log10()).sum()
+}
+
+fn bruteforce_log10(len: usize) -> f64 {
+    let min = if len == 1 { 11f64 } else { 51f64 };
+    (len as f64).max(min.log10())
+}
+
+/// Cheapest way to cover the password with `matches` and bruteforce gaps, zxcvbn style:
+/// `k! · Π guesses + 10⁴^(k-1)` for a sequence of `k` parts. Returns log10 guesses and parts.
+pub(crate) fn most_guessable(chars: &[char], matches: Vec<Match>) -> (f64, Vec<Match>) {
+    let n = chars.len();
+    if n == 0 {
+        return (0.0, vec![]);
+    }
+
+    let guesses = |m: &Match| {
+        if m.len() == n {
+            return m.guesses_log10;
+        }
+        let min = if m.len() == 1 {
+            MIN_SUBMATCH_GUESSES_SINGLE_CHAR
+        } else {
+            MIN_SUBMATCH_GUESSES_MULTI_CHAR
+        };
+        m.guesses_log10.max(min.log10())
+    };
+
+    let mut ending_at: Vec<Vec<usize>> = vec![vec![]; n];
+    for (index, m) in matches.iter().enumerate() {
+        ending_at[m.j].push(index);
+    }
+
+    // best[k][j]: lowest Σ log10 guesses covering 0..=j with exactly k parts.
+    let mut best = vec![vec![f64::INFINITY; n]; n + 1];
+    let mut back: Vec<Vec<Option<Step>>> = vec![vec![None; n]; n + 1];
+
+    for j in 0..n {
+        let steps = ending_at[j]
+            .iter()
+            .map(|&index| {
+                (
+                    matches[index].i,
+                    guesses(&matches[index]),
+                    Step::Match(index),
+                )
+            })
+            .chain((0..=j).map(|i| (i, bruteforce_log10(j - i + 1), Step::Bruteforce(i))));
+
+        for (i, cost, step) in steps {
+            if i == 0 {
+                if cost < best[1][j] {
+                    best[1][j] = cost;
+                    back[1][j] = Some(step);
+                }
+                continue;
+            }
+            for k in 1..=i {
+                let total = best[k][i - 1] + cost;
+                if total < best[k + 1][j] {
+                    best[k + 1][j] = total;
+                    back[k + 1][j] = Some(step);
+                }
+            }
+        }
+    }
+
+    let (mut k, total) = (1..=n)
+        .filter(|&k| best[k][n - 1].is_finite())
+        .map(|k| {
+            let product = log10_factorial(k) + best[k][n - 1];
+            let growing = MIN_GUESSES_BEFORE_GROWING_SEQUENCE_LOG10 * (k - 1) as f64;
+            (k, log10_sum(product, growing))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .expect("a single bruteforce part always covers the password");
+
+    let mut sequence = vec![];
+    let mut j = n - 1;
+    while k > 0 {
+        let part = match back[k][j].expect("every counted part has a step") {
+            Step::Match(index) => matches[index].clone(),
+            Step::Bruteforce(i) => Match {
+                i,
+                j,
+                token: chars[i..=j].iter().collect(),
+                pattern: Pattern::Bruteforce,
+                guesses_log10: bruteforce_log10(j - i + 1),
+            },
+        };
+        k -= 1;
+        if part.i == 0 {
+            sequence.push(part);
+            break;
+        }
+        j = part.i - 1;
+        sequence.push(part);
+    }
+    sequence.reverse();
+    (total, sequence)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::password::matching::omnimatch;
+    use std::collections::HashMap;
+
+    fn score(password: &str) -> (f64, Vec<String>) {
+        let chars: Vec<char> = password.chars().collect();
+        let (guesses, sequence) = most_guessable(&chars, omnimatch(&chars, &HashMap::new(), 2024));
+        (guesses, sequence.into_iter().map(|m| m.token).collect())
+    }
+
+    #[test]
+    fn picks_cheapest_cover() {
+        let (_, parts) = score("correcthorse");
+        assert_eq!(parts, ["correct", "horse"]);
+
+        let (guesses, parts) = score("password");
+        assert_eq!(parts, ["password"]);
+        assert!(guesses < 1.0);
+    }
+
+    #[test]
+    fn falls_back_to_bruteforce() {
+        let (guesses, parts) = score("x#9Lq");
+        assert_eq!(parts, ["x#9Lq"]);
+        assert!((guesses - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn empty_password_has_one_guess() {
+        assert_eq!(score(""), (0.0, vec![]));
+    }
+}
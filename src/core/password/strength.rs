@@ -0,0 +1,31 @@
+#[derive(Debug, PartialEq, PartialOrd, Eq, Clone, Copy)]
+pub enum StrengthLevel {
+    VeryWeak = 0,
+    Weak = 1,
+    Medium = 2,
+    Strong = 3,
+    VeryStrong = 4,
+}
+
+impl StrengthLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StrengthLevel::VeryWeak => "very_weak",
+            StrengthLevel::Weak => "weak",
+            StrengthLevel::Medium => "medium",
+            StrengthLevel::Strong => "strong",
+            StrengthLevel::VeryStrong => "very_strong",
+        }
+    }
+
+    /// Buckets an estimated number of guesses: under 10³, 10⁶, 10⁸, 10¹⁰ and above.
+    pub fn from_guesses_log10(guesses_log10: f64) -> Self {
+        match guesses_log10 {
+            g if g < 3.0 => StrengthLevel::VeryWeak,
+            g if g < 6.0 => StrengthLevel::Weak,
+            g if g < 8.0 => StrengthLevel::Medium,
+            g if g < 10.0 => StrengthLevel::Strong,
+            _ => StrengthLevel::VeryStrong,
+        }
+    }
+}
@@ -0,0 +1,170 @@
+/// Naming convention applied to error paths before rendering.
+///
+/// Useful for DSL validators whose field names follow Rust naming while the
+/// client expects the serialized (e.g. `camelCase`) names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathCase {
+    Camel,
+    Pascal,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl PathCase {
+    pub fn apply(&self, segment: &str) -> String {
+        let words = split_words(segment);
+        if words.is_empty() {
+            return segment.to_string();
+        }
+
+        match self {
+            PathCase::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, w)| {
+                    if i == 0 {
+                        w.to_lowercase()
+                    } else {
+                        capitalize(w)
+                    }
+                })
+                .collect(),
+            PathCase::Pascal => words.iter().map(|w| capitalize(w)).collect(),
+            PathCase::Snake => words.join("_").to_lowercase(),
+            PathCase::ScreamingSnake => words.join("_").to_uppercase(),
+            PathCase::Kebab => words.join("-").to_lowercase(),
+        }
+    }
+}
+
+fn split_words(segment: &str) -> Vec<String> {
+    let chars: Vec<char> = segment.chars().collect();
+    let mut words = vec![];
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            // "userName" → user|Name, "HTTPServer" → HTTP|Server
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.push(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars.flat_map(char::to_lowercase))
+            .collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_snake_to_camel() {
+        assert_eq!(PathCase::Camel.apply("first_name"), "firstName");
+        assert_eq!(PathCase::Camel.apply("address_line_2"), "addressLine2");
+    }
+
+    #[test]
+    fn converts_snake_to_pascal() {
+        assert_eq!(PathCase::Pascal.apply("first_name"), "FirstName");
+    }
+
+    #[test]
+    fn converts_camel_to_snake() {
+        assert_eq!(PathCase::Snake.apply("firstName"), "first_name");
+        assert_eq!(PathCase::Snake.apply("HTTPServer"), "http_server");
+    }
+
+    #[test]
+    fn converts_to_screaming_snake_and_kebab() {
+        assert_eq!(PathCase::ScreamingSnake.apply("firstName"), "FIRST_NAME");
+        assert_eq!(PathCase::Kebab.apply("first_name"), "first-name");
+    }
+
+    #[test]
+    fn keeps_numeric_segments() {
+        assert_eq!(PathCase::Camel.apply("0"), "0");
+        assert_eq!(PathCase::Kebab.apply("12"), "12");
+    }
+
+    #[test]
+    fn keeps_empty_segment() {
+        assert_eq!(PathCase::Camel.apply(""), "");
+        assert_eq!(PathCase::Camel.apply("__"), "__");
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod derive_tests {
+    use serde::Deserialize;
+    use valida::prelude::*;
+
+    #[Validatable(std::io::Error)]
+    #[derive(Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct SignUp {
+        #[validate(min_length(2))]
+        pub first_name: String,
+
+        #[serde(rename = "mail")]
+        #[validate(email)]
+        pub email_address: String,
+
+        #[validate(rename = "zip", min_length(5))]
+        pub postal_code: String,
+
+        #[validate(min(18))]
+        pub r#age: i32,
+    }
+
+    #[tokio::test]
+    async fn derive_uses_serde_names_for_error_paths() {
+        let dto = SignUp {
+            first_name: "A".into(),
+            email_address: "nope".into(),
+            postal_code: "1".into(),
+            r#age: 1,
+        };
+
+        let errors = match SignUpValidator.validate(&dto).await {
+            Err(ValidatorFailure::Invalid(e)) => e,
+            _ => panic!("Should be invalid"),
+        };
+
+        assert!(errors.has_error_for_field("firstName"));
+        assert!(errors.has_error_for_field("mail"));
+        assert!(errors.has_error_for_field("zip"));
+        assert!(errors.has_error_for_field("age"));
+        assert!(!errors.has_error_for_field("first_name"));
+    }
+}
@@ -1,5 +1,6 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::{BTreeMap, HashMap};
 
 pub(crate) struct NotEmpty {}
@@ -11,10 +12,26 @@ impl<T: RuleTarget> IValidatorRule<T> for NotEmpty {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let descriptor = RuleDescriptor::new("not_empty", &["validator.required"]);
+        Some(match T::json_type() {
+            Some(json_type) => descriptor.param("type", json_type),
+            None => descriptor,
+        })
+    }
 }
 
 pub trait RuleTarget {
     fn is_empty(&self) -> bool;
+
+    /// JSON type of the value (`string`, `array`, `object`), when it is known statically.
+    fn json_type() -> Option<&'static str>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 // Строкові типи
@@ -22,18 +39,30 @@ impl RuleTarget for &str {
     fn is_empty(&self) -> bool {
         self.trim().is_empty()
     }
+
+    fn json_type() -> Option<&'static str> {
+        Some("string")
+    }
 }
 
 impl RuleTarget for String {
     fn is_empty(&self) -> bool {
         self.trim().is_empty()
     }
+
+    fn json_type() -> Option<&'static str> {
+        Some("string")
+    }
 }
 
 impl<T> RuleTarget for Vec<T> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn json_type() -> Option<&'static str> {
+        Some("array")
+    }
 }
 
 // Option<T>
@@ -41,6 +70,10 @@ impl<T: RuleTarget> RuleTarget for Option<T> {
     fn is_empty(&self) -> bool {
         self.as_ref().is_none_or(|v| v.is_empty())
     }
+
+    fn json_type() -> Option<&'static str> {
+        T::json_type()
+    }
 }
 
 // Колекції
@@ -48,12 +81,20 @@ impl<K, V> RuleTarget for HashMap<K, V> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn json_type() -> Option<&'static str> {
+        Some("object")
+    }
 }
 
 impl<K, V> RuleTarget for BTreeMap<K, V> {
     fn is_empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn json_type() -> Option<&'static str> {
+        Some("object")
+    }
 }
 
 #[cfg(test)]
@@ -115,4 +156,25 @@ mod tests {
     );
 
     test_not_empty!(ne_btreemap_fail, BTreeMap::<String, i32>::new(), false);
+
+    #[test]
+    fn describes_json_type() {
+        let json_type =
+            |descriptor: Option<RuleDescriptor>| descriptor.unwrap().params.get("type").cloned();
+
+        assert_eq!(
+            json_type(IValidatorRule::<Option<String>>::describe(&NotEmpty {})),
+            Some("string".into())
+        );
+        assert_eq!(
+            json_type(IValidatorRule::<Vec<u8>>::describe(&NotEmpty {})),
+            Some("array".into())
+        );
+        assert_eq!(
+            json_type(IValidatorRule::<HashMap<String, u8>>::describe(
+                &NotEmpty {}
+            )),
+            Some("object".into())
+        );
+    }
 }
@@ -1,5 +1,6 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::marker::PhantomData;
 use std::rc::Rc;
 use std::sync::Arc;
@@ -18,6 +19,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("not_none", &["validator.not_none"]))
+    }
 }
 
 // ┌──────────── Box<Option<T>> ─────────────┐
@@ -31,6 +36,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("not_none", &["validator.not_none"]))
+    }
 }
 
 // ┌──────────── Rc<Option<T>> ─────────────┐
@@ -44,6 +53,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("not_none", &["validator.not_none"]))
+    }
 }
 
 // ┌──────────── Arc<Option<T>> ─────────────┐
@@ -57,6 +70,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("not_none", &["validator.not_none"]))
+    }
 }
 
 #[cfg(test)]
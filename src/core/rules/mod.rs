@@ -1,9 +1,14 @@
 pub(crate) mod byte_ref;
 pub(crate) mod common;
 pub mod nested;
+pub(crate) mod net;
+pub(crate) mod net_ref;
+pub(crate) mod number;
 pub(crate) mod numeric;
 pub(crate) mod slice;
 pub(crate) mod slice_ref;
 pub(crate) mod str_ref;
 pub(crate) mod string;
+pub(crate) mod temporal;
+pub(crate) mod temporal_ref;
 pub(crate) mod value_ref;
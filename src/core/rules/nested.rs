@@ -9,3 +9,20 @@ where
     where
         TValidator: IValidate<V, E> + 'static;
 }
+
+/// `nested` plus a mutable accessor for the field, so that `IValidate::sanitize` also runs the
+/// sanitizers of the nested validator: `.nested_mut(|u| &mut u.address, AddressValidator)`.
+/// An `Arc` is sanitized only while it is not shared (`Arc::get_mut`).
+pub trait NestedMutField<T, F, V, E>
+where
+    V: Send + Sync + 'static,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    fn nested_mut<TValidator>(
+        self,
+        accessor: impl Fn(&mut T) -> &mut F + Send + Sync + 'static,
+        validator: TValidator,
+    ) -> Self
+    where
+        TValidator: IValidate<V, E> + 'static;
+}
@@ -0,0 +1,111 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::net_ref::IpRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::net::IpAddr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IpVersion {
+    Any,
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn name(&self) -> &'static str {
+        match self {
+            IpVersion::Any => "ip",
+            IpVersion::V4 => "ipv4",
+            IpVersion::V6 => "ipv6",
+        }
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            IpVersion::Any => "validator.ip",
+            IpVersion::V4 => "validator.ipv4",
+            IpVersion::V6 => "validator.ipv6",
+        }
+    }
+
+    fn matches(&self, ip: &IpAddr) -> bool {
+        match self {
+            IpVersion::Any => true,
+            IpVersion::V4 => ip.is_ipv4(),
+            IpVersion::V6 => ip.is_ipv6(),
+        }
+    }
+}
+
+pub(crate) struct Ip {
+    pub version: IpVersion,
+}
+
+impl<T: IpRef> IValidatorRule<T> for Ip {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.ip_addr() {
+            Some(Ok(ip)) if self.version.matches(&ip) => Ok(()),
+            Some(_) => Err(ValidationError::new(self.version.key())),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let key: &'static [&'static str] = match self.version {
+            IpVersion::Any => &["validator.ip"],
+            IpVersion::V4 => &["validator.ipv4"],
+            IpVersion::V6 => &["validator.ipv6"],
+        };
+        Some(RuleDescriptor::new(self.version.name(), key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn validator(version: IpVersion) -> Ip {
+        Ip { version }
+    }
+
+    #[test]
+    fn validates_any_version() {
+        assert!(validator(IpVersion::Any).validate(&"192.168.0.1").is_ok());
+        assert!(validator(IpVersion::Any).validate(&"2001:db8::1").is_ok());
+
+        let err = validator(IpVersion::Any)
+            .validate(&"192.168.0")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.ip");
+    }
+
+    #[test]
+    fn restricts_version() {
+        assert!(validator(IpVersion::V4).validate(&"10.0.0.1").is_ok());
+        let err = validator(IpVersion::V4).validate(&"::1").unwrap_err();
+        assert_eq!(err.key, "validator.ipv4");
+
+        assert!(
+            validator(IpVersion::V6)
+                .validate(&Ipv6Addr::LOCALHOST)
+                .is_ok()
+        );
+        let err = validator(IpVersion::V6)
+            .validate(&Ipv4Addr::LOCALHOST)
+            .unwrap_err();
+        assert_eq!(err.key, "validator.ipv6");
+    }
+
+    #[test]
+    fn fails_garbage_with_version_key() {
+        let err = validator(IpVersion::V6).validate(&"not an ip").unwrap_err();
+        assert_eq!(err.key, "validator.ipv6");
+    }
+
+    #[test]
+    fn skips_none() {
+        let value: Option<String> = None;
+        assert!(validator(IpVersion::V4).validate(&value).is_ok());
+    }
+}
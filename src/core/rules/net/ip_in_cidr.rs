@@ -0,0 +1,107 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::net::network::IpNetwork;
+use crate::core::rules::net_ref::IpRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Allow-list (`deny: false`) or deny-list (`deny: true`) of CIDR blocks.
+pub(crate) struct IpInCidr {
+    pub networks: Vec<IpNetwork>,
+    pub deny: bool,
+}
+
+impl IpInCidr {
+    fn key(&self) -> &'static str {
+        if self.deny {
+            "validator.ip_not_in_cidr"
+        } else {
+            "validator.ip_in_cidr"
+        }
+    }
+
+    fn networks(&self) -> String {
+        self.networks
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl<T: IpRef> IValidatorRule<T> for IpInCidr {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(ip) = value.ip_addr() else {
+            return Ok(());
+        };
+        let ip = ip?;
+
+        let listed = self.networks.iter().any(|net| net.contains(&ip));
+        if listed == self.deny {
+            return Err(ValidationError::new_with_params(
+                self.key(),
+                HashMap::from([("networks".into(), self.networks())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let (name, keys): (_, &'static [&'static str]) = if self.deny {
+            (
+                "ip_not_in_cidr",
+                &["validator.ip_not_in_cidr", "validator.ip"],
+            )
+        } else {
+            ("ip_in_cidr", &["validator.ip_in_cidr", "validator.ip"])
+        };
+        let networks: Vec<_> = self.networks.iter().map(ToString::to_string).collect();
+        Some(RuleDescriptor::new(name, keys).param("networks", networks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(networks: &[&str], deny: bool) -> IpInCidr {
+        IpInCidr {
+            networks: networks
+                .iter()
+                .map(|n| IpNetwork::parse(n).unwrap())
+                .collect(),
+            deny,
+        }
+    }
+
+    #[test]
+    fn allow_list() {
+        let rule = validator(&["10.0.0.0/8", "2001:db8::/32"], false);
+        assert!(rule.validate(&"10.20.30.40").is_ok());
+        assert!(rule.validate(&"2001:db8::5").is_ok());
+
+        let err = rule.validate(&"192.168.1.1").unwrap_err();
+        assert_eq!(err.key, "validator.ip_in_cidr");
+        assert_eq!(
+            err.params.get("networks"),
+            Some(&"10.0.0.0/8, 2001:db8::/32".into())
+        );
+    }
+
+    #[test]
+    fn deny_list() {
+        let rule = validator(&["127.0.0.0/8", "169.254.0.0/16"], true);
+        assert!(rule.validate(&"8.8.8.8").is_ok());
+
+        let err = rule.validate(&"169.254.169.254").unwrap_err();
+        assert_eq!(err.key, "validator.ip_not_in_cidr");
+    }
+
+    #[test]
+    fn fails_invalid_ip() {
+        let err = validator(&["10.0.0.0/8"], true)
+            .validate(&"10.0.0")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.ip");
+    }
+}
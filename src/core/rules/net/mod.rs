@@ -0,0 +1,5 @@
+pub mod ip;
+pub mod ip_in_cidr;
+pub mod port;
+pub mod public_ip;
+pub mod socket_addr;
@@ -0,0 +1,45 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::net_ref::PortRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// TCP/UDP port `1..=65535`, as an integer or a decimal string.
+pub(crate) struct Port {}
+
+impl<T: PortRef> IValidatorRule<T> for Port {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if let Some(port) = value.port() {
+            port?;
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("port", &["validator.port"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_ports() {
+        assert!(Port {}.validate(&8080u16).is_ok());
+        assert!(Port {}.validate(&65535i64).is_ok());
+        assert!(Port {}.validate(&"22").is_ok());
+    }
+
+    #[test]
+    fn fails_out_of_range() {
+        let err = Port {}.validate(&0u16).unwrap_err();
+        assert_eq!(err.key, "validator.port");
+        assert!(Port {}.validate(&70000u32).is_err());
+        assert!(Port {}.validate(&"http").is_err());
+    }
+
+    #[test]
+    fn skips_none() {
+        assert!(Port {}.validate(&None::<u16>).is_ok());
+    }
+}
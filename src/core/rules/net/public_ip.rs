@@ -0,0 +1,52 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::net::special::is_public_ip;
+use crate::core::rules::net_ref::IpRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+pub(crate) struct PublicIp {}
+
+impl<T: IpRef> IValidatorRule<T> for PublicIp {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if let Some(ip) = value.ip_addr()
+            && !is_public_ip(&ip?)
+        {
+            return Err(ValidationError::new("validator.public_ip"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "public_ip",
+            &["validator.public_ip", "validator.ip"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::IpAddr;
+
+    #[test]
+    fn validates_public_address() {
+        assert!(PublicIp {}.validate(&"93.184.216.34").is_ok());
+        assert!(PublicIp {}.validate(&"2606:4700::1111").is_ok());
+    }
+
+    #[test]
+    fn fails_internal_address() {
+        let err = PublicIp {}.validate(&"169.254.169.254").unwrap_err();
+        assert_eq!(err.key, "validator.public_ip");
+
+        let ip: IpAddr = "::ffff:10.0.0.1".parse().unwrap();
+        assert!(PublicIp {}.validate(&ip).is_err());
+    }
+
+    #[test]
+    fn fails_invalid_address() {
+        let err = PublicIp {}.validate(&"localhost").unwrap_err();
+        assert_eq!(err.key, "validator.ip");
+    }
+}
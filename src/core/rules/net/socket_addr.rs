@@ -0,0 +1,53 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::net_ref::SocketAddrRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// `ip:port` with a literal address (`[..]` for IPv6) and a non-zero port.
+pub(crate) struct SocketAddress {}
+
+impl<T: SocketAddrRef> IValidatorRule<T> for SocketAddress {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if let Some(addr) = value.socket_addr()
+            && addr?.port() == 0
+        {
+            return Err(ValidationError::new("validator.socket_addr"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "socket_addr",
+            &["validator.socket_addr"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{Ipv4Addr, SocketAddrV4};
+
+    #[test]
+    fn validates_socket_addresses() {
+        assert!(SocketAddress {}.validate(&"10.0.0.1:5432").is_ok());
+        assert!(SocketAddress {}.validate(&"[2001:db8::1]:443").is_ok());
+
+        let addr = SocketAddrV4::new(Ipv4Addr::LOCALHOST, 6379);
+        assert!(SocketAddress {}.validate(&addr).is_ok());
+    }
+
+    #[test]
+    fn fails_invalid_addresses() {
+        for value in [
+            "10.0.0.1",
+            "2001:db8::1:443",
+            "example.com:80",
+            "10.0.0.1:0",
+        ] {
+            let err = SocketAddress {}.validate(&value).unwrap_err();
+            assert_eq!(err.key, "validator.socket_addr", "{value}");
+        }
+    }
+}
@@ -0,0 +1,197 @@
+use crate::core::errors::ValidationError;
+use std::borrow::Cow;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+use std::sync::Arc;
+
+pub trait IpRef {
+    /// `None` when there is nothing to check; `Some(Err(..))` for strings that are not
+    /// IP addresses.
+    fn ip_addr(&self) -> Option<Result<IpAddr, ValidationError>>;
+}
+
+pub trait SocketAddrRef {
+    /// `None` when there is nothing to check; `Some(Err(..))` for strings that are not
+    /// `ip:port` pairs.
+    fn socket_addr(&self) -> Option<Result<SocketAddr, ValidationError>>;
+}
+
+pub trait PortRef {
+    /// `None` when there is nothing to check; `Some(Err(..))` for values outside `1..=65535`.
+    fn port(&self) -> Option<Result<u16, ValidationError>>;
+}
+
+pub(crate) fn parse_ip(value: &str) -> Option<Result<IpAddr, ValidationError>> {
+    Some(
+        value
+            .parse()
+            .map_err(|_| ValidationError::new("validator.ip")),
+    )
+}
+
+pub(crate) fn parse_socket_addr(value: &str) -> Option<Result<SocketAddr, ValidationError>> {
+    Some(
+        value
+            .parse()
+            .map_err(|_| ValidationError::new("validator.socket_addr")),
+    )
+}
+
+pub(crate) fn port_from<N: TryInto<u16>>(value: N) -> Option<Result<u16, ValidationError>> {
+    Some(match value.try_into() {
+        Ok(port) if port != 0 => Ok(port),
+        _ => Err(ValidationError::new("validator.port")),
+    })
+}
+
+fn parse_port(value: &str) -> Option<Result<u16, ValidationError>> {
+    if value.is_empty() || !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Some(Err(ValidationError::new("validator.port")));
+    }
+    port_from(value.parse::<u64>().unwrap_or(u64::MAX))
+}
+
+macro_rules! impl_for_strings {
+    ($trait:ident, $method:ident, $target:ty, $parse:ident) => {
+        impl $trait for String {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                $parse(self)
+            }
+        }
+
+        impl $trait for &str {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                $parse(self)
+            }
+        }
+
+        impl $trait for Cow<'static, str> {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                $parse(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_for_wrappers {
+    ($trait:ident, $method:ident, $target:ty) => {
+        impl<T: $trait> $trait for Option<T> {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                self.as_ref().and_then(|v| v.$method())
+            }
+        }
+
+        impl<T: $trait> $trait for &T {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                (**self).$method()
+            }
+        }
+
+        impl<T: $trait> $trait for Box<T> {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                (**self).$method()
+            }
+        }
+
+        impl<T: $trait> $trait for Arc<T> {
+            fn $method(&self) -> Option<Result<$target, ValidationError>> {
+                (**self).$method()
+            }
+        }
+    };
+}
+
+impl_for_strings!(IpRef, ip_addr, IpAddr, parse_ip);
+impl_for_strings!(SocketAddrRef, socket_addr, SocketAddr, parse_socket_addr);
+impl_for_strings!(PortRef, port, u16, parse_port);
+
+impl_for_wrappers!(IpRef, ip_addr, IpAddr);
+impl_for_wrappers!(SocketAddrRef, socket_addr, SocketAddr);
+impl_for_wrappers!(PortRef, port, u16);
+
+impl IpRef for IpAddr {
+    fn ip_addr(&self) -> Option<Result<IpAddr, ValidationError>> {
+        Some(Ok(*self))
+    }
+}
+
+impl IpRef for Ipv4Addr {
+    fn ip_addr(&self) -> Option<Result<IpAddr, ValidationError>> {
+        Some(Ok(IpAddr::V4(*self)))
+    }
+}
+
+impl IpRef for Ipv6Addr {
+    fn ip_addr(&self) -> Option<Result<IpAddr, ValidationError>> {
+        Some(Ok(IpAddr::V6(*self)))
+    }
+}
+
+impl SocketAddrRef for SocketAddr {
+    fn socket_addr(&self) -> Option<Result<SocketAddr, ValidationError>> {
+        Some(Ok(*self))
+    }
+}
+
+impl SocketAddrRef for SocketAddrV4 {
+    fn socket_addr(&self) -> Option<Result<SocketAddr, ValidationError>> {
+        Some(Ok(SocketAddr::V4(*self)))
+    }
+}
+
+impl SocketAddrRef for SocketAddrV6 {
+    fn socket_addr(&self) -> Option<Result<SocketAddr, ValidationError>> {
+        Some(Ok(SocketAddr::V6(*self)))
+    }
+}
+
+macro_rules! impl_port_for_ints {
+    ($($t:ty),*) => {
+        $(
+            impl PortRef for $t {
+                fn port(&self) -> Option<Result<u16, ValidationError>> {
+                    port_from(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_port_for_ints!(u16, u32, u64, usize, i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ip_strings() {
+        assert_eq!(
+            "10.0.0.1".ip_addr().unwrap().unwrap(),
+            IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1))
+        );
+        assert!("::1".to_string().ip_addr().unwrap().is_ok());
+
+        let err = "10.0.0.256".ip_addr().unwrap().unwrap_err();
+        assert_eq!(err.key, "validator.ip");
+        assert!(None::<String>.ip_addr().is_none());
+    }
+
+    #[test]
+    fn parses_socket_addr_strings() {
+        assert!("127.0.0.1:8080".socket_addr().unwrap().is_ok());
+        assert!("[::1]:443".socket_addr().unwrap().is_ok());
+
+        let err = "localhost:80".socket_addr().unwrap().unwrap_err();
+        assert_eq!(err.key, "validator.socket_addr");
+    }
+
+    #[test]
+    fn checks_port_range() {
+        assert_eq!(8080u16.port().unwrap().unwrap(), 8080);
+        assert_eq!("443".port().unwrap().unwrap(), 443);
+        assert!(0u16.port().unwrap().is_err());
+        assert!(65536u32.port().unwrap().is_err());
+        assert!((-1i32).port().unwrap().is_err());
+        assert!("+80".port().unwrap().is_err());
+        assert!("".port().unwrap().is_err());
+    }
+}
@@ -0,0 +1,164 @@
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
+
+/// Arithmetic behind `multiple_of`, `finite`, `max_decimal_places` and `precision`, for the
+/// `ValueRef` targets.
+pub trait Number: PartialOrd + ToString {
+    /// `false` for NaN and infinities.
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    /// Whether `self` is an integer multiple of `step`; a zero `step` accepts only zero.
+    fn divisible_by(&self, step: &Self) -> bool;
+
+    /// Significant digits before and after the decimal point: `(3, 1)` for `120.50`,
+    /// `(0, 3)` for `0.005`. Floats count the digits of their shortest round-trip form, so
+    /// `0.1 + 0.2` has 17 decimal places.
+    fn decimal_digits(&self) -> (usize, usize) {
+        let text = self.to_string();
+        let unsigned = text.trim_start_matches('-');
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        (
+            integer.trim_start_matches('0').len(),
+            fraction.trim_end_matches('0').len(),
+        )
+    }
+}
+
+macro_rules! impl_number_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn divisible_by(&self, step: &Self) -> bool {
+                    if *step == 0 {
+                        return *self == 0;
+                    }
+                    // `MIN % -1` overflows, but `MIN` is a multiple of `-1`.
+                    self.checked_rem(*step).is_none_or(|rest| rest == 0)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_integers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+macro_rules! impl_number_for_floats {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn is_finite(&self) -> bool {
+                    <$t>::is_finite(*self)
+                }
+
+                /// Within a few ULPs of an integer quotient, so that `0.3` is a multiple of
+                /// `0.1` despite binary rounding.
+                fn divisible_by(&self, step: &Self) -> bool {
+                    if !Number::is_finite(self) || !Number::is_finite(step) {
+                        return false;
+                    }
+                    if *step == 0.0 {
+                        return *self == 0.0;
+                    }
+                    let quotient = self / step;
+                    (quotient - quotient.round()).abs() <= 4.0 * <$t>::EPSILON * quotient.abs().max(1.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_floats!(f32, f64);
+
+macro_rules! impl_number_for_non_zero {
+    ($($t:ty),*) => {
+        $(
+            impl Number for $t {
+                fn divisible_by(&self, step: &Self) -> bool {
+                    self.get().divisible_by(&step.get())
+                }
+            }
+        )*
+    };
+}
+
+impl_number_for_non_zero!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+#[cfg(feature = "decimal")]
+impl Number for rust_decimal::Decimal {
+    fn divisible_by(&self, step: &Self) -> bool {
+        if step.is_zero() {
+            return self.is_zero();
+        }
+        self.checked_rem(*step).is_none_or(|rest| rest.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_integer_multiples() {
+        assert!(15u32.divisible_by(&5));
+        assert!(!16i64.divisible_by(&5));
+        assert!((-10i8).divisible_by(&5));
+        assert!(i128::MIN.divisible_by(&-1));
+        assert!(0usize.divisible_by(&0));
+        assert!(!3usize.divisible_by(&0));
+        assert!(
+            NonZeroU16::new(300)
+                .unwrap()
+                .divisible_by(&NonZeroU16::new(100).unwrap())
+        );
+    }
+
+    #[test]
+    fn checks_float_multiples_despite_rounding() {
+        assert!(0.3f64.divisible_by(&0.1));
+        assert!(1.15f64.divisible_by(&0.05));
+        assert!(19.99f32.divisible_by(&0.01));
+        assert!(!0.35f64.divisible_by(&0.1));
+        assert!(!1_000_000_000_000.35f64.divisible_by(&0.1));
+        assert!(!f64::NAN.divisible_by(&0.1));
+        assert!(!Number::is_finite(&f64::INFINITY));
+    }
+
+    #[test]
+    fn counts_decimal_digits() {
+        assert_eq!(120.5f64.decimal_digits(), (3, 1));
+        assert_eq!((-0.005f64).decimal_digits(), (0, 3));
+        assert_eq!((0.1f64 + 0.2).decimal_digits(), (0, 17));
+        assert_eq!(4200u64.decimal_digits(), (4, 0));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn supports_decimals() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        let amount = Decimal::from_str("120.50").unwrap();
+        assert_eq!(amount.decimal_digits(), (3, 1));
+        assert!(amount.divisible_by(&Decimal::from_str("0.25").unwrap()));
+        assert!(!amount.divisible_by(&Decimal::from_str("0.2").unwrap()));
+    }
+}
@@ -0,0 +1,50 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::number::Number;
+use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// Rejects NaN and infinities, which pass every comparison rule (`NaN < min` and `NaN > max`
+/// are both false). Integers and decimals are always finite.
+pub struct Finite {}
+
+impl<V, T> IValidatorRule<V> for Finite
+where
+    V: ValueRef<Target = T>,
+    T: Number + Send + Sync + 'static,
+{
+    fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        match value.value() {
+            Some(actual) if !actual.is_finite() => Err(ValidationError::new("validator.finite")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("finite", &["validator.finite"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    macro_rules! test_finite {
+        ($name:ident, $value:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let result = Finite {}.validate(&$value);
+                assert_eq!(result.is_ok(), $expected);
+            }
+        };
+    }
+
+    test_finite!(fin_f64_ok, 1.5f64, true);
+    test_finite!(fin_f64_nan_fail, f64::NAN, false);
+    test_finite!(fin_f32_inf_fail, f32::INFINITY, false);
+    test_finite!(fin_f64_neg_inf_fail, f64::NEG_INFINITY, false);
+    test_finite!(fin_i64_ok, i64::MAX, true);
+    test_finite!(fin_arc_f64_nan_fail, Arc::new(f64::NAN), false);
+    test_finite!(fin_opt_none, None::<f64>, true);
+}
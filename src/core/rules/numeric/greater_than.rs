@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct GreaterThan<T> {
@@ -23,6 +24,13 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("greater_than", &["validator.greater_than"])
+                .number("min", &self.min),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,76 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::number::Number;
+use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// At most `max` digits after the decimal point, trailing zeros aside. Floats are judged by
+/// their shortest round-trip form, so `0.1 + 0.2` (`0.30000000000000004`) fails `max = 2`.
+pub struct MaxDecimalPlaces {
+    pub max: usize,
+}
+
+impl<V, T> IValidatorRule<V> for MaxDecimalPlaces
+where
+    V: ValueRef<Target = T>,
+    T: Number + Send + Sync + 'static,
+{
+    fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        let Some(actual) = value.value() else {
+            return Ok(());
+        };
+        if !actual.is_finite() {
+            return Err(ValidationError::new("validator.finite"));
+        }
+        if actual.decimal_digits().1 > self.max {
+            return Err(ValidationError::new_with_params(
+                "validator.decimal.scale",
+                HashMap::from([("scale".into(), self.max.to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "max_decimal_places",
+                &["validator.decimal.scale", "validator.finite"],
+            )
+            .param("max", self.max),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! test_max_decimal_places {
+        ($name:ident, $value:expr, $max:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let validator = MaxDecimalPlaces { max: $max };
+                let result = validator.validate(&$value);
+                assert_eq!(result.is_ok(), $expected);
+            }
+        };
+    }
+
+    test_max_decimal_places!(mdp_f64_ok, 19.99, 2, true);
+    test_max_decimal_places!(mdp_f64_trailing_zero_ok, 19.90, 1, true);
+    test_max_decimal_places!(mdp_f64_fail, 19.999, 2, false);
+    test_max_decimal_places!(mdp_f64_sum_fail, 0.1 + 0.2, 2, false);
+    test_max_decimal_places!(mdp_f32_ok, 0.25f32, 2, true);
+    test_max_decimal_places!(mdp_i32_ok, 42, 0, true);
+    test_max_decimal_places!(mdp_f64_nan_fail, f64::NAN, 2, false);
+    test_max_decimal_places!(mdp_opt_none, None::<f64>, 0, true);
+
+    #[test]
+    fn reports_scale() {
+        let err = MaxDecimalPlaces { max: 2 }.validate(&1.005).unwrap_err();
+        assert_eq!(err.key, "validator.decimal.scale");
+        assert_eq!(err.params["scale"], "2");
+    }
+}
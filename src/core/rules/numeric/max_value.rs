@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct MaxValue<T> {
@@ -23,6 +24,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("max_value", &["validator.max_value"]).number("max", &self.max))
+    }
 }
 
 #[cfg(test)]
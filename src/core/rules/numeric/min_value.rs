@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct MinValue<T> {
@@ -24,6 +25,10 @@ where
 
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("min_value", &["validator.min_value"]).number("min", &self.min))
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,13 @@
+pub(crate) mod finite;
 pub(crate) mod greater_than;
 pub(crate) mod less_than;
+pub(crate) mod max_decimal_places;
 pub(crate) mod max_value;
 pub(crate) mod min_value;
+pub(crate) mod multiple_of;
 pub(crate) mod negative;
 pub(crate) mod negative_or_zero;
 pub(crate) mod positive;
 pub(crate) mod positive_or_zero;
+pub(crate) mod precision;
 pub(crate) mod range;
@@ -0,0 +1,74 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::number::Number;
+use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub struct MultipleOf<T> {
+    pub step: T,
+}
+
+impl<V, T> IValidatorRule<V> for MultipleOf<T>
+where
+    V: ValueRef<Target = T>,
+    T: Number + Send + Sync + 'static,
+{
+    fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        match value.value() {
+            Some(actual) if !actual.divisible_by(&self.step) => {
+                Err(ValidationError::new_with_params(
+                    "validator.multiple_of",
+                    HashMap::from([("step".into(), self.step.to_string())]),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("multiple_of", &["validator.multiple_of"])
+                .number("step", &self.step),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::NonZeroU32;
+
+    macro_rules! test_multiple_of {
+        ($name:ident, $value:expr, $step:expr, $expected:expr) => {
+            #[test]
+            fn $name() {
+                let validator = MultipleOf { step: $step };
+                let result = validator.validate(&$value);
+                assert_eq!(result.is_ok(), $expected);
+            }
+        };
+    }
+
+    test_multiple_of!(mo_i32_ok, 15, 5, true);
+    test_multiple_of!(mo_i32_fail, 16, 5, false);
+    test_multiple_of!(mo_u128_ok, u128::MAX - 1, 2, true);
+    test_multiple_of!(mo_usize_fail, 7usize, 2, false);
+    test_multiple_of!(mo_f64_cents_ok, 19.99, 0.01, true);
+    test_multiple_of!(mo_f64_fail, 0.35, 0.1, false);
+    test_multiple_of!(mo_f64_nan_fail, f64::NAN, 0.5, false);
+    test_multiple_of!(
+        mo_non_zero_ok,
+        NonZeroU32::new(300).unwrap(),
+        NonZeroU32::new(100).unwrap(),
+        true
+    );
+    test_multiple_of!(mo_opt_none, None::<i64>, 3, true);
+
+    #[test]
+    fn reports_step() {
+        let err = MultipleOf { step: 0.25 }.validate(&1.1).unwrap_err();
+        assert_eq!(err.key, "validator.multiple_of");
+        assert_eq!(err.params["step"], "0.25");
+    }
+}
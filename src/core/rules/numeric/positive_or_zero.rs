@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use num_traits::Zero;
 
 pub struct PositiveOrZero {}
@@ -18,6 +19,13 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "positive_or_zero",
+            &["validator.positive_or_zero"],
+        ))
+    }
 }
 
 #[cfg(test)]
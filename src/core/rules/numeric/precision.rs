@@ -0,0 +1,113 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::number::Number;
+use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Number that fits SQL `DECIMAL(precision, scale)`: at most `scale` digits after the point and
+/// `precision - scale` before it. The numeric counterpart of the `decimal_precision` string
+/// rule, with the same error keys and params.
+pub struct Precision {
+    pub precision: usize,
+    pub scale: usize,
+}
+
+impl Precision {
+    fn error(&self, key: &str) -> ValidationError {
+        ValidationError::new_with_params(
+            key,
+            HashMap::from([
+                ("precision".into(), self.precision.to_string()),
+                ("scale".into(), self.scale.to_string()),
+                (
+                    "integer".into(),
+                    self.precision.saturating_sub(self.scale).to_string(),
+                ),
+            ]),
+        )
+    }
+}
+
+impl<V, T> IValidatorRule<V> for Precision
+where
+    V: ValueRef<Target = T>,
+    T: Number + Send + Sync + 'static,
+{
+    fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        let Some(actual) = value.value() else {
+            return Ok(());
+        };
+        if !actual.is_finite() {
+            return Err(ValidationError::new("validator.finite"));
+        }
+        let (integer, fraction) = actual.decimal_digits();
+        if fraction > self.scale {
+            return Err(self.error("validator.decimal.scale"));
+        }
+        if integer > self.precision.saturating_sub(self.scale) {
+            return Err(self.error("validator.decimal.precision"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "precision",
+                &[
+                    "validator.decimal.scale",
+                    "validator.decimal.precision",
+                    "validator.finite",
+                ],
+            )
+            .param("precision", self.precision)
+            .param("scale", self.scale),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key<V: ValueRef<Target = T>, T: Number + Send + Sync + 'static>(value: V) -> Option<String> {
+        let rule = Precision {
+            precision: 5,
+            scale: 2,
+        };
+        rule.validate(&value).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn accepts_numbers_within_precision_and_scale() {
+        assert_eq!(key(999.99), None);
+        assert_eq!(key(-0.5f32), None);
+        assert_eq!(key(120u16), None);
+        assert_eq!(key(None::<f64>), None);
+    }
+
+    #[test]
+    fn rejects_oversized_numbers() {
+        assert_eq!(key(1.005).as_deref(), Some("validator.decimal.scale"));
+        assert_eq!(key(1000.0).as_deref(), Some("validator.decimal.precision"));
+        assert_eq!(
+            key(-1234i64).as_deref(),
+            Some("validator.decimal.precision")
+        );
+        assert_eq!(key(f64::INFINITY).as_deref(), Some("validator.finite"));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn checks_decimals() {
+        use rust_decimal::Decimal;
+        use std::str::FromStr;
+
+        assert_eq!(key(Decimal::from_str("999.990").unwrap()), None);
+        assert_eq!(
+            key(Decimal::from_str("0.001").unwrap()).as_deref(),
+            Some("validator.decimal.scale")
+        );
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::value_ref::ValueRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct Range<T> {
@@ -27,6 +28,14 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("range", &["validator.range"])
+                .number("min", &self.min)
+                .number("max", &self.max),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -50,6 +59,15 @@ mod tests {
         };
     }
 
+    #[test]
+    fn describes_bounds() {
+        let rule = Range { min: 1, max: 120 };
+        let descriptor = IValidatorRule::<u8>::describe(&rule).unwrap();
+        assert_eq!(descriptor.name, "range");
+        assert_eq!(descriptor.params["min"], serde_json::json!(1));
+        assert_eq!(descriptor.params["max"], serde_json::json!(120));
+    }
+
     // ┌────────── Примітиви ──────────┐
     test_range!(rg_i8_inside, i8, 5, 0, 10, true);
     test_range!(rg_i8_below, i8, -1, 0, 10, false);
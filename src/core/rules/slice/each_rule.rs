@@ -1,7 +1,9 @@
+use crate::core::context::ValidationContext;
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::primitive::PrimitiveRule;
 use crate::core::rules::slice_ref::SliceRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 
 pub struct EachRule<R> {
     pub rule: R,
@@ -14,9 +16,17 @@ where
     R: IValidatorRule<V::Item>,
 {
     fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &V,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
         if let Some(slice) = value.slice() {
             for (i, item) in slice.iter().enumerate() {
-                if let Err(mut err) = self.rule.validate(item) {
+                if let Err(mut err) = self.rule.validate_with_context(item, ctx) {
                     err.params.insert("index".into(), i.to_string());
                     return Err(err);
                 }
@@ -24,6 +34,11 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let inner = self.rule.describe()?;
+        Some(RuleDescriptor::new("each", inner.keys).with_rules(vec![inner]))
+    }
 }
 
 #[cfg(test)]
@@ -31,10 +46,14 @@ mod tests {
     use super::*;
     use crate::core::contract::IValidatorRule;
     use crate::core::rules::string::max_length::MaxLength;
+    use crate::core::text::length::LengthUnit;
 
     fn validator(max: usize) -> EachRule<MaxLength> {
         EachRule {
-            rule: MaxLength { max },
+            rule: MaxLength {
+                max,
+                unit: LengthUnit::Chars,
+            },
         }
     }
 
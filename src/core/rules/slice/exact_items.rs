@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::slice_ref::SliceRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub(crate) struct ExactItems {
@@ -22,6 +23,13 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("exact_items", &["validator.exact_items"])
+                .param("expected", self.expected),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::slice_ref::SliceRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub(crate) struct MaxItems {
@@ -22,6 +23,10 @@ where
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("max_items", &["validator.max_items"]).param("max", self.max))
+    }
 }
 
 #[cfg(test)]
@@ -46,6 +46,13 @@ impl<T> SliceRef for std::sync::Arc<Vec<T>> {
     }
 }
 
+impl SliceRef for serde_json::Value {
+    type Item = serde_json::Value;
+    fn slice(&self) -> Option<&[serde_json::Value]> {
+        self.as_array().map(|v| v.as_slice())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SliceRef;
@@ -102,6 +109,13 @@ mod tests {
         assert_eq!(slice, Some(&[12, 13][..]));
     }
 
+    #[test]
+    fn slice_from_json_value() {
+        let value = serde_json::json!([1, 2]);
+        assert_eq!(value.slice().map(|s| s.len()), Some(2));
+        assert_eq!(serde_json::json!({}).slice(), None);
+    }
+
     #[test]
     fn slice_from_empty_vec() {
         let value: Vec<i32> = vec![];
@@ -22,6 +22,12 @@ impl StrAsRef for &str {
     }
 }
 
+impl StrAsRef for serde_json::Value {
+    fn as_str_ref(&self) -> Option<&str> {
+        self.as_str()
+    }
+}
+
 impl<T: StrAsRef> StrAsRef for Option<T> {
     fn as_str_ref(&self) -> Option<&str> {
         self.as_ref().and_then(|v| v.as_str_ref())
@@ -126,6 +132,12 @@ mod tests {
         assert_eq!(value.as_str_ref(), Some("ref-cow"));
     }
 
+    #[test]
+    fn from_json_value() {
+        assert_eq!(serde_json::json!("json").as_str_ref(), Some("json"));
+        assert_eq!(serde_json::json!(1).as_str_ref(), None);
+    }
+
     #[test]
     fn nested_option_rc() {
         let value: Option<Rc<String>> = Some(Rc::new("nested".to_string()));
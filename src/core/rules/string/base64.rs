@@ -0,0 +1,102 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::base64_options::Base64Options;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub(crate) struct Base64 {
+    pub options: Base64Options,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Base64 {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let options = &self.options;
+        let Some(decoded) = options.decode(value) else {
+            return Err(ValidationError::new("validator.base64"));
+        };
+
+        let size = decoded.len();
+        if let Some(min) = options.min_decoded.filter(|&min| size < min) {
+            return Err(ValidationError::new_with_params(
+                "validator.base64.too_small",
+                HashMap::from([("min".into(), min.to_string())]),
+            ));
+        }
+        if let Some(max) = options.max_decoded.filter(|&max| size > max) {
+            return Err(ValidationError::new_with_params(
+                "validator.base64.too_large",
+                HashMap::from([("max".into(), max.to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let options = &self.options;
+        let mut descriptor = RuleDescriptor::new(
+            "base64",
+            &[
+                "validator.base64",
+                "validator.base64.too_small",
+                "validator.base64.too_large",
+            ],
+        )
+        .param("url_safe", options.url_safe)
+        .param("padding", options.padding.as_str());
+
+        if let Some(min) = options.min_decoded {
+            descriptor = descriptor.param("min_decoded_size", min);
+        }
+        if let Some(max) = options.max_decoded {
+            descriptor = descriptor.param("max_decoded_size", max);
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::formats::base64_options::Base64Padding;
+
+    fn key(options: Base64Options, value: &str) -> Option<String> {
+        Base64 { options }.validate(&value).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn validates_encoding() {
+        assert_eq!(key(Base64Options::new(), "aGVsbG8="), None);
+        assert_eq!(
+            key(Base64Options::new(), "aGVsbG8").as_deref(),
+            Some("validator.base64")
+        );
+        assert_eq!(
+            key(Base64Options::new(), "a GVsbG8=").as_deref(),
+            Some("validator.base64")
+        );
+        let url_safe = Base64Options::new()
+            .url_safe(true)
+            .padding(Base64Padding::Optional);
+        assert_eq!(key(url_safe, "-_-_"), None);
+    }
+
+    #[test]
+    fn limits_decoded_size() {
+        let options = || Base64Options::new().min_decoded_size(2).max_decoded_size(4);
+        assert_eq!(key(options(), "aGVs"), None);
+
+        let err = Base64 { options: options() }.validate(&"aA==").unwrap_err();
+        assert_eq!(err.key, "validator.base64.too_small");
+        assert_eq!(err.params["min"], "2");
+
+        let err = Base64 { options: options() }
+            .validate(&"aGVsbG8=")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.base64.too_large");
+        assert_eq!(err.params["max"], "4");
+    }
+}
@@ -0,0 +1,53 @@
+use crate::core::codes::locale::canonical_locale;
+use crate::core::codes::{CodeCase, check_code};
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// BCP 47 language tag (`uk-UA`, `zh-Hant-TW`).
+pub(crate) struct Bcp47Locale {
+    pub case: CodeCase,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Bcp47Locale {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(value) => check_code(value, self.case, "validator.locale", canonical_locale),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("bcp47_locale", &["validator.locale", "validator.code.case"])
+                .param("case", self.case.as_str()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_locales() {
+        let canonical = Bcp47Locale {
+            case: CodeCase::Canonical,
+        };
+        assert!(canonical.validate(&"uk-UA").is_ok());
+        assert_eq!(
+            canonical.validate(&"uk_UA").unwrap_err().key,
+            "validator.locale"
+        );
+
+        let err = canonical.validate(&"uk-ua").unwrap_err();
+        assert_eq!(err.key, "validator.code.case");
+        assert_eq!(err.params["expected"], "uk-UA");
+
+        let insensitive = Bcp47Locale {
+            case: CodeCase::Insensitive,
+        };
+        assert!(insensitive.validate(&"uk-ua").is_ok());
+    }
+}
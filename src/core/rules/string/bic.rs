@@ -0,0 +1,41 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// ISO 9362: bank (4 letters), country (2 letters), location (2), optional branch (3).
+const BIC_PATTERN: &str = r"^[A-Z]{6}[A-Z0-9]{2}([A-Z0-9]{3})?$";
+
+static BIC: Lazy<Regex> = Lazy::new(|| Regex::new(BIC_PATTERN).unwrap());
+
+pub(crate) struct Bic {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Bic {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !BIC.is_match(s) => Err(ValidationError::new("validator.bic")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("bic", &["validator.bic"]).param("pattern", BIC_PATTERN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_bics() {
+        assert!(Bic {}.validate(&"DEUTDEFF").is_ok());
+        assert!(Bic {}.validate(&"PBANUA2X").is_ok());
+        assert!(Bic {}.validate(&"DEUTDEFF500").is_ok());
+        assert!(Bic {}.validate(&"deutdeff").is_err());
+        assert!(Bic {}.validate(&"DEUTDEFF50").is_err());
+        assert!(Bic {}.validate(&"DEU1DEFF").is_err());
+    }
+}
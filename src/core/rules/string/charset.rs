@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct Charset {
@@ -19,6 +20,10 @@ impl<T: StrAsRef> IValidatorRule<T> for Charset {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("charset", &["validator.charset"]))
+    }
 }
 
 #[cfg(test)]
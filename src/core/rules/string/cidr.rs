@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 use std::net::IpAddr;
 
@@ -43,6 +44,17 @@ impl<T: StrAsRef> IValidatorRule<T> for Cidr {
 
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "cidr",
+            &[
+                "validator.cidr.format",
+                "validator.cidr.ip_invalid",
+                "validator.cidr.mask_invalid",
+            ],
+        ))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,102 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::finance::card::{CardBrand, card_digits, luhn_valid};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Card number with a valid Luhn checksum; spaces and dashes between digits are allowed.
+/// With a non-empty `brands`, the detected brand must be one of them.
+pub(crate) struct CreditCard {
+    pub brands: Vec<CardBrand>,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for CreditCard {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let Some(digits) = card_digits(value) else {
+            return Err(ValidationError::new("validator.credit_card.format"));
+        };
+        if !luhn_valid(&digits) {
+            return Err(ValidationError::new("validator.credit_card.checksum"));
+        }
+
+        let brand = CardBrand::detect(&digits);
+        if self.brands.is_empty() || brand.is_some_and(|b| self.brands.contains(&b)) {
+            return Ok(());
+        }
+        let allowed: Vec<_> = self.brands.iter().map(CardBrand::as_str).collect();
+        Err(ValidationError::new_with_params(
+            "validator.credit_card.brand",
+            HashMap::from([
+                (
+                    "brand".into(),
+                    brand.map_or("unknown", |b| b.as_str()).to_string(),
+                ),
+                ("allowed".into(), allowed.join(", ")),
+            ]),
+        ))
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let mut descriptor = RuleDescriptor::new(
+            "credit_card",
+            &[
+                "validator.credit_card.format",
+                "validator.credit_card.checksum",
+                "validator.credit_card.brand",
+            ],
+        );
+        if !self.brands.is_empty() {
+            let brands: Vec<_> = self.brands.iter().map(CardBrand::as_str).collect();
+            descriptor = descriptor.param("brands", brands);
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(brands: &[CardBrand], value: &str) -> Option<String> {
+        let rule = CreditCard {
+            brands: brands.to_vec(),
+        };
+        rule.validate(&value).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn validates_numbers() {
+        assert_eq!(key(&[], "4111 1111 1111 1111"), None);
+        assert_eq!(key(&[], "3782-822463-10005"), None);
+        assert_eq!(
+            key(&[], "4111 1111 1111 1112").as_deref(),
+            Some("validator.credit_card.checksum")
+        );
+        assert_eq!(
+            key(&[], "4111.1111.1111.1111").as_deref(),
+            Some("validator.credit_card.format")
+        );
+    }
+
+    #[test]
+    fn restricts_brands() {
+        let brands = [CardBrand::Visa, CardBrand::Mastercard];
+        assert_eq!(key(&brands, "5555555555554444"), None);
+
+        let rule = CreditCard {
+            brands: brands.to_vec(),
+        };
+        let err = rule.validate(&"378282246310005").unwrap_err();
+        assert_eq!(err.key, "validator.credit_card.brand");
+        assert_eq!(err.params["brand"], "amex");
+        assert_eq!(err.params["allowed"], "visa, mastercard");
+
+        // Luhn-valid, but no known brand.
+        let err = rule.validate(&"9111111111111110").unwrap_err();
+        assert_eq!(err.params["brand"], "unknown");
+    }
+}
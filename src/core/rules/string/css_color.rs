@@ -0,0 +1,21 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::css_color::is_css_color;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// Any CSS color value: hex, named (`rebeccapurple`) or functional (`rgb(0 0 0 / 50%)`).
+pub(crate) struct CssColor {}
+
+impl<T: StrAsRef> IValidatorRule<T> for CssColor {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_css_color(s) => Err(ValidationError::new("validator.css_color")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("css_color", &["validator.css_color"]))
+    }
+}
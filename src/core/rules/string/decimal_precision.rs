@@ -0,0 +1,105 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Decimal amount that fits SQL `DECIMAL(precision, scale)`: an optional sign, digits and an
+/// optional `.` fraction of at most `scale` digits, with at most `precision - scale` digits
+/// before the point (leading zeros aside).
+pub(crate) struct DecimalPrecision {
+    pub precision: usize,
+    pub scale: usize,
+}
+
+impl DecimalPrecision {
+    fn error(&self, key: &str) -> ValidationError {
+        ValidationError::new_with_params(
+            key,
+            HashMap::from([
+                ("precision".into(), self.precision.to_string()),
+                ("scale".into(), self.scale.to_string()),
+                (
+                    "integer".into(),
+                    self.precision.saturating_sub(self.scale).to_string(),
+                ),
+            ]),
+        )
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for DecimalPrecision {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let unsigned = value.strip_prefix(['-', '+']).unwrap_or(value);
+        let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+        let digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+        let has_fraction = unsigned.contains('.');
+        if integer.is_empty()
+            || !digits(integer)
+            || !digits(fraction)
+            || (has_fraction && fraction.is_empty())
+        {
+            return Err(self.error("validator.decimal.format"));
+        }
+        if fraction.len() > self.scale {
+            return Err(self.error("validator.decimal.scale"));
+        }
+        if integer.trim_start_matches('0').len() > self.precision.saturating_sub(self.scale) {
+            return Err(self.error("validator.decimal.precision"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "decimal_precision",
+                &[
+                    "validator.decimal.format",
+                    "validator.decimal.scale",
+                    "validator.decimal.precision",
+                ],
+            )
+            .param("precision", self.precision)
+            .param("scale", self.scale),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(value: &str) -> Option<String> {
+        let rule = DecimalPrecision {
+            precision: 10,
+            scale: 2,
+        };
+        rule.validate(&value).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn accepts_amounts_within_precision_and_scale() {
+        assert_eq!(key("12345678.90"), None);
+        assert_eq!(key("-0.5"), None);
+        assert_eq!(key("+42"), None);
+        assert_eq!(key("00000000012.00"), None);
+    }
+
+    #[test]
+    fn rejects_malformed_or_oversized_amounts() {
+        assert_eq!(key("1,50").as_deref(), Some("validator.decimal.format"));
+        assert_eq!(key("1.").as_deref(), Some("validator.decimal.format"));
+        assert_eq!(key(".5").as_deref(), Some("validator.decimal.format"));
+        assert_eq!(key("1e3").as_deref(), Some("validator.decimal.format"));
+        assert_eq!(key("1.505").as_deref(), Some("validator.decimal.scale"));
+        assert_eq!(
+            key("123456789.00").as_deref(),
+            Some("validator.decimal.precision")
+        );
+    }
+}
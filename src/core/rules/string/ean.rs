@@ -0,0 +1,21 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::gtin::is_ean;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// EAN-8 or EAN-13 barcode number.
+pub(crate) struct Ean {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Ean {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_ean(s) => Err(ValidationError::new("validator.ean")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("ean", &["validator.ean"]))
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use idna::domain_to_ascii;
 use once_cell::sync::Lazy;
 use regex::Regex;
@@ -9,59 +10,111 @@ use std::collections::HashMap;
 static LOCAL_RE: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"^[\p{L}0-9.!#$%&'*+/=?^_`{|}~-]+$").unwrap());
 
-fn validate_email(email: &str) -> Result<(), String> {
+/// Why an address failed the format check; `code` groups them for `validator.email.*` keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum EmailFailure {
+    TooLong,
+    AtSign,
+    LocalLength,
+    LocalChars,
+    LocalDots,
+    DomainEncoding,
+    DomainLength,
+    DomainDot,
+    DomainEmptyLabel,
+    DomainHyphen,
+    DomainChars,
+    EmptyTld,
+}
+
+impl EmailFailure {
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            EmailFailure::TooLong | EmailFailure::LocalLength | EmailFailure::DomainLength => {
+                "length"
+            }
+            EmailFailure::AtSign => "at_sign",
+            EmailFailure::LocalChars | EmailFailure::LocalDots => "local",
+            EmailFailure::DomainEncoding
+            | EmailFailure::DomainDot
+            | EmailFailure::DomainEmptyLabel
+            | EmailFailure::DomainHyphen
+            | EmailFailure::DomainChars => "domain_format",
+            EmailFailure::EmptyTld => "tld",
+        }
+    }
+
+    pub(crate) fn reason(&self) -> &'static str {
+        match self {
+            EmailFailure::TooLong => "Email address is too long (max 254 characters)",
+            EmailFailure::AtSign => "Email must contain exactly one '@' symbol",
+            EmailFailure::LocalLength => "Local part must be 1 to 64 characters",
+            EmailFailure::LocalChars => "Invalid characters in local part",
+            EmailFailure::LocalDots => "Invalid dot sequence in local part",
+            EmailFailure::DomainEncoding => "Invalid domain encoding",
+            EmailFailure::DomainLength => "Domain part must be 1 to 255 characters",
+            EmailFailure::DomainDot => "Domain must have at least one dot (e.g., example.com)",
+            EmailFailure::DomainEmptyLabel => "Domain parts cannot be empty",
+            EmailFailure::DomainHyphen => "Domain parts cannot start or end with a hyphen",
+            EmailFailure::DomainChars => "Invalid characters in domain",
+            EmailFailure::EmptyTld => "Top-level domain cannot be empty",
+        }
+    }
+}
+
+/// Returns the domain in its ASCII (punycode) form.
+pub(crate) fn validate_email(email: &str) -> Result<String, EmailFailure> {
     // Check total length (RFC 5321: max 254 chars)
     if email.len() > 254 {
-        return Err("Email address is too long (max 254 characters)".to_string());
+        return Err(EmailFailure::TooLong);
     }
 
     // Split into local part and domain
     let parts: Vec<&str> = email.split('@').collect();
     if parts.len() != 2 {
-        return Err("Email must contain exactly one '@' symbol".to_string());
+        return Err(EmailFailure::AtSign);
     }
     let (local, domain) = (parts[0], parts[1]);
 
     // Check local part (max 64 chars, RFC 5321)
     if local.is_empty() || local.len() > 64 {
-        return Err("Local part must be 1 to 64 characters".to_string());
+        return Err(EmailFailure::LocalLength);
     }
 
     // Validate local part with Unicode support
     if !LOCAL_RE.is_match(local) {
-        return Err("Invalid characters in local part".to_string());
+        return Err(EmailFailure::LocalChars);
     }
     if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
-        return Err("Invalid dot sequence in local part".to_string());
+        return Err(EmailFailure::LocalDots);
     }
 
     // Convert domain to ASCII (handles IDN like приклад.укр)
-    let domain_ascii =
-        domain_to_ascii(domain).map_err(|_| "Invalid domain encoding".to_string())?;
+    let domain_ascii = domain_to_ascii(domain).map_err(|_| EmailFailure::DomainEncoding)?;
 
     // Check domain length (max 255 chars, RFC 5321)
     if domain_ascii.is_empty() || domain_ascii.len() > 255 {
-        return Err("Domain part must be 1 to 255 characters".to_string());
+        return Err(EmailFailure::DomainLength);
     }
 
     // Validate domain (ASCII form)
     let domain_parts: Vec<&str> = domain_ascii.split('.').collect();
     if domain_parts.len() < 2 {
-        return Err("Domain must have at least one dot (e.g., example.com)".to_string());
+        return Err(EmailFailure::DomainDot);
     }
 
     for part in domain_parts.iter() {
         if part.is_empty() {
-            return Err("Domain parts cannot be empty".to_string());
+            return Err(EmailFailure::DomainEmptyLabel);
         }
         if part.starts_with('-') || part.ends_with('-') {
-            return Err("Domain parts cannot start or end with a hyphen".to_string());
+            return Err(EmailFailure::DomainHyphen);
         }
         for c in part.chars() {
             if c.is_alphanumeric() || c == '-' {
                 continue;
             }
-            return Err("Invalid characters in domain".to_string());
+            return Err(EmailFailure::DomainChars);
         }
     }
 
@@ -69,10 +122,10 @@ fn validate_email(email: &str) -> Result<(), String> {
     // Or use tld.len() < 1 to prevent empty TLDs
     let tld = domain_parts.last().unwrap();
     if tld.is_empty() {
-        return Err("Top-level domain cannot be empty".to_string());
+        return Err(EmailFailure::EmptyTld);
     }
 
-    Ok(())
+    Ok(domain_ascii)
 }
 
 pub(crate) struct Email {}
@@ -80,15 +133,22 @@ pub(crate) struct Email {}
 impl<T: StrAsRef> IValidatorRule<T> for Email {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(email) = value.as_str_ref() {
-            if let Err(reason) = validate_email(email) {
+            if let Err(failure) = validate_email(email) {
                 return Err(ValidationError::new_with_params(
                     "validator.email_format",
-                    HashMap::from([("reason".into(), reason)]),
+                    HashMap::from([
+                        ("reason".into(), failure.reason().to_string()),
+                        ("code".into(), failure.code().to_string()),
+                    ]),
                 ));
             }
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("email", &["validator.email_format"]))
+    }
 }
 
 #[cfg(test)]
@@ -117,6 +177,7 @@ mod tests {
         let err = result.unwrap_err();
         assert_eq!(err.key, "validator.email_format");
         assert!(err.params.get("reason").unwrap().contains("@"));
+        assert_eq!(err.params.get("code"), Some(&"at_sign".into()));
     }
 
     #[test]
@@ -0,0 +1,210 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::net::email_options::{EmailOptions, is_disposable};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::string::email::validate_email;
+use crate::core::rules::string::url_with_options::has_tld;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub(crate) struct EmailWithOptions {
+    pub options: EmailOptions,
+}
+
+fn domain_error(key: &str, domain: &str) -> ValidationError {
+    ValidationError::new_with_params(key, HashMap::from([("domain".into(), domain.into())]))
+}
+
+/// Extracts `addr` from `Name <addr>`; a bare address is returned unchanged.
+fn strip_display_name(value: &str) -> Result<&str, ValidationError> {
+    let value = value.trim();
+    if !value.contains('<') && !value.contains('>') {
+        return Ok(value);
+    }
+    let invalid = || ValidationError::new("validator.email.display_name");
+    let inner = value.strip_suffix('>').ok_or_else(invalid)?;
+    let (name, address) = inner.rsplit_once('<').ok_or_else(invalid)?;
+    let name = name.trim();
+    let quoted = name.len() >= 2 && name.starts_with('"') && name.ends_with('"');
+    if address.contains(['<', '>']) || (!quoted && name.contains(['<', '>', '"', '@'])) {
+        return Err(invalid());
+    }
+    Ok(address)
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for EmailWithOptions {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(s) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let options = &self.options;
+
+        let address = if options.allow_display_name {
+            strip_display_name(s)?
+        } else {
+            s
+        };
+
+        let domain = validate_email(address).map_err(|failure| {
+            ValidationError::new_with_params(
+                format!("validator.email.{}", failure.code()),
+                HashMap::from([("reason".into(), failure.reason().to_string())]),
+            )
+        })?;
+        let domain = domain.to_ascii_lowercase();
+
+        if options.ascii_only && !address.is_ascii() {
+            return Err(ValidationError::new("validator.email.ascii"));
+        }
+        if options.require_tld && !has_tld(&domain) {
+            return Err(domain_error("validator.email.tld", &domain));
+        }
+        if !options.domain_allowed(&domain) {
+            return Err(domain_error("validator.email.domain", &domain));
+        }
+        if options.block_disposable && is_disposable(&domain) {
+            return Err(domain_error("validator.email.disposable", &domain));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let options = &self.options;
+        let mut descriptor = RuleDescriptor::new(
+            "email",
+            &[
+                "validator.email.length",
+                "validator.email.at_sign",
+                "validator.email.local",
+                "validator.email.domain_format",
+                "validator.email.tld",
+                "validator.email.ascii",
+                "validator.email.domain",
+                "validator.email.disposable",
+                "validator.email.display_name",
+            ],
+        );
+
+        if !options.allowed_domains.is_empty() {
+            descriptor = descriptor.param("allow_domains", options.allowed_domains.clone());
+        }
+        if !options.denied_domains.is_empty() {
+            descriptor = descriptor.param("deny_domains", options.denied_domains.clone());
+        }
+        for (name, enabled) in [
+            ("ascii_only", options.ascii_only),
+            ("block_disposable", options.block_disposable),
+            ("require_tld", options.require_tld),
+            ("allow_display_name", options.allow_display_name),
+        ] {
+            if enabled {
+                descriptor = descriptor.param(name, true);
+            }
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(options: EmailOptions) -> EmailWithOptions {
+        EmailWithOptions { options }
+    }
+
+    fn key(options: EmailOptions, value: &str) -> String {
+        validator(options).validate(&value).unwrap_err().key
+    }
+
+    #[test]
+    fn default_options_match_email() {
+        let rule = validator(EmailOptions::new());
+        assert!(rule.validate(&"user@example.com").is_ok());
+        assert!(rule.validate(&"юзер@приклад.укр").is_ok());
+        assert!(rule.validate(&"user@mailinator.com").is_ok());
+        assert_eq!(
+            key(EmailOptions::new(), "Jane <jane@example.com>"),
+            "validator.email.local"
+        );
+    }
+
+    #[test]
+    fn exposes_specific_format_failures() {
+        let cases = [
+            ("userexample.com", "validator.email.at_sign"),
+            ("a..b@example.com", "validator.email.local"),
+            ("user@localhost", "validator.email.domain_format"),
+            ("user@-example.com", "validator.email.domain_format"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(key(EmailOptions::new(), value), expected, "{value}");
+        }
+        let long = format!("{}@example.com", "a".repeat(65));
+        let err = validator(EmailOptions::new()).validate(&long).unwrap_err();
+        assert_eq!(err.key, "validator.email.length");
+        assert!(err.params["reason"].contains("Local part"));
+    }
+
+    #[test]
+    fn applies_policy_options() {
+        let options = || {
+            EmailOptions::new()
+                .ascii_only(true)
+                .require_tld(true)
+                .block_disposable(true)
+                .deny_domains(&["competitor.com"])
+        };
+        assert!(validator(options()).validate(&"user@example.com").is_ok());
+
+        let cases = [
+            ("юзер@example.com", "validator.email.ascii"),
+            ("user@приклад.укр", "validator.email.ascii"),
+            ("user@example.c0m", "validator.email.tld"),
+            ("user@competitor.com", "validator.email.domain"),
+            ("user@mailinator.com", "validator.email.disposable"),
+            ("user@eu.Mailinator.com", "validator.email.disposable"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(key(options(), value), expected, "{value}");
+        }
+
+        let err = validator(options())
+            .validate(&"user@mailinator.com")
+            .unwrap_err();
+        assert_eq!(err.params["domain"], "mailinator.com");
+    }
+
+    #[test]
+    fn allow_list_matches_idn_domains() {
+        let options = || EmailOptions::new().allow_domains(&["*.приклад.укр", "example.com"]);
+        assert!(validator(options()).validate(&"a@mail.приклад.укр").is_ok());
+        assert!(validator(options()).validate(&"a@example.com").is_ok());
+        assert_eq!(key(options(), "a@gmail.com"), "validator.email.domain");
+    }
+
+    #[test]
+    fn accepts_display_names_when_enabled() {
+        let options = || EmailOptions::new().allow_display_name(true);
+        for value in [
+            "jane@example.com",
+            "Jane Doe <jane@example.com>",
+            "\"Doe, Jane\" <jane@example.com>",
+            "<jane@example.com>",
+        ] {
+            assert!(validator(options()).validate(&value).is_ok(), "{value}");
+        }
+        for value in [
+            "Jane <jane@example.com",
+            "Jane <<jane@example.com>>",
+            "a@b <jane@example.com>",
+        ] {
+            assert_eq!(
+                key(options(), value),
+                "validator.email.display_name",
+                "{value}"
+            );
+        }
+        assert_eq!(key(options(), "Jane <oops>"), "validator.email.at_sign");
+    }
+}
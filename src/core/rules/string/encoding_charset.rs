@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use encoding_rs::Encoding;
 use std::collections::HashMap;
 
@@ -33,6 +34,16 @@ impl<T: StrAsRef> IValidatorRule<T> for EncodingCharset {
             Ok(())
         }
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "encoding_charset",
+                &["validator.invalid_encoding", "validator.unknown_charset"],
+            )
+            .param("charset", self.charset),
+        )
+    }
 }
 
 #[cfg(test)]
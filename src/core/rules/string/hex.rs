@@ -0,0 +1,40 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Hexadecimal digits of either case, without a `0x` prefix.
+const HEX_PATTERN: &str = r"^[0-9a-fA-F]+$";
+
+static HEX: Lazy<Regex> = Lazy::new(|| Regex::new(HEX_PATTERN).unwrap());
+
+pub(crate) struct Hex {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Hex {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !HEX.is_match(s) => Err(ValidationError::new("validator.hex")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("hex", &["validator.hex"]).param("pattern", HEX_PATTERN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_hex_strings() {
+        assert!(Hex {}.validate(&"deadBEEF").is_ok());
+        assert!(Hex {}.validate(&"0").is_ok());
+        assert!(Hex {}.validate(&"0xff").is_err());
+        assert!(Hex {}.validate(&"").is_err());
+        assert!(Hex {}.validate(&"cafe babe").is_err());
+    }
+}
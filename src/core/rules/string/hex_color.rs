@@ -0,0 +1,24 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::css_color::{HEX_COLOR_PATTERN, is_hex_color};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`.
+pub(crate) struct HexColor {}
+
+impl<T: StrAsRef> IValidatorRule<T> for HexColor {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_hex_color(s) => Err(ValidationError::new("validator.hex_color")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("hex_color", &["validator.hex_color"])
+                .param("pattern", HEX_COLOR_PATTERN),
+        )
+    }
+}
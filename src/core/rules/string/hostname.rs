@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use regex::Regex;
 
 pub(crate) struct Hostname {}
@@ -20,6 +21,10 @@ impl<T: StrAsRef> IValidatorRule<T> for Hostname {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("hostname", &["validator.hostname"]))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,52 @@
+use crate::core::codes::timezone::timezone;
+use crate::core::codes::{CodeCase, check_code};
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// IANA time zone name (`Europe/Kyiv`), links to other zones included.
+pub(crate) struct IanaTimezone {
+    pub case: CodeCase,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for IanaTimezone {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(value) => check_code(value, self.case, "validator.timezone", timezone),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "iana_timezone",
+                &["validator.timezone", "validator.code.case"],
+            )
+            .param("case", self.case.as_str()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_time_zones() {
+        let rule = IanaTimezone {
+            case: CodeCase::Canonical,
+        };
+        assert!(rule.validate(&"Europe/Kyiv").is_ok());
+        assert!(rule.validate(&"Europe/Kiev").is_ok());
+        assert_eq!(
+            rule.validate(&"Kyiv").unwrap_err().key,
+            "validator.timezone"
+        );
+        assert_eq!(
+            rule.validate(&"europe/kyiv").unwrap_err().params["expected"],
+            "Europe/Kyiv"
+        );
+    }
+}
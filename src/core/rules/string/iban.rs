@@ -0,0 +1,66 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::finance::iban::{IbanFailure, validate_iban};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub(crate) struct Iban {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Iban {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        validate_iban(value).map_err(|failure| match failure {
+            IbanFailure::Format => ValidationError::new("validator.iban.format"),
+            IbanFailure::Country(country) => ValidationError::new_with_params(
+                "validator.iban.country",
+                HashMap::from([("country".into(), country)]),
+            ),
+            IbanFailure::Length { country, expected } => ValidationError::new_with_params(
+                "validator.iban.length",
+                HashMap::from([
+                    ("country".into(), country),
+                    ("expected".into(), expected.to_string()),
+                ]),
+            ),
+            IbanFailure::Checksum => ValidationError::new("validator.iban.checksum"),
+        })
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "iban",
+            &[
+                "validator.iban.format",
+                "validator.iban.country",
+                "validator.iban.length",
+                "validator.iban.checksum",
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_ibans() {
+        assert!(
+            Iban {}
+                .validate(&"UA21 3223 1300 0002 6007 2335 6600 1")
+                .is_ok()
+        );
+        assert!(Iban {}.validate(&None::<String>).is_ok());
+
+        let err = Iban {}.validate(&"DE8937040044053201300").unwrap_err();
+        assert_eq!(err.key, "validator.iban.length");
+        assert_eq!(err.params["country"], "DE");
+        assert_eq!(err.params["expected"], "22");
+
+        let err = Iban {}.validate(&"DE88370400440532013000").unwrap_err();
+        assert_eq!(err.key, "validator.iban.checksum");
+    }
+}
@@ -0,0 +1,21 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::isbn::is_isbn;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// ISBN-10 or ISBN-13, hyphens and spaces allowed.
+pub(crate) struct Isbn {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Isbn {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_isbn(s) => Err(ValidationError::new("validator.isbn")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("isbn", &["validator.isbn"]))
+    }
+}
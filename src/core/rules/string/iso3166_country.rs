@@ -0,0 +1,71 @@
+use crate::core::codes::country::{alpha2, alpha3};
+use crate::core::codes::{CodeCase, check_code};
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+#[derive(Clone, Copy)]
+pub(crate) enum CountryFormat {
+    Alpha2,
+    Alpha3,
+}
+
+pub(crate) struct Iso3166Country {
+    pub format: CountryFormat,
+    pub case: CodeCase,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Iso3166Country {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let canonical = match self.format {
+            CountryFormat::Alpha2 => alpha2,
+            CountryFormat::Alpha3 => alpha3,
+        };
+        check_code(value, self.case, "validator.country", canonical)
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let (name, pattern) = match self.format {
+            CountryFormat::Alpha2 => ("iso3166_alpha2", "^[A-Z]{2}$"),
+            CountryFormat::Alpha3 => ("iso3166_alpha3", "^[A-Z]{3}$"),
+        };
+        let descriptor = RuleDescriptor::new(name, &["validator.country", "validator.code.case"])
+            .param("case", self.case.as_str());
+        Some(match self.case {
+            CodeCase::Canonical => descriptor.param("pattern", pattern),
+            CodeCase::Insensitive => descriptor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(format: CountryFormat, case: CodeCase) -> Iso3166Country {
+        Iso3166Country { format, case }
+    }
+
+    #[test]
+    fn validates_country_codes() {
+        let alpha2 = rule(CountryFormat::Alpha2, CodeCase::Canonical);
+        assert!(alpha2.validate(&"UA").is_ok());
+        assert!(alpha2.validate(&None::<String>).is_ok());
+        assert_eq!(
+            alpha2.validate(&"UKR").unwrap_err().key,
+            "validator.country"
+        );
+
+        let err = alpha2.validate(&"ua").unwrap_err();
+        assert_eq!(err.key, "validator.code.case");
+        assert_eq!(err.params["expected"], "UA");
+
+        let alpha3 = rule(CountryFormat::Alpha3, CodeCase::Insensitive);
+        assert!(alpha3.validate(&"ukr").is_ok());
+        assert!(alpha3.validate(&"UA").is_err());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::finance::currency::is_currency;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+pub(crate) struct Iso4217Currency {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Iso4217Currency {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(code) if !is_currency(code) => Err(ValidationError::new("validator.currency")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("iso4217_currency", &["validator.currency"])
+                .param("pattern", "^[A-Z]{3}$"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_currency_codes() {
+        assert!(Iso4217Currency {}.validate(&"UAH").is_ok());
+        assert!(Iso4217Currency {}.validate(&"usd").is_err());
+        assert!(Iso4217Currency {}.validate(&"XYZ").is_err());
+        assert!(Iso4217Currency {}.validate(&None::<&str>).is_ok());
+    }
+}
@@ -0,0 +1,30 @@
+use crate::core::codes::language::language;
+use crate::core::codes::{CodeCase, check_code};
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// ISO 639 language code, two or three letters (`uk`, `ukr`).
+pub(crate) struct Iso639Language {
+    pub case: CodeCase,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Iso639Language {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(value) => check_code(value, self.case, "validator.language", language),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "iso639_language",
+                &["validator.language", "validator.code.case"],
+            )
+            .param("case", self.case.as_str()),
+        )
+    }
+}
@@ -0,0 +1,21 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::isbn::is_issn;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// ISSN, `0317-8471` or `03178471`.
+pub(crate) struct Issn {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Issn {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_issn(s) => Err(ValidationError::new("validator.issn")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("issn", &["validator.issn"]))
+    }
+}
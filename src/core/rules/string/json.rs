@@ -1,19 +1,29 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
+use crate::core::formats::document_options::parse_error;
 use crate::core::rules::str_ref::StrAsRef;
-use serde_json;
+use crate::core::schema::descriptor::RuleDescriptor;
+use serde_json::Value;
 
 pub(crate) struct Json {}
 
+/// The parsed document, or `validator.invalid_json` with the position of the syntax error.
+pub(crate) fn parse_json(source: &str) -> Result<Value, ValidationError> {
+    serde_json::from_str(source)
+        .map_err(|e| parse_error("validator.invalid_json", e.line(), e.column()))
+}
+
 impl<T: StrAsRef> IValidatorRule<T> for Json {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(s) = value.as_str_ref() {
-            if serde_json::from_str::<serde_json::Value>(s).is_err() {
-                return Err(ValidationError::new("validator.invalid_json"));
-            }
+            parse_json(s)?;
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("json", &["validator.invalid_json"]))
+    }
 }
 
 #[cfg(test)]
@@ -54,6 +64,8 @@ mod tests {
 
         let err = result.unwrap_err();
         assert_eq!(err.key, "validator.invalid_json");
+        assert_eq!(err.params["line"], "1");
+        assert_eq!(err.params["column"], "21");
     }
 
     #[test]
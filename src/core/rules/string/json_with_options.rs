@@ -0,0 +1,88 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::document_options::{DocumentOptions, DocumentType, json_depth};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::string::json::parse_json;
+use crate::core::schema::descriptor::RuleDescriptor;
+use serde_json::Value;
+
+pub(crate) struct JsonWithOptions {
+    pub options: DocumentOptions,
+}
+
+impl JsonWithOptions {
+    fn check(&self, source: &str) -> Result<(), ValidationError> {
+        self.options.check_size(source)?;
+        let document = parse_json(source)?;
+        let top_level = match document {
+            Value::Object(_) => Some(DocumentType::Object),
+            Value::Array(_) => Some(DocumentType::Array),
+            _ => None,
+        };
+        self.options.check_shape(json_depth(&document), top_level)
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for JsonWithOptions {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(source) => self.check(source),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.options.describe(RuleDescriptor::new(
+            "json",
+            &[
+                "validator.invalid_json",
+                "validator.document.too_large",
+                "validator.document.too_deep",
+                "validator.document.top_level",
+            ],
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(options: DocumentOptions, source: &str) -> Option<String> {
+        JsonWithOptions { options }
+            .validate(&source)
+            .err()
+            .map(|err| err.key)
+    }
+
+    #[test]
+    fn limits_size_and_depth() {
+        let options = || DocumentOptions::new().max_size(16).max_depth(2);
+        assert_eq!(key(options(), r#"{"a": [1, 2]}"#), None);
+        assert_eq!(
+            key(options(), r#"{"a": [1, 2, 3, 4, 5]}"#).as_deref(),
+            Some("validator.document.too_large")
+        );
+        assert_eq!(
+            key(options(), r#"[[[1]]]"#).as_deref(),
+            Some("validator.document.too_deep")
+        );
+        assert_eq!(
+            key(options(), r#"{"a": "#).as_deref(),
+            Some("validator.invalid_json")
+        );
+    }
+
+    #[test]
+    fn requires_top_level_type() {
+        let object = || DocumentOptions::new().top_level(DocumentType::Object);
+        assert_eq!(key(object(), r#"{"a": 1}"#), None);
+
+        let err = JsonWithOptions { options: object() }
+            .validate(&"[1]")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.document.top_level");
+        assert_eq!(err.params["expected"], "object");
+        assert!(key(object(), "42").is_some());
+    }
+}
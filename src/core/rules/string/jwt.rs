@@ -0,0 +1,141 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// JWS compact serialization: `header.claims.signature`, the first two Base64url-encoded JSON
+/// objects. The signature is only checked for encoding, never verified. With non-empty
+/// `algorithms`, the `alg` of the header must be one of them.
+pub(crate) struct Jwt {
+    pub algorithms: Vec<String>,
+}
+
+fn segment(encoded: &str) -> Result<Option<Map<String, Value>>, ValidationError> {
+    let bytes = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| ValidationError::new("validator.jwt.format"))?;
+    match serde_json::from_slice(&bytes) {
+        Ok(Value::Object(map)) => Ok(Some(map)),
+        _ => Ok(None),
+    }
+}
+
+impl Jwt {
+    fn check(&self, token: &str) -> Result<(), ValidationError> {
+        let segments: Vec<&str> = token.split('.').collect();
+        let [header, claims, signature] = segments[..] else {
+            return Err(ValidationError::new("validator.jwt.format"));
+        };
+        if URL_SAFE_NO_PAD.decode(signature).is_err() {
+            return Err(ValidationError::new("validator.jwt.format"));
+        }
+
+        let header =
+            segment(header)?.ok_or_else(|| ValidationError::new("validator.jwt.header"))?;
+        let Some(Value::String(alg)) = header.get("alg") else {
+            return Err(ValidationError::new("validator.jwt.header"));
+        };
+        if segment(claims)?.is_none() {
+            return Err(ValidationError::new("validator.jwt.claims"));
+        }
+
+        if self.algorithms.is_empty() || self.algorithms.contains(alg) {
+            return Ok(());
+        }
+        Err(ValidationError::new_with_params(
+            "validator.jwt.alg",
+            HashMap::from([
+                ("alg".into(), alg.clone()),
+                ("allowed".into(), self.algorithms.join(", ")),
+            ]),
+        ))
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Jwt {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(token) => self.check(token),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let mut descriptor = RuleDescriptor::new(
+            "jwt",
+            &[
+                "validator.jwt.format",
+                "validator.jwt.header",
+                "validator.jwt.claims",
+                "validator.jwt.alg",
+            ],
+        );
+        if !self.algorithms.is_empty() {
+            descriptor = descriptor.param("algorithms", self.algorithms.clone());
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(header: &str, claims: &str) -> String {
+        format!(
+            "{}.{}.c2lnbmF0dXJl",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(claims)
+        )
+    }
+
+    fn key(algorithms: &[&str], token: &str) -> Option<String> {
+        let rule = Jwt {
+            algorithms: algorithms.iter().map(|a| a.to_string()).collect(),
+        };
+        rule.validate(&token).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn checks_structure() {
+        let valid = token(r#"{"alg":"HS256","typ":"JWT"}"#, r#"{"sub":"42"}"#);
+        assert_eq!(key(&[], &valid), None);
+        assert_eq!(key(&[], "eyJhbGciOiJIUzI1NiJ9.e30.c2ln").as_deref(), None);
+
+        assert_eq!(key(&[], "a.b").as_deref(), Some("validator.jwt.format"));
+        assert_eq!(key(&[], "a.b.c.d").as_deref(), Some("validator.jwt.format"));
+        assert_eq!(
+            key(&[], "e30=.e30.").as_deref(),
+            Some("validator.jwt.format")
+        );
+        assert_eq!(
+            key(&[], &token(r#"{"typ":"JWT"}"#, "{}")).as_deref(),
+            Some("validator.jwt.header")
+        );
+        assert_eq!(
+            key(&[], &token(r#"{"alg":"none"}"#, "[1]")).as_deref(),
+            Some("validator.jwt.claims")
+        );
+    }
+
+    #[test]
+    fn restricts_algorithms() {
+        let unsigned = token(r#"{"alg":"none"}"#, "{}");
+        assert_eq!(
+            key(&["RS256", "ES256"], &token(r#"{"alg":"ES256"}"#, "{}")),
+            None
+        );
+
+        let rule = Jwt {
+            algorithms: vec!["RS256".into(), "ES256".into()],
+        };
+        let err = rule.validate(&unsigned.as_str()).unwrap_err();
+        assert_eq!(err.key, "validator.jwt.alg");
+        assert_eq!(err.params["alg"], "none");
+        assert_eq!(err.params["allowed"], "RS256, ES256");
+    }
+}
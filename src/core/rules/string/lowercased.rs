@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 
 pub(crate) struct Lowercased {}
 
@@ -13,6 +14,13 @@ impl<T: StrAsRef> IValidatorRule<T> for Lowercased {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "lowercased",
+            &["validator.is_lowercase"],
+        ))
+    }
 }
 
 #[cfg(test)]
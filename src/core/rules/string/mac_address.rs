@@ -1,15 +1,17 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use regex::Regex;
 
+const MAC_PATTERN: &str = r"(?i)^([0-9A-F]{2}[:-]){5}([0-9A-F]{2})$|^[0-9A-F]{12}$";
+
 pub(crate) struct MacAddress {}
 
 impl<T: StrAsRef> IValidatorRule<T> for MacAddress {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(s) = value.as_str_ref() {
-            let pattern = r"(?i)^([0-9A-F]{2}[:-]){5}([0-9A-F]{2})$|^[0-9A-F]{12}$";
-            let regex = Regex::new(pattern).unwrap();
+            let regex = Regex::new(MAC_PATTERN).unwrap();
 
             if !regex.is_match(s) {
                 return Err(ValidationError::new("validator.invalid_mac"));
@@ -17,6 +19,13 @@ impl<T: StrAsRef> IValidatorRule<T> for MacAddress {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("mac_address", &["validator.invalid_mac"])
+                .param("pattern", MAC_PATTERN),
+        )
+    }
 }
 
 #[cfg(test)]
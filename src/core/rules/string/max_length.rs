@@ -1,25 +1,49 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::length::LengthUnit;
 use std::collections::HashMap;
 
 pub(crate) struct MaxLength {
     pub max: usize,
+    pub unit: LengthUnit,
+}
+
+impl MaxLength {
+    /// Same split as `MinLength::keys`.
+    fn keys(&self) -> &'static [&'static str] {
+        match self.unit {
+            LengthUnit::Bytes => &["validator.max_bytes"],
+            _ => &["validator.max_length"],
+        }
+    }
 }
 
 impl<T: StrAsRef> IValidatorRule<T> for MaxLength {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(s) = value.as_str_ref() {
-            let actual = s.chars().count();
+            let actual = self.unit.count(s);
             if actual > self.max {
                 return Err(ValidationError::new_with_params(
-                    "validator.max_length",
-                    HashMap::from([("max".to_string(), self.max.to_string())]),
+                    self.keys()[0],
+                    HashMap::from([
+                        ("max".to_string(), self.max.to_string()),
+                        ("unit".to_string(), self.unit.as_str().to_string()),
+                    ]),
                 ));
             }
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let descriptor = RuleDescriptor::new("max_length", self.keys()).param("max", self.max);
+        match self.unit {
+            LengthUnit::Chars => Some(descriptor),
+            unit => Some(descriptor.param("unit", unit.as_str())),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -28,7 +52,10 @@ mod tests {
     use crate::core::contract::IValidatorRule;
 
     fn validator(max: usize) -> MaxLength {
-        MaxLength { max }
+        MaxLength {
+            max,
+            unit: LengthUnit::Chars,
+        }
     }
 
     #[test]
@@ -99,4 +126,18 @@ mod tests {
         let result = validator(7).validate(&value);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn counts_in_the_configured_unit() {
+        let value = "👩\u{200D}💻"; // 1 grapheme, 3 chars, 5 UTF-16 units, 11 bytes
+        let rule = |max, unit| MaxLength { max, unit };
+        assert!(rule(1, LengthUnit::Graphemes).validate(&value).is_ok());
+        assert!(rule(3, LengthUnit::Chars).validate(&value).is_ok());
+        assert!(rule(4, LengthUnit::Utf16).validate(&value).is_err());
+
+        let err = rule(10, LengthUnit::Bytes).validate(&value).unwrap_err();
+        assert_eq!(err.key, "validator.max_bytes");
+        assert_eq!(err.params["max"], "10");
+        assert_eq!(err.params["unit"], "bytes");
+    }
 }
@@ -1,25 +1,49 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::length::LengthUnit;
 use std::collections::HashMap;
 
 pub(crate) struct MinLength {
     pub min: usize,
+    pub unit: LengthUnit,
+}
+
+impl MinLength {
+    /// Byte limits get their own key: the messages of `validator.min_length` say "characters".
+    fn keys(&self) -> &'static [&'static str] {
+        match self.unit {
+            LengthUnit::Bytes => &["validator.min_bytes"],
+            _ => &["validator.min_length"],
+        }
+    }
 }
 
 impl<T: StrAsRef> IValidatorRule<T> for MinLength {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(s) = value.as_str_ref() {
-            let actual = s.chars().count();
+            let actual = self.unit.count(s);
             if actual < self.min {
                 return Err(ValidationError::new_with_params(
-                    "validator.min_length",
-                    HashMap::from([("min".to_string(), self.min.to_string())]),
+                    self.keys()[0],
+                    HashMap::from([
+                        ("min".to_string(), self.min.to_string()),
+                        ("unit".to_string(), self.unit.as_str().to_string()),
+                    ]),
                 ));
             }
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let descriptor = RuleDescriptor::new("min_length", self.keys()).param("min", self.min);
+        match self.unit {
+            LengthUnit::Chars => Some(descriptor),
+            unit => Some(descriptor.param("unit", unit.as_str())),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -28,7 +52,10 @@ mod tests {
     use crate::core::contract::IValidatorRule;
 
     fn validator(min: usize) -> MinLength {
-        MinLength { min }
+        MinLength {
+            min,
+            unit: LengthUnit::Chars,
+        }
     }
 
     #[test]
@@ -99,4 +126,20 @@ mod tests {
         let result = validator(5).validate(&value);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn counts_in_the_configured_unit() {
+        let value = "e\u{301}"; // é as e + combining acute accent
+        let rule = |min, unit| MinLength { min, unit };
+        assert!(rule(2, LengthUnit::Chars).validate(&value).is_ok());
+        assert!(rule(3, LengthUnit::Bytes).validate(&value).is_ok());
+
+        let err = rule(2, LengthUnit::Graphemes).validate(&value).unwrap_err();
+        assert_eq!(err.key, "validator.min_length");
+        assert_eq!(err.params["unit"], "graphemes");
+        assert_eq!(
+            rule(4, LengthUnit::Bytes).validate(&value).unwrap_err().key,
+            "validator.min_bytes"
+        );
+    }
 }
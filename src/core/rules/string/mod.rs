@@ -1,20 +1,56 @@
+pub(crate) mod base64;
+pub(crate) mod bcp47_locale;
+pub(crate) mod bic;
 pub(crate) mod charset;
 pub(crate) mod cidr;
+pub(crate) mod credit_card;
+pub(crate) mod css_color;
+pub(crate) mod decimal_precision;
+pub(crate) mod ean;
 pub(crate) mod email;
+pub(crate) mod email_with_options;
 pub(crate) mod encoding_charset;
+pub(crate) mod hex;
+pub(crate) mod hex_color;
 pub(crate) mod hostname;
+pub(crate) mod iana_timezone;
+pub(crate) mod iban;
+pub(crate) mod isbn;
+pub(crate) mod iso3166_country;
+pub(crate) mod iso4217_currency;
+pub(crate) mod iso639_language;
+pub(crate) mod issn;
 pub(crate) mod json;
+pub(crate) mod json_with_options;
+pub(crate) mod jwt;
 pub(crate) mod lowercased;
 pub(crate) mod mac_address;
 pub(crate) mod max_length;
 pub(crate) mod min_length;
+pub(crate) mod national_id;
+pub(crate) mod no_bidi_controls;
 pub(crate) mod no_suspicious_characters;
+pub(crate) mod no_zero_width;
+pub(crate) mod not_confusable;
 pub(crate) mod one_of;
+pub(crate) mod password_policy;
 pub(crate) mod password_strength;
+#[cfg(feature = "phone")]
+pub(crate) mod phone;
 pub(crate) mod regex_match;
+pub(crate) mod semver;
+pub(crate) mod single_script;
+pub(crate) mod slug;
 pub(crate) mod trimmed;
+pub(crate) mod ulid;
+pub(crate) mod unicode_normalized;
+pub(crate) mod upc;
 pub(crate) mod uppercased;
 pub(crate) mod url;
+pub(crate) mod url_with_options;
 pub(crate) mod uuid_valid;
 pub(crate) mod uuid_version;
+pub(crate) mod vat;
 pub(crate) mod word_count;
+pub(crate) mod xml;
+pub(crate) mod yaml;
@@ -0,0 +1,77 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::national::{IdRule, NationalIdRegistry};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// Validates with the rule a `NationalIdRegistry` holds for `country`, looked up once when the
+/// field is declared so that unknown countries fail there rather than on every value.
+pub(crate) struct NationalId {
+    pub country: String,
+    rule: IdRule,
+}
+
+impl NationalId {
+    /// `None` when `registry` has no validator for `country`.
+    pub(crate) fn resolve(registry: &NationalIdRegistry, country: &str) -> Option<Self> {
+        Some(Self {
+            country: country.to_ascii_uppercase(),
+            rule: registry.get(country)?,
+        })
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for NationalId {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(value) => self.rule.validate(&value),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("national_id", &["validator.national_id"])
+                .param("country", self.country.as_str()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::identifiers::national::BUILT_IN;
+
+    struct Oib;
+
+    impl<T: StrAsRef> IValidatorRule<T> for Oib {
+        fn validate(&self, value: &T) -> Result<(), ValidationError> {
+            match value.as_str_ref().is_some_and(|v| v.len() == 11) {
+                true => Ok(()),
+                false => Err(ValidationError::new("validator.national_id")),
+            }
+        }
+    }
+
+    #[test]
+    fn resolves_registered_countries() {
+        assert!(NationalId::resolve(&BUILT_IN, "HR").is_none());
+        assert!(NationalId::resolve(&BUILT_IN, "UK").is_none());
+
+        let registry = NationalIdRegistry::new().register("HR", Oib);
+        let rule = NationalId::resolve(&registry, "hr").unwrap();
+        assert_eq!(rule.country, "HR");
+        assert!(rule.validate(&"69435151530").is_ok());
+        assert!(rule.validate(&"694").is_err());
+        assert!(rule.validate(&None::<String>).is_ok());
+    }
+
+    #[test]
+    fn reports_the_country() {
+        let rule = NationalId::resolve(&BUILT_IN, "PL").unwrap();
+        assert!(rule.validate(&"44051401359").is_ok());
+        let err = rule.validate(&"44051401358").unwrap_err();
+        assert_eq!(err.key, "validator.national_id");
+        assert_eq!(err.params["country"], "PL");
+    }
+}
@@ -0,0 +1,50 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::unicode::{code_point, is_bidi_control};
+use std::collections::HashMap;
+
+/// Rejects directional formatting characters (U+202E RIGHT-TO-LEFT OVERRIDE, ...) that make
+/// text display differently from its logical order.
+pub(crate) struct NoBidiControls {}
+
+impl<T: StrAsRef> IValidatorRule<T> for NoBidiControls {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value
+            .as_str_ref()
+            .and_then(|s| s.chars().find(|&c| is_bidi_control(c)))
+        {
+            Some(c) => Err(ValidationError::new_with_params(
+                "validator.unicode.bidi_control",
+                HashMap::from([("char".into(), code_point(c))]),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "no_bidi_controls",
+            &["validator.unicode.bidi_control"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_directional_overrides() {
+        assert!(NoBidiControls {}.validate(&"invoice.pdf").is_ok());
+        assert!(NoBidiControls {}.validate(&"שלום").is_ok());
+
+        let err = NoBidiControls {}
+            .validate(&"invoice\u{202E}fdp.exe")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.unicode.bidi_control");
+        assert_eq!(err.params["char"], "U+202E");
+        assert!(NoBidiControls {}.validate(&"a\u{2066}b").is_err());
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
 pub struct NoSuspiciousCharacters {
@@ -19,6 +20,13 @@ impl<T: StrAsRef> IValidatorRule<T> for NoSuspiciousCharacters {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("no_suspicious_characters", &["validator.no_suspicious"])
+                .param("blacklist", self.blacklist.iter().collect::<String>()),
+        )
+    }
 }
 
 #[cfg(test)]
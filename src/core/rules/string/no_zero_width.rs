@@ -0,0 +1,47 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::unicode::{code_point, is_zero_width};
+use std::collections::HashMap;
+
+/// Rejects invisible zero-width characters. Note that ZWJ also joins emoji sequences and ZWNJ
+/// is part of Persian spelling.
+pub(crate) struct NoZeroWidth {}
+
+impl<T: StrAsRef> IValidatorRule<T> for NoZeroWidth {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value
+            .as_str_ref()
+            .and_then(|s| s.chars().find(|&c| is_zero_width(c)))
+        {
+            Some(c) => Err(ValidationError::new_with_params(
+                "validator.unicode.zero_width",
+                HashMap::from([("char".into(), code_point(c))]),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "no_zero_width_chars",
+            &["validator.unicode.zero_width"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_invisible_characters() {
+        assert!(NoZeroWidth {}.validate(&"admin").is_ok());
+
+        let err = NoZeroWidth {}.validate(&"ad\u{200B}min").unwrap_err();
+        assert_eq!(err.key, "validator.unicode.zero_width");
+        assert_eq!(err.params["char"], "U+200B");
+        assert!(NoZeroWidth {}.validate(&"\u{FEFF}admin").is_err());
+    }
+}
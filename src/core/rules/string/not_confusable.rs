@@ -0,0 +1,73 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::unicode::skeleton;
+use std::collections::HashMap;
+
+/// Rejects values that look like one of the reserved names (UTS #39 skeletons, ignoring case),
+/// including the names themselves.
+pub(crate) struct NotConfusable {
+    /// Reserved names with their skeletons.
+    reserved: Vec<(String, String)>,
+}
+
+impl NotConfusable {
+    pub fn new(reserved: &[&str]) -> Self {
+        Self {
+            reserved: reserved
+                .iter()
+                .map(|name| (name.to_string(), skeleton(name)))
+                .collect(),
+        }
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for NotConfusable {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(s) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let value_skeleton = skeleton(s);
+        match self.reserved.iter().find(|(_, sk)| *sk == value_skeleton) {
+            Some((name, _)) => Err(ValidationError::new_with_params(
+                "validator.unicode.confusable",
+                HashMap::from([("reserved".into(), name.clone())]),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let names: Vec<_> = self
+            .reserved
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        Some(
+            RuleDescriptor::new("not_confusable_with", &["validator.unicode.confusable"])
+                .param("reserved", names),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_lookalikes_of_reserved_names() {
+        let rule = NotConfusable::new(&["admin", "paypal"]);
+        assert!(rule.validate(&"ann").is_ok());
+        assert!(rule.validate(&"paypal2").is_ok());
+
+        for spoof in ["admin", "Admin", "аdmin", "adrnin", "раураl"] {
+            let err = rule.validate(&spoof).unwrap_err();
+            assert_eq!(err.key, "validator.unicode.confusable", "{spoof}");
+        }
+        assert_eq!(
+            rule.validate(&"раураl").unwrap_err().params["reserved"],
+            "paypal"
+        );
+    }
+}
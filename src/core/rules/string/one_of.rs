@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::{HashMap, HashSet};
 
 pub(crate) struct OneOf {
@@ -22,6 +23,12 @@ impl<T: StrAsRef> IValidatorRule<T> for OneOf {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let mut allowed: Vec<_> = self.allowed.iter().cloned().collect();
+        allowed.sort();
+        Some(RuleDescriptor::new("one_of", &["validator.one_of"]).param("allowed", allowed))
+    }
 }
 
 #[cfg(test)]
@@ -93,4 +100,14 @@ mod tests {
         let result = validator().validate(&value);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn describes_sorted_values() {
+        let descriptor = IValidatorRule::<&str>::describe(&validator()).unwrap();
+        assert_eq!(descriptor.keys, ["validator.one_of"]);
+        assert_eq!(
+            descriptor.params["allowed"],
+            serde_json::json!(["DE", "PL", "UA"])
+        );
+    }
 }
@@ -0,0 +1,225 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::{IValidatorRule, IValidatorRuleDto};
+use crate::core::errors::{RuleFailure, ValidationError};
+use crate::core::password::policy::PasswordPolicy;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::string::password_strength::UserInputs;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+const KEY_PREFIX: &str = "validator.password.";
+
+pub(crate) struct PasswordPolicyRule {
+    pub policy: PasswordPolicy,
+}
+
+impl PasswordPolicyRule {
+    /// Every unmet requirement under its code (`too_short`, `missing_digit`), with its params.
+    fn check(&self, password: &str, user_inputs: &[&str]) -> Result<(), RuleFailure> {
+        let errors = self.policy.check(password, user_inputs);
+        if errors.is_empty() {
+            return Ok(());
+        }
+        let children = errors
+            .into_iter()
+            .map(|err| (err.key.trim_start_matches(KEY_PREFIX).to_string(), err))
+            .collect();
+        Err(RuleFailure::Children(children))
+    }
+
+    fn descriptor(&self) -> RuleDescriptor {
+        let policy = &self.policy;
+        let mut descriptor = RuleDescriptor::new(
+            "password_policy",
+            &[
+                "validator.password.too_short",
+                "validator.password.too_long",
+                "validator.password.missing_uppercase",
+                "validator.password.missing_lowercase",
+                "validator.password.missing_digit",
+                "validator.password.missing_symbol",
+                "validator.password.repeated_chars",
+                "validator.password.unique_chars",
+                "validator.password.common",
+                "validator.password.contains_user_input",
+            ],
+        );
+
+        if let Some(min) = policy.min_length {
+            descriptor = descriptor.param("min_length", min);
+        }
+        if let Some(max) = policy.max_length {
+            descriptor = descriptor.param("max_length", max);
+        }
+        let counts = [
+            ("min_uppercase", policy.min_uppercase),
+            ("min_lowercase", policy.min_lowercase),
+            ("min_digits", policy.min_digits),
+            ("min_symbols", policy.min_symbols),
+            ("min_unique", policy.min_unique),
+        ];
+        for (name, count) in counts.into_iter().filter(|(_, count)| *count > 0) {
+            descriptor = descriptor.param(name, count);
+        }
+        if let Some(max) = policy.max_repeated {
+            descriptor = descriptor.param("max_repeated", max);
+        }
+        if policy.forbid_common {
+            descriptor = descriptor.param("forbid_common", true);
+        }
+        descriptor
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for PasswordPolicyRule {
+    /// The first unmet requirement, where a single error is expected (rule specs, `each`).
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_all(value, &ValidationContext::default())
+            .map_err(RuleFailure::into_first)
+    }
+
+    fn validate_all(&self, value: &T, _ctx: &ValidationContext) -> Result<(), RuleFailure> {
+        match value.as_str_ref() {
+            Some(password) => self.check(password, &[]),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.descriptor())
+    }
+}
+
+/// `PasswordPolicyRule` that also rejects passwords containing personal data of the DTO.
+pub(crate) struct PasswordPolicyWithInputs<T> {
+    pub rule: PasswordPolicyRule,
+    pub user_inputs: UserInputs<T>,
+}
+
+impl<T, V: StrAsRef> IValidatorRuleDto<T, V> for PasswordPolicyWithInputs<T> {
+    fn validate(&self, dto: &T, value: &V, ctx: &ValidationContext) -> Result<(), ValidationError> {
+        self.validate_all(dto, value, ctx)
+            .map_err(RuleFailure::into_first)
+    }
+
+    fn validate_all(
+        &self,
+        dto: &T,
+        value: &V,
+        _ctx: &ValidationContext,
+    ) -> Result<(), RuleFailure> {
+        let Some(password) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let inputs = (self.user_inputs)(dto);
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        self.rule.check(password, &inputs)
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.rule.descriptor())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use valida::prelude::*;
+
+    fn rule() -> PasswordPolicyRule {
+        PasswordPolicyRule {
+            policy: PasswordPolicy::new()
+                .min_length(12)
+                .require_uppercase(1)
+                .require_digit(1)
+                .require_symbol(1),
+        }
+    }
+
+    #[test]
+    fn reports_every_unmet_requirement() {
+        let ctx = ValidationContext::default();
+        let Err(RuleFailure::Children(children)) = rule().validate_all(&"short", &ctx) else {
+            panic!("expected an error per requirement");
+        };
+        let codes: Vec<&str> = children.iter().map(|(code, _)| code.as_str()).collect();
+        assert_eq!(
+            codes,
+            [
+                "too_short",
+                "missing_uppercase",
+                "missing_digit",
+                "missing_symbol"
+            ]
+        );
+        assert_eq!(children[0].1.key, "validator.password.too_short");
+        assert_eq!(children[0].1.params["min"], "12");
+        assert_eq!(children[2].1.key, "validator.password.missing_digit");
+        assert_eq!(children[2].1.params["min"], "1");
+
+        let err = rule().validate(&"longenoughPassword!").unwrap_err();
+        assert_eq!(err.key, "validator.password.missing_digit");
+
+        assert!(rule().validate_all(&"Long-enough-Passw0rd", &ctx).is_ok());
+        assert!(rule().validate(&None::<String>).is_ok());
+    }
+
+    struct Account {
+        username: String,
+        password: String,
+    }
+
+    struct AccountValidator;
+
+    impl IValidate<Account, std::io::Error> for AccountValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Account, std::io::Error>,
+        ) -> RulesBuilder<Account, std::io::Error> {
+            builder
+                .field("password", |a| &a.password)
+                .password_policy_with_inputs(
+                    PasswordPolicy::new().min_length(8).require_digit(1),
+                    |a| vec![a.username.clone()],
+                )
+                .build();
+            builder
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_passwords_containing_the_username() {
+        let account = |password: &str| Account {
+            username: "jdoe".into(),
+            password: password.into(),
+        };
+        assert!(
+            AccountValidator
+                .validate(&account("river-stone-42"))
+                .await
+                .is_ok()
+        );
+
+        match AccountValidator.validate(&account("jdoe-2024")).await {
+            Err(ValidatorFailure::Invalid(errors)) => {
+                let json = errors.to_json_raw().to_string();
+                assert!(json.contains("validator.password.contains_user_input"));
+                assert!(json.contains("jdoe"));
+            }
+            _ => panic!("Should be invalid"),
+        }
+
+        match AccountValidator.validate(&account("jdoe")).await {
+            Err(ValidatorFailure::Invalid(errors)) => {
+                let errors = errors.to_json_dot_raw();
+                assert_eq!(
+                    errors["password.too_short"]["key"],
+                    "validator.password.too_short"
+                );
+                assert_eq!(errors["password.too_short"]["params"]["min"], "8");
+                assert_eq!(errors["password.missing_digit"]["params"]["min"], "1");
+                assert!(errors.get("password.contains_user_input").is_some());
+            }
+            _ => panic!("Should be invalid"),
+        }
+    }
+}
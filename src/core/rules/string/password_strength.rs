@@ -1,74 +1,91 @@
-use crate::core::contract::IValidatorRule;
+use crate::core::context::ValidationContext;
+use crate::core::contract::{IValidatorRule, IValidatorRuleDto};
 use crate::core::errors::ValidationError;
+use crate::core::password::estimate::estimate_at;
+use crate::core::password::feedback::KEYS;
+use crate::core::password::strength::StrengthLevel;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 
-#[derive(Debug, PartialEq, PartialOrd, Eq, Clone, Copy)]
-pub enum StrengthLevel {
-    VeryWeak = 0,
-    Weak = 1,
-    Medium = 2,
-    Strong = 3,
-    VeryStrong = 4,
+pub struct PasswordStrength {
+    pub level: StrengthLevel,
 }
 
-pub fn estimate_password_strength(password: &str) -> StrengthLevel {
-    let length = password.len();
-    if length == 0 {
-        return StrengthLevel::VeryWeak;
-    }
-
-    let mut control = 0;
-    let mut digit = 0;
-    let mut upper = 0;
-    let mut lower = 0;
-    let mut symbol = 0;
-    let mut other = 0;
-
-    let mut unique = std::collections::HashSet::new();
-
-    for chr in password.chars() {
-        unique.insert(chr);
-        let code = chr as u32;
-        match code {
-            0..=31 | 127 => control = 33,
-            48..=57 => digit = 10,
-            65..=90 => upper = 26,
-            97..=122 => lower = 26,
-            128.. => other = 128,
-            _ => symbol = 33,
+impl PasswordStrength {
+    fn check(
+        &self,
+        password: &str,
+        user_inputs: &[&str],
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let estimate = estimate_at(password, user_inputs, ctx.now().date().year);
+        if estimate.strength >= self.level {
+            return Ok(());
         }
-    }
 
-    let pool: f64 = (lower + upper + digit + symbol + control + other) as f64;
-    let chars = unique.len();
-    let entropy = (chars as f64) * pool.log2() + ((length - chars) as f64) * (chars as f64).log2();
-
-    match entropy {
-        e if e >= 120.0 => StrengthLevel::VeryStrong,
-        e if e >= 100.0 => StrengthLevel::Strong,
-        e if e >= 80.0 => StrengthLevel::Medium,
-        e if e >= 60.0 => StrengthLevel::Weak,
-        _ => StrengthLevel::VeryWeak,
+        let mut params = HashMap::from([
+            ("strength".into(), format!("{:?}", estimate.strength)),
+            (
+                "suggestions".into(),
+                estimate.feedback.suggestions.join(","),
+            ),
+        ]);
+        if let Some(warning) = estimate.feedback.warning {
+            params.insert("warning".into(), warning.into());
+        }
+        Err(ValidationError::new_with_params(
+            "validator.password_strength",
+            params,
+        ))
     }
-}
 
-pub struct PasswordStrength {
-    pub level: StrengthLevel,
+    fn descriptor(&self) -> RuleDescriptor {
+        RuleDescriptor::new("password_strength", KEYS).param("level", self.level.as_str())
+    }
 }
 
 impl<T: StrAsRef> IValidatorRule<T> for PasswordStrength {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
-        if let Some(password) = value.as_str_ref() {
-            let strength = estimate_password_strength(password);
-            if strength < self.level {
-                return Err(ValidationError::new_with_params(
-                    "validator.password_strength",
-                    HashMap::from([("strength".into(), format!("{strength:?}"))]),
-                ));
-            }
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(password) => self.check(password, &[], ctx),
+            None => Ok(()),
         }
-        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.descriptor())
+    }
+}
+
+pub(crate) type UserInputs<T> = Box<dyn Fn(&T) -> Vec<String> + Send + Sync>;
+
+/// `PasswordStrength` that penalizes personal data read from the DTO (username, email, ...).
+pub(crate) struct PasswordStrengthWithInputs<T> {
+    pub strength: PasswordStrength,
+    pub user_inputs: UserInputs<T>,
+}
+
+impl<T, V: StrAsRef> IValidatorRuleDto<T, V> for PasswordStrengthWithInputs<T> {
+    fn validate(&self, dto: &T, value: &V, ctx: &ValidationContext) -> Result<(), ValidationError> {
+        let Some(password) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let inputs = (self.user_inputs)(dto);
+        let inputs: Vec<&str> = inputs.iter().map(String::as_str).collect();
+        self.strength.check(password, &inputs, ctx)
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.strength.descriptor())
     }
 }
 
@@ -76,6 +93,7 @@ impl<T: StrAsRef> IValidatorRule<T> for PasswordStrength {
 mod tests {
     use super::*;
     use crate::core::contract::IValidatorRule;
+    use valida::prelude::*;
 
     fn validator(level: StrengthLevel) -> PasswordStrength {
         PasswordStrength { level }
@@ -99,9 +117,57 @@ mod tests {
         assert!(validator(StrengthLevel::VeryWeak).validate(&value).is_ok());
     }
 
+    #[test]
+    fn reports_feedback_params() {
+        let err = validator(StrengthLevel::Medium)
+            .validate(&"Password1!Password1!")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.password_strength");
+        assert_eq!(err.params["warning"], "repeat_group");
+        assert_eq!(err.params["suggestions"], "add_word,avoid_repeats");
+    }
+
     #[test]
     fn validates_option_none() {
         let value: Option<String> = None;
         assert!(validator(StrengthLevel::Medium).validate(&value).is_ok());
     }
+
+    struct Account {
+        username: String,
+        password: String,
+    }
+
+    struct AccountValidator;
+
+    impl IValidate<Account, std::io::Error> for AccountValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Account, std::io::Error>,
+        ) -> RulesBuilder<Account, std::io::Error> {
+            builder
+                .field("password", |a| &a.password)
+                .password_strength_with_inputs(StrengthLevel::Strong, |a| vec![a.username.clone()])
+                .build();
+            builder
+        }
+    }
+
+    #[tokio::test]
+    async fn penalizes_user_inputs_from_dto() {
+        let account = |username: &str| Account {
+            username: username.into(),
+            password: "bordunos_wx7k".into(),
+        };
+        assert!(AccountValidator.validate(&account("someone")).await.is_ok());
+
+        match AccountValidator.validate(&account("bordunos")).await {
+            Err(ValidatorFailure::Invalid(errors)) => {
+                let json = errors.to_json_raw().to_string();
+                assert!(json.contains("validator.password_strength"));
+                assert!(json.contains("\"warning\":\"user_input\""));
+            }
+            _ => panic!("Should be invalid"),
+        }
+    }
 }
@@ -0,0 +1,157 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::features::phone::PhoneOptions;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub(crate) struct Phone {
+    pub options: PhoneOptions,
+}
+
+impl Phone {
+    fn check(&self, value: &str) -> Result<(), ValidationError> {
+        let options = &self.options;
+        let number = match options.parse(value) {
+            Ok(number) => number,
+            Err(_) if options.region.is_none() && !value.trim_start().starts_with('+') => {
+                return Err(ValidationError::new("validator.phone.international"));
+            }
+            Err(_) => return Err(ValidationError::new("validator.phone.format")),
+        };
+        if !number.is_valid() {
+            return Err(ValidationError::new("validator.phone.invalid"));
+        }
+
+        if let Some(region) = options.region.filter(|_| options.region_only)
+            && number.country().id() != Some(region)
+        {
+            return Err(ValidationError::new_with_params(
+                "validator.phone.region",
+                HashMap::from([("region".into(), region.as_ref().to_string())]),
+            ));
+        }
+
+        options.type_allowed(&number).map_err(|actual| {
+            let allowed: Vec<_> = options.allowed_types.iter().map(|t| t.as_str()).collect();
+            ValidationError::new_with_params(
+                "validator.phone.type",
+                HashMap::from([
+                    ("type".into(), actual.to_string()),
+                    ("allowed".into(), allowed.join(", ")),
+                ]),
+            )
+        })
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Phone {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(value) => self.check(value),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let options = &self.options;
+        let mut descriptor = RuleDescriptor::new(
+            "phone",
+            &[
+                "validator.phone.international",
+                "validator.phone.format",
+                "validator.phone.invalid",
+                "validator.phone.region",
+                "validator.phone.type",
+            ],
+        );
+
+        if let Some(region) = options.region_code() {
+            descriptor = descriptor.param("region", region);
+        }
+        if options.region_only {
+            descriptor = descriptor.param("region_only", true);
+        }
+        if !options.allowed_types.is_empty() {
+            let types: Vec<_> = options.allowed_types.iter().map(|t| t.as_str()).collect();
+            descriptor = descriptor.param("types", types);
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::features::phone::PhoneType;
+
+    fn key(options: PhoneOptions, value: &str) -> Option<String> {
+        Phone { options }.validate(&value).err().map(|err| err.key)
+    }
+
+    #[test]
+    fn accepts_international_numbers() {
+        assert_eq!(key(PhoneOptions::new(), "+380 50 123 4567"), None);
+        assert_eq!(key(PhoneOptions::new(), "+49 151 23456789"), None);
+        assert_eq!(key(PhoneOptions::new(), "+44 20 7946 0958"), None);
+        assert!(
+            Phone {
+                options: PhoneOptions::new()
+            }
+            .validate(&None::<String>)
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn parses_national_formats_with_region() {
+        let ua = || PhoneOptions::new().region("UA");
+        assert_eq!(key(ua(), "050 123 4567"), None);
+        assert_eq!(key(ua(), "(044) 123-45-67"), None);
+        assert_eq!(
+            key(PhoneOptions::new(), "050 123 4567").as_deref(),
+            Some("validator.phone.international")
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_and_invalid_numbers() {
+        let ua = || PhoneOptions::new().region("UA");
+        assert_eq!(
+            key(ua(), "call me").as_deref(),
+            Some("validator.phone.format")
+        );
+        assert_eq!(
+            key(ua(), "+380 12 345").as_deref(),
+            Some("validator.phone.invalid")
+        );
+        assert_eq!(key(ua(), "").as_deref(), Some("validator.phone.format"));
+    }
+
+    #[test]
+    fn restricts_region_and_type() {
+        let err = Phone {
+            options: PhoneOptions::new().region("UA").region_only(true),
+        }
+        .validate(&"+49 151 23456789")
+        .unwrap_err();
+        assert_eq!(err.key, "validator.phone.region");
+        assert_eq!(err.params["region"], "UA");
+
+        let mobile = || {
+            PhoneOptions::new()
+                .region("UA")
+                .allow_types(&[PhoneType::Mobile])
+        };
+        assert_eq!(key(mobile(), "050 123 4567"), None);
+        let err = Phone { options: mobile() }
+            .validate(&"044 123 4567")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.phone.type");
+        assert_eq!(err.params["type"], "fixed_line");
+        assert_eq!(err.params["allowed"], "mobile");
+
+        // US numbers are fixed-line-or-mobile and satisfy either type.
+        assert_eq!(key(mobile(), "+1 650-253-0000"), None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -20,6 +21,13 @@ impl<T: StrAsRef> IValidatorRule<T> for RegexMatch {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("regex_match", &["validator.regex"])
+                .param("pattern", self.pattern.as_str()),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,47 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Semantic Versioning 2.0.0, the pattern published at semver.org (no `v` prefix).
+const SEMVER_PATTERN: &str = r"^(?:0|[1-9]\d*)\.(?:0|[1-9]\d*)\.(?:0|[1-9]\d*)(?:-(?:(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*)(?:\.(?:0|[1-9]\d*|\d*[a-zA-Z-][0-9a-zA-Z-]*))*))?(?:\+[0-9a-zA-Z-]+(?:\.[0-9a-zA-Z-]+)*)?$";
+
+static SEMVER: Lazy<Regex> = Lazy::new(|| Regex::new(SEMVER_PATTERN).unwrap());
+
+pub(crate) struct Semver {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Semver {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !SEMVER.is_match(s) => Err(ValidationError::new("validator.semver")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("semver", &["validator.semver"]).param("pattern", SEMVER_PATTERN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_versions() {
+        for version in [
+            "1.0.0",
+            "0.10.3",
+            "1.0.0-alpha.1",
+            "1.0.0-0.3.7",
+            "2.1.0+build.5",
+        ] {
+            assert!(Semver {}.validate(&version).is_ok(), "{version}");
+        }
+        for version in ["1.0", "v1.0.0", "01.0.0", "1.0.0-01", "1.0.0-", "1.0.0+"] {
+            assert!(Semver {}.validate(&version).is_err(), "{version}");
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::unicode::scripts;
+use std::collections::HashMap;
+use unicode_security::MixedScript;
+
+/// UTS #39 single-script string: digits, punctuation and combining marks go with any script,
+/// and Han may be mixed with Hiragana/Katakana (Japanese), Hangul (Korean) or Bopomofo.
+pub(crate) struct SingleScript {}
+
+impl<T: StrAsRef> IValidatorRule<T> for SingleScript {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !s.is_single_script() => Err(ValidationError::new_with_params(
+                "validator.unicode.mixed_script",
+                HashMap::from([("scripts".into(), scripts(s).join(", "))]),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "single_script",
+            &["validator.unicode.mixed_script"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_mixed_scripts() {
+        assert!(SingleScript {}.validate(&"paypal_42").is_ok());
+        assert!(SingleScript {}.validate(&"Київ-2024").is_ok());
+        assert!(SingleScript {}.validate(&"東京タワー").is_ok());
+
+        let err = SingleScript {}.validate(&"раураl").unwrap_err();
+        assert_eq!(err.key, "validator.unicode.mixed_script");
+        assert_eq!(err.params["scripts"], "Cyrillic, Latin");
+    }
+}
@@ -0,0 +1,41 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Lowercase ASCII letters and digits in groups joined by single hyphens: `hello-world-2`.
+const SLUG_PATTERN: &str = r"^[a-z0-9]+(?:-[a-z0-9]+)*$";
+
+static SLUG: Lazy<Regex> = Lazy::new(|| Regex::new(SLUG_PATTERN).unwrap());
+
+pub(crate) struct Slug {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Slug {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !SLUG.is_match(s) => Err(ValidationError::new("validator.slug")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("slug", &["validator.slug"]).param("pattern", SLUG_PATTERN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_slugs() {
+        assert!(Slug {}.validate(&"hello-world-2").is_ok());
+        assert!(Slug {}.validate(&"hello").is_ok());
+        assert!(Slug {}.validate(&"Hello-World").is_err());
+        assert!(Slug {}.validate(&"hello--world").is_err());
+        assert!(Slug {}.validate(&"-hello").is_err());
+        assert!(Slug {}.validate(&"hello_world").is_err());
+    }
+}
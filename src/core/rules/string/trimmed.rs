@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 
 pub(crate) struct Trimmed {}
 
@@ -13,6 +14,10 @@ impl<T: StrAsRef> IValidatorRule<T> for Trimmed {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("trimmed", &["validator.trimmed"]))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,40 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// 26 Crockford Base32 characters of either case; the first one is at most `7` (48-bit time).
+const ULID_PATTERN: &str = r"^[0-7][0-9A-HJKMNP-TV-Za-hjkmnp-tv-z]{25}$";
+
+static ULID: Lazy<Regex> = Lazy::new(|| Regex::new(ULID_PATTERN).unwrap());
+
+pub(crate) struct Ulid {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Ulid {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !ULID.is_match(s) => Err(ValidationError::new("validator.ulid")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("ulid", &["validator.ulid"]).param("pattern", ULID_PATTERN))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_ulids() {
+        assert!(Ulid {}.validate(&"01ARZ3NDEKTSV4RRFFQ69G5FAV").is_ok());
+        assert!(Ulid {}.validate(&"01arz3ndektsv4rrffq69g5fav").is_ok());
+        assert!(Ulid {}.validate(&"81ARZ3NDEKTSV4RRFFQ69G5FAV").is_err());
+        assert!(Ulid {}.validate(&"01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+        assert!(Ulid {}.validate(&"01ARZ3NDEKTSV4RRFFQ69G5FA").is_err());
+    }
+}
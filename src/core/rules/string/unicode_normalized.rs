@@ -0,0 +1,53 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::unicode::NormalizationForm;
+use std::collections::HashMap;
+
+pub(crate) struct UnicodeNormalized {
+    pub form: NormalizationForm,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for UnicodeNormalized {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !self.form.is_normalized(s) => Err(ValidationError::new_with_params(
+                "validator.unicode.not_normalized",
+                HashMap::from([("form".into(), self.form.as_str().to_string())]),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("unicode_normalized", &["validator.unicode.not_normalized"])
+                .param("form", self.form.as_str()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_denormalized_text() {
+        let nfc = UnicodeNormalized {
+            form: NormalizationForm::Nfc,
+        };
+        assert!(nfc.validate(&"Café").is_ok());
+        assert!(nfc.validate(&None::<String>).is_ok());
+
+        let err = nfc.validate(&"Cafe\u{301}").unwrap_err();
+        assert_eq!(err.key, "validator.unicode.not_normalized");
+        assert_eq!(err.params["form"], "NFC");
+
+        let nfkc = UnicodeNormalized {
+            form: NormalizationForm::Nfkc,
+        };
+        assert!(nfkc.validate(&"Ａdmin").is_err());
+        assert!(nfkc.validate(&"Admin").is_ok());
+    }
+}
@@ -0,0 +1,21 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::gtin::is_upc;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// UPC-A barcode number.
+pub(crate) struct Upc {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Upc {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(s) if !is_upc(s) => Err(ValidationError::new("validator.upc")),
+            _ => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("upc", &["validator.upc"]))
+    }
+}
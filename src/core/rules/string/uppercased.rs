@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 
 pub(crate) struct Uppercased {}
 
@@ -13,6 +14,13 @@ impl<T: StrAsRef> IValidatorRule<T> for Uppercased {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "uppercased",
+            &["validator.is_uppercase"],
+        ))
+    }
 }
 
 #[cfg(test)]
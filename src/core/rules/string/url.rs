@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use url;
 
 pub(crate) struct UrlValid {}
@@ -14,6 +15,10 @@ impl<T: StrAsRef> IValidatorRule<T> for UrlValid {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("url", &["validator.url"]))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,290 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::net::special::is_public_ip;
+use crate::core::net::url_options::UrlOptions;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use url::{Host, Url};
+
+pub(crate) struct UrlWithOptions {
+    pub options: UrlOptions,
+}
+
+fn error(key: &str, params: &[(&str, String)]) -> ValidationError {
+    ValidationError::new_with_params(
+        key,
+        params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect::<HashMap<_, _>>(),
+    )
+}
+
+pub(crate) fn has_tld(domain: &str) -> bool {
+    match domain.trim_end_matches('.').rsplit_once('.') {
+        Some((_, tld)) => {
+            tld.len() >= 2
+                && (tld.starts_with("xn--") || tld.bytes().all(|b| b.is_ascii_alphabetic()))
+        }
+        None => false,
+    }
+}
+
+impl UrlWithOptions {
+    fn check_host(&self, url: &Url) -> Result<(), ValidationError> {
+        let options = &self.options;
+        let host_rules = options.require_tld
+            || options.forbid_ip_host
+            || options.forbid_private
+            || !options.allowed_hosts.is_empty()
+            || !options.denied_hosts.is_empty();
+
+        let Some(host) = url.host() else {
+            if host_rules {
+                return Err(error("validator.url.host", &[("host", String::new())]));
+            }
+            return Ok(());
+        };
+
+        let (name, ip) = match host {
+            Host::Domain(domain) => (domain.to_ascii_lowercase(), None),
+            Host::Ipv4(v4) => (v4.to_string(), Some(IpAddr::V4(v4))),
+            Host::Ipv6(v6) => (v6.to_string(), Some(IpAddr::V6(v6))),
+        };
+        // `example.com.` is the same host as `example.com`
+        let bare = name.trim_end_matches('.');
+
+        if let Some(ip) = ip {
+            if options.forbid_ip_host {
+                return Err(error("validator.url.ip_host", &[("host", name)]));
+            }
+            if options.forbid_private && !is_public_ip(&ip) {
+                return Err(error("validator.url.private", &[("host", name)]));
+            }
+        } else {
+            if options.forbid_private && (bare == "localhost" || bare.ends_with(".localhost")) {
+                return Err(error("validator.url.private", &[("host", name)]));
+            }
+            if options.require_tld && !has_tld(&name) {
+                return Err(error("validator.url.tld", &[("host", name)]));
+            }
+        }
+
+        if !options.host_allowed(bare) {
+            return Err(error("validator.url.host", &[("host", name)]));
+        }
+        Ok(())
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for UrlWithOptions {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(s) = value.as_str_ref() else {
+            return Ok(());
+        };
+        let options = &self.options;
+
+        // code points, like `maxLength` in the exported schema
+        if let Some(max) = options.max_length
+            && s.chars().count() > max
+        {
+            return Err(error(
+                "validator.url.max_length",
+                &[("max", max.to_string())],
+            ));
+        }
+
+        let url = Url::parse(s).map_err(|_| ValidationError::new("validator.url"))?;
+
+        if !options.schemes.is_empty() && !options.schemes.iter().any(|s| s == url.scheme()) {
+            return Err(error(
+                "validator.url.scheme",
+                &[
+                    ("scheme", url.scheme().to_string()),
+                    ("allowed", options.schemes.join(", ")),
+                ],
+            ));
+        }
+
+        if options.forbid_credentials && (!url.username().is_empty() || url.password().is_some()) {
+            return Err(ValidationError::new("validator.url.credentials"));
+        }
+
+        self.check_host(&url)
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let options = &self.options;
+        let mut descriptor = RuleDescriptor::new(
+            "url",
+            &[
+                "validator.url",
+                "validator.url.scheme",
+                "validator.url.host",
+                "validator.url.tld",
+                "validator.url.credentials",
+                "validator.url.ip_host",
+                "validator.url.private",
+                "validator.url.max_length",
+            ],
+        );
+
+        if !options.schemes.is_empty() {
+            descriptor = descriptor.param("schemes", options.schemes.clone());
+        }
+        if !options.allowed_hosts.is_empty() {
+            descriptor = descriptor.param("allow_hosts", options.allowed_hosts.clone());
+        }
+        if !options.denied_hosts.is_empty() {
+            descriptor = descriptor.param("deny_hosts", options.denied_hosts.clone());
+        }
+        for (name, enabled) in [
+            ("require_tld", options.require_tld),
+            ("forbid_credentials", options.forbid_credentials),
+            ("forbid_ip_host", options.forbid_ip_host),
+            ("forbid_private", options.forbid_private),
+        ] {
+            if enabled {
+                descriptor = descriptor.param(name, true);
+            }
+        }
+        if let Some(max) = options.max_length {
+            descriptor = descriptor.param("max_length", max);
+        }
+        Some(descriptor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(options: UrlOptions) -> UrlWithOptions {
+        UrlWithOptions { options }
+    }
+
+    fn key(options: UrlOptions, value: &str) -> String {
+        validator(options).validate(&value).unwrap_err().key
+    }
+
+    #[test]
+    fn default_options_accept_any_url() {
+        for value in [
+            "javascript:alert(1)",
+            "file:///etc/passwd",
+            "http://10.0.0.1",
+        ] {
+            assert!(validator(UrlOptions::new()).validate(&value).is_ok());
+        }
+        assert_eq!(key(UrlOptions::new(), "not a url"), "validator.url");
+    }
+
+    #[test]
+    fn ssrf_safe_accepts_public_urls() {
+        let rule = validator(UrlOptions::ssrf_safe());
+        assert!(
+            rule.validate(&"https://hooks.example.com/callback?id=1")
+                .is_ok()
+        );
+        assert!(rule.validate(&"http://xn--bcher-kva.de/").is_ok());
+    }
+
+    #[test]
+    fn ssrf_safe_rejects_dangerous_urls() {
+        let cases = [
+            ("javascript:alert(1)", "validator.url.scheme"),
+            ("file:///etc/passwd", "validator.url.scheme"),
+            ("https://user:pw@example.com/", "validator.url.credentials"),
+            ("http://169.254.169.254/latest", "validator.url.ip_host"),
+            ("http://[::1]:8080/", "validator.url.ip_host"),
+            ("http://localhost/", "validator.url.private"),
+            ("http://intranet/", "validator.url.tld"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(key(UrlOptions::ssrf_safe(), value), expected, "{value}");
+        }
+    }
+
+    #[test]
+    fn private_ip_literals_without_ip_ban() {
+        let options = UrlOptions::new().forbid_private(true);
+        assert!(
+            validator(options.clone())
+                .validate(&"http://8.8.8.8/")
+                .is_ok()
+        );
+        // WHATWG parsing turns `0x7f.1` into 127.0.0.1
+        assert_eq!(
+            key(options.clone(), "http://0x7f.1/"),
+            "validator.url.private"
+        );
+        assert_eq!(
+            key(options.clone(), "http://app.localhost/"),
+            "validator.url.private"
+        );
+        for value in ["http://[::10.0.0.1]/", "http://[64:ff9b:1::a00:1]/"] {
+            assert_eq!(
+                key(options.clone(), value),
+                "validator.url.private",
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn reports_scheme_params() {
+        let err = validator(UrlOptions::new().schemes(&["HTTPS"]))
+            .validate(&"http://example.com")
+            .unwrap_err();
+        assert_eq!(err.params.get("scheme"), Some(&"http".into()));
+        assert_eq!(err.params.get("allowed"), Some(&"https".into()));
+    }
+
+    #[test]
+    fn checks_host_lists() {
+        let options = UrlOptions::new()
+            .allow_hosts(&["*.example.com"])
+            .deny_hosts(&["internal.example.com"]);
+        assert!(
+            validator(options.clone())
+                .validate(&"https://api.example.com")
+                .is_ok()
+        );
+
+        let err = validator(options.clone())
+            .validate(&"https://internal.example.com")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.url.host");
+        assert_eq!(err.params.get("host"), Some(&"internal.example.com".into()));
+
+        assert_eq!(
+            key(options.clone(), "https://evil.com"),
+            "validator.url.host"
+        );
+        assert_eq!(
+            key(options.clone(), "https://internal.example.com./"),
+            "validator.url.host"
+        );
+        assert!(
+            validator(options.clone())
+                .validate(&"https://api.example.com./")
+                .is_ok()
+        );
+        assert_eq!(key(options, "mailto:a@example.com"), "validator.url.host");
+    }
+
+    #[test]
+    fn checks_max_length() {
+        let err = validator(UrlOptions::new().max_length(20))
+            .validate(&"https://example.com/long/path")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.url.max_length");
+        assert_eq!(err.params.get("max"), Some(&"20".into()));
+
+        // 20 code points, 23 bytes
+        let rule = validator(UrlOptions::new().max_length(20));
+        assert!(rule.validate(&"https://bücher.de/ää").is_ok());
+    }
+}
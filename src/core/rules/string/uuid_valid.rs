@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use uuid::Uuid;
 
 pub struct UuidValid {}
@@ -14,6 +15,10 @@ impl<T: StrAsRef> IValidatorRule<T> for UuidValid {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("uuid", &["validator.uuid"]))
+    }
 }
 
 #[cfg(test)]
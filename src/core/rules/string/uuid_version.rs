@@ -1,6 +1,7 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
 use std::collections::HashMap;
 use uuid::Uuid;
 
@@ -15,6 +16,20 @@ pub enum UuidVersion {
     V8,
 }
 
+impl UuidVersion {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UuidVersion::V1 => "v1",
+            UuidVersion::V3 => "v3",
+            UuidVersion::V4 => "v4",
+            UuidVersion::V5 => "v5",
+            UuidVersion::V6 => "v6",
+            UuidVersion::V7 => "v7",
+            UuidVersion::V8 => "v8",
+        }
+    }
+}
+
 pub struct UuidVersionValidator {
     pub version: UuidVersion,
 }
@@ -49,6 +64,19 @@ impl<T: StrAsRef> IValidatorRule<T> for UuidVersionValidator {
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "uuid_version",
+                &[
+                    "validator.uuid.invalid_format",
+                    "validator.uuid.version.mismatch",
+                ],
+            )
+            .param("version", self.version.as_str()),
+        )
+    }
 }
 
 #[cfg(test)]
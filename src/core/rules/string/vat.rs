@@ -0,0 +1,53 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::identifiers::vat::{VatFailure, validate_vat};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+pub(crate) struct Vat {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Vat {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(value) = value.as_str_ref() else {
+            return Ok(());
+        };
+        validate_vat(value).map_err(|failure| {
+            let (key, country) = match failure {
+                VatFailure::Country(country) => ("validator.vat.country", country),
+                VatFailure::Format(country) => ("validator.vat.format", country),
+                VatFailure::Checksum(country) => ("validator.vat.checksum", country),
+            };
+            ValidationError::new_with_params(key, HashMap::from([("country".into(), country)]))
+        })
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "vat",
+            &[
+                "validator.vat.country",
+                "validator.vat.format",
+                "validator.vat.checksum",
+            ],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_the_country_of_the_number() {
+        assert!(Vat {}.validate(&"DE 136 695 976").is_ok());
+        assert!(Vat {}.validate(&None::<String>).is_ok());
+
+        let err = Vat {}.validate(&"DE136695977").unwrap_err();
+        assert_eq!(err.key, "validator.vat.checksum");
+        assert_eq!(err.params["country"], "DE");
+        let err = Vat {}.validate(&"US123456789").unwrap_err();
+        assert_eq!(err.key, "validator.vat.country");
+        assert_eq!(err.params["country"], "US");
+    }
+}
@@ -1,38 +1,68 @@
 use crate::core::contract::IValidatorRule;
 use crate::core::errors::ValidationError;
 use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::text::length::WordBoundary;
 use std::collections::HashMap;
 
 pub(crate) struct WordCount {
     pub min: Option<usize>,
     pub max: Option<usize>,
+    pub boundary: WordBoundary,
 }
 
 impl<T: StrAsRef> IValidatorRule<T> for WordCount {
     fn validate(&self, value: &T) -> Result<(), ValidationError> {
         if let Some(s) = value.as_str_ref() {
-            let count = s.split_whitespace().count();
-
-            if let Some(min) = self.min {
-                if count < min {
-                    return Err(ValidationError::new_with_params(
-                        "validator.word_count.too_few",
-                        HashMap::from([("min".into(), min.to_string())]),
-                    ));
-                }
+            let count = self.boundary.count(s);
+            let boundary = self.boundary.as_str().to_string();
+
+            if let Some(min) = self.min
+                && count < min
+            {
+                return Err(ValidationError::new_with_params(
+                    "validator.word_count.too_few",
+                    HashMap::from([
+                        ("min".into(), min.to_string()),
+                        ("boundary".into(), boundary),
+                    ]),
+                ));
             }
 
-            if let Some(max) = self.max {
-                if count > max {
-                    return Err(ValidationError::new_with_params(
-                        "validator.word_count.too_many",
-                        HashMap::from([("max".into(), max.to_string())]),
-                    ));
-                }
+            if let Some(max) = self.max
+                && count > max
+            {
+                return Err(ValidationError::new_with_params(
+                    "validator.word_count.too_many",
+                    HashMap::from([
+                        ("max".into(), max.to_string()),
+                        ("boundary".into(), boundary),
+                    ]),
+                ));
             }
         }
         Ok(())
     }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let mut rule = RuleDescriptor::new(
+            "word_count",
+            &[
+                "validator.word_count.too_few",
+                "validator.word_count.too_many",
+            ],
+        );
+        if let Some(min) = self.min {
+            rule = rule.param("min", min);
+        }
+        if let Some(max) = self.max {
+            rule = rule.param("max", max);
+        }
+        if self.boundary != WordBoundary::Whitespace {
+            rule = rule.param("boundary", self.boundary.as_str());
+        }
+        Some(rule)
+    }
 }
 
 #[cfg(test)]
@@ -41,7 +71,11 @@ mod tests {
     use crate::core::contract::IValidatorRule;
 
     fn validator(min: Option<usize>, max: Option<usize>) -> WordCount {
-        WordCount { min, max }
+        WordCount {
+            min,
+            max,
+            boundary: WordBoundary::Whitespace,
+        }
     }
 
     #[test]
@@ -91,4 +125,18 @@ mod tests {
         let result = validator(Some(1), Some(5)).validate(&value);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn splits_on_unicode_word_boundaries() {
+        let rule = WordCount {
+            min: None,
+            max: Some(3),
+            boundary: WordBoundary::Unicode,
+        };
+        assert!(rule.validate(&"well-known").is_ok());
+
+        let err = rule.validate(&"state-of-the-art").unwrap_err();
+        assert_eq!(err.key, "validator.word_count.too_many");
+        assert_eq!(err.params["boundary"], "unicode");
+    }
 }
@@ -0,0 +1,56 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::document_options::parse_error;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+/// Well-formed XML 1.0 document: one root element, matching tags, unique attributes, declared
+/// entities. No schema or DTD validation.
+pub(crate) struct Xml {}
+
+impl<T: StrAsRef> IValidatorRule<T> for Xml {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(source) = value.as_str_ref() else {
+            return Ok(());
+        };
+        match roxmltree::Document::parse(source) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                let position = e.pos();
+                Err(parse_error(
+                    "validator.invalid_xml",
+                    position.row as usize,
+                    position.col as usize,
+                ))
+            }
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new("xml", &["validator.invalid_xml"]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_well_formedness() {
+        assert!(Xml {}.validate(&"<note><to>Ann</to></note>").is_ok());
+        assert!(
+            Xml {}
+                .validate(&r#"<?xml version="1.0"?><a x="1"><b/></a>"#)
+                .is_ok()
+        );
+        assert!(Xml {}.validate(&None::<String>).is_ok());
+
+        let err = Xml {}.validate(&"<a>\n  <b></a>").unwrap_err();
+        assert_eq!(err.key, "validator.invalid_xml");
+        assert_eq!(err.params["line"], "2");
+
+        assert!(Xml {}.validate(&"<a/><b/>").is_err());
+        assert!(Xml {}.validate(&r#"<a x="1" x="2"/>"#).is_err());
+        assert!(Xml {}.validate(&"plain text").is_err());
+    }
+}
@@ -0,0 +1,93 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::formats::document_options::{
+    DocumentOptions, DocumentType, parse_error, yaml_depth,
+};
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use serde_yaml::Value;
+
+/// Well-formed YAML (a single document) within the limits of `options`.
+pub(crate) struct Yaml {
+    pub options: DocumentOptions,
+}
+
+impl Yaml {
+    fn check(&self, source: &str) -> Result<(), ValidationError> {
+        self.options.check_size(source)?;
+        let document: Value = serde_yaml::from_str(source).map_err(|e| {
+            let (line, column) = e.location().map_or((1, 1), |l| (l.line(), l.column()));
+            parse_error("validator.invalid_yaml", line, column)
+        })?;
+        let top_level = match document {
+            Value::Mapping(_) => Some(DocumentType::Object),
+            Value::Sequence(_) => Some(DocumentType::Array),
+            _ => None,
+        };
+        self.options.check_shape(yaml_depth(&document), top_level)
+    }
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for Yaml {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        match value.as_str_ref() {
+            Some(source) => self.check(source),
+            None => Ok(()),
+        }
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(self.options.describe(RuleDescriptor::new(
+            "yaml",
+            &[
+                "validator.invalid_yaml",
+                "validator.document.too_large",
+                "validator.document.too_deep",
+                "validator.document.top_level",
+            ],
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(options: DocumentOptions) -> Yaml {
+        Yaml { options }
+    }
+
+    #[test]
+    fn reports_syntax_errors_with_position() {
+        assert!(
+            rule(DocumentOptions::new())
+                .validate(&"name: Ann\nage: 30\n")
+                .is_ok()
+        );
+        assert!(rule(DocumentOptions::new()).validate(&"42").is_ok());
+
+        let err = rule(DocumentOptions::new())
+            .validate(&"name: Ann\n  age: 30\n")
+            .unwrap_err();
+        assert_eq!(err.key, "validator.invalid_yaml");
+        assert_eq!(err.params["line"], "2");
+    }
+
+    #[test]
+    fn applies_document_options() {
+        let options = || {
+            DocumentOptions::new()
+                .max_depth(1)
+                .top_level(DocumentType::Object)
+        };
+        assert!(rule(options()).validate(&"a: 1\nb: 2\n").is_ok());
+        assert_eq!(
+            rule(options()).validate(&"a: [1]\n").unwrap_err().key,
+            "validator.document.too_deep"
+        );
+        assert_eq!(
+            rule(options()).validate(&"- 1\n").unwrap_err().key,
+            "validator.document.top_level"
+        );
+    }
+}
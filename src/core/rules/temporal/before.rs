@@ -0,0 +1,123 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::temporal::bound::TimeBound;
+use std::collections::HashMap;
+
+pub(crate) struct Before {
+    pub bound: TimeBound,
+}
+
+impl<T: TemporalRef> IValidatorRule<T> for Before {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(actual) = value.moment() else {
+            return Ok(());
+        };
+        let actual = actual?;
+
+        if let Some(limit) = self.bound.resolve(ctx.now())
+            && actual >= limit
+        {
+            return Err(ValidationError::new_with_params(
+                "validator.date_before",
+                HashMap::from([("date".into(), limit.to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "before",
+                &["validator.date_before", "validator.date_invalid"],
+            )
+            .param("date", self.bound.describe()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+    use std::time::Duration;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator(bound: TimeBound) -> Before {
+        Before { bound }
+    }
+
+    #[test]
+    fn validates_earlier_date() {
+        let value = "2024-04-30";
+        let result = validator(TimeBound::parse("2024-05-01").unwrap())
+            .validate_with_context(&value, &ctx());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn fails_equal_or_later_date() {
+        let bound = TimeBound::parse("2024-05-01").unwrap();
+
+        let err = validator(bound)
+            .validate_with_context(&"2024-05-01", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.date_before");
+        assert_eq!(err.params.get("date"), Some(&"2024-05-01".into()));
+
+        assert!(
+            validator(bound)
+                .validate_with_context(&"2024-05-02T00:00:00Z", &ctx())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn resolves_relative_bound() {
+        let bound = TimeBound::Ago(Duration::from_secs(3600));
+        assert!(
+            validator(bound)
+                .validate_with_context(&"2024-05-01T10:59:59Z", &ctx())
+                .is_ok()
+        );
+
+        let err = validator(bound)
+            .validate_with_context(&"2024-05-01T11:00:00Z", &ctx())
+            .unwrap_err();
+        assert_eq!(err.params.get("date"), Some(&"2024-05-01T11:00:00Z".into()));
+    }
+
+    #[test]
+    fn fails_unparsable_string() {
+        let err = validator(TimeBound::Now)
+            .validate_with_context(&"yesterday", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.date_invalid");
+    }
+
+    #[test]
+    fn skips_none() {
+        let value: Option<String> = None;
+        assert!(
+            validator(TimeBound::Now)
+                .validate_with_context(&value, &ctx())
+                .is_ok()
+        );
+    }
+}
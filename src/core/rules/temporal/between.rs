@@ -0,0 +1,109 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::temporal::bound::TimeBound;
+use std::collections::HashMap;
+
+/// Inclusive on both ends.
+pub(crate) struct Between {
+    pub min: TimeBound,
+    pub max: TimeBound,
+}
+
+impl<T: TemporalRef> IValidatorRule<T> for Between {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(actual) = value.moment() else {
+            return Ok(());
+        };
+        let actual = actual?;
+
+        let now = ctx.now();
+        if let (Some(min), Some(max)) = (self.min.resolve(now), self.max.resolve(now))
+            && (actual < min || actual > max)
+        {
+            return Err(ValidationError::new_with_params(
+                "validator.date_between",
+                HashMap::from([
+                    ("min".into(), min.to_string()),
+                    ("max".into(), max.to_string()),
+                ]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new(
+                "between",
+                &["validator.date_between", "validator.date_invalid"],
+            )
+            .param("min", self.min.describe())
+            .param("max", self.max.describe()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+    use std::time::Duration;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator(min: TimeBound, max: TimeBound) -> Between {
+        Between { min, max }
+    }
+
+    #[test]
+    fn validates_inclusive_range() {
+        let rule = validator(
+            TimeBound::parse("2024-01-01").unwrap(),
+            TimeBound::parse("2024-12-31").unwrap(),
+        );
+        assert!(rule.validate_with_context(&"2024-01-01", &ctx()).is_ok());
+        assert!(rule.validate_with_context(&"2024-12-31", &ctx()).is_ok());
+        assert!(
+            rule.validate_with_context(&"2024-06-15T10:00:00+03:00", &ctx())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fails_outside_range() {
+        let rule = validator(
+            TimeBound::parse("2024-01-01").unwrap(),
+            TimeBound::parse("2024-12-31").unwrap(),
+        );
+        let err = rule
+            .validate_with_context(&"2025-01-01", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.date_between");
+        assert_eq!(err.params.get("min"), Some(&"2024-01-01".into()));
+        assert_eq!(err.params.get("max"), Some(&"2024-12-31".into()));
+    }
+
+    #[test]
+    fn supports_relative_window() {
+        let week = Duration::from_secs(7 * 86_400);
+        let rule = validator(TimeBound::Now, TimeBound::FromNow(week));
+        assert!(rule.validate_with_context(&"2024-05-03", &ctx()).is_ok());
+        assert!(rule.validate_with_context(&"2024-04-30", &ctx()).is_err());
+        assert!(rule.validate_with_context(&"2024-05-09", &ctx()).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::temporal::calendar::BusinessCalendar;
+
+pub(crate) struct BusinessDay {
+    pub calendar: BusinessCalendar,
+}
+
+impl<T: TemporalRef> IValidatorRule<T> for BusinessDay {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        let Some(actual) = value.moment() else {
+            return Ok(());
+        };
+
+        if !self.calendar.is_business_day(&actual?.date()) {
+            return Err(ValidationError::new("validator.business_day"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "business_day",
+            &["validator.business_day", "validator.date_invalid"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator() -> BusinessDay {
+        BusinessDay {
+            calendar: BusinessCalendar::new().holidays(&["2024-12-25"]).unwrap(),
+        }
+    }
+
+    #[test]
+    fn validates_weekday() {
+        assert!(validator().validate(&"2024-05-03").is_ok());
+    }
+
+    #[test]
+    fn fails_weekend_and_holiday() {
+        let err = validator().validate(&"2024-05-04").unwrap_err();
+        assert_eq!(err.key, "validator.business_day");
+        assert!(validator().validate(&"2024-12-25").is_err());
+    }
+
+    #[test]
+    fn uses_local_date_of_offset() {
+        // Friday evening in New York is already Saturday in UTC
+        assert!(validator().validate(&"2024-05-03T22:00:00-04:00").is_ok());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::temporal::format::DateFormat;
+use std::collections::HashMap;
+
+pub(crate) struct DateFormatRule {
+    pub format: DateFormat,
+}
+
+impl<T: StrAsRef> IValidatorRule<T> for DateFormatRule {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        if let Some(s) = value.as_str_ref()
+            && !self.format.matches(s)
+        {
+            return Err(ValidationError::new_with_params(
+                "validator.date_format",
+                HashMap::from([("format".into(), self.format.name().to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("date_format", &["validator.date_format"])
+                .param("format", self.format.name()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(format: DateFormat) -> DateFormatRule {
+        DateFormatRule { format }
+    }
+
+    #[test]
+    fn validates_matching_format() {
+        let value = "2024-05-01";
+        assert!(validator(DateFormat::Date).validate(&value).is_ok());
+    }
+
+    #[test]
+    fn fails_other_format() {
+        let value = "01.05.2024";
+        let err = validator(DateFormat::Date).validate(&value).unwrap_err();
+        assert_eq!(err.key, "validator.date_format");
+        assert_eq!(err.params.get("format"), Some(&"date".into()));
+    }
+
+    #[test]
+    fn validates_pattern() {
+        let value = "01.05.2024".to_string();
+        assert!(
+            validator(DateFormat::pattern("%d.%m.%Y"))
+                .validate(&value)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn skips_none() {
+        let value: Option<String> = None;
+        assert!(validator(DateFormat::DateTime).validate(&value).is_ok());
+    }
+}
@@ -0,0 +1,84 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+pub(crate) struct InFuture {}
+
+impl<T: TemporalRef> IValidatorRule<T> for InFuture {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(actual) = value.moment() else {
+            return Ok(());
+        };
+
+        if actual? <= ctx.now() {
+            return Err(ValidationError::new("validator.in_future"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "in_future",
+            &["validator.in_future", "validator.date_invalid"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator() -> InFuture {
+        InFuture {}
+    }
+
+    #[test]
+    fn validates_future_value() {
+        assert!(
+            validator()
+                .validate_with_context(&"2024-05-01T12:00:01Z", &ctx())
+                .is_ok()
+        );
+        assert!(
+            validator()
+                .validate_with_context(&"2024-05-01T14:01:00+02:00", &ctx())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fails_now_and_past() {
+        let err = validator()
+            .validate_with_context(&"2024-05-01T12:00:00Z", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.in_future");
+        assert!(
+            validator()
+                .validate_with_context(&"2024-05-01", &ctx())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn skips_none() {
+        let value: Option<&str> = None;
+        assert!(validator().validate_with_context(&value, &ctx()).is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+
+pub(crate) struct InPast {}
+
+impl<T: TemporalRef> IValidatorRule<T> for InPast {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(actual) = value.moment() else {
+            return Ok(());
+        };
+
+        if actual? >= ctx.now() {
+            return Err(ValidationError::new("validator.in_past"));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(RuleDescriptor::new(
+            "in_past",
+            &["validator.in_past", "validator.date_invalid"],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+    use std::time::SystemTime;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator() -> InPast {
+        InPast {}
+    }
+
+    #[test]
+    fn validates_past_value() {
+        assert!(
+            validator()
+                .validate_with_context(&"2024-05-01T11:59:59Z", &ctx())
+                .is_ok()
+        );
+        assert!(
+            validator()
+                .validate_with_context(&"1990-01-01", &ctx())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fails_now_and_future() {
+        let err = validator()
+            .validate_with_context(&"2024-05-01T12:00:00Z", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.in_past");
+        assert!(
+            validator()
+                .validate_with_context(&"2030-01-01", &ctx())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn validates_system_time() {
+        assert!(
+            validator()
+                .validate_with_context(&SystemTime::UNIX_EPOCH, &ctx())
+                .is_ok()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Full years between a birth date and today's (UTC) date.
+pub(crate) struct MaxAge {
+    pub years: u32,
+}
+
+impl<T: TemporalRef> IValidatorRule<T> for MaxAge {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(birth) = value.moment() else {
+            return Ok(());
+        };
+
+        let age = birth?.date().years_until(&ctx.now().date());
+        if age > self.years as i32 {
+            return Err(ValidationError::new_with_params(
+                "validator.max_age",
+                HashMap::from([("max".into(), self.years.to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("max_age", &["validator.max_age", "validator.date_invalid"])
+                .param("max", self.years),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator(years: u32) -> MaxAge {
+        MaxAge { years }
+    }
+
+    #[test]
+    fn validates_young_enough() {
+        assert!(
+            validator(120)
+                .validate_with_context(&"1904-05-02", &ctx())
+                .is_ok()
+        );
+        assert!(
+            validator(120)
+                .validate_with_context(&"2000-01-01", &ctx())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fails_too_old() {
+        let err = validator(120)
+            .validate_with_context(&"1903-05-01", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.max_age");
+        assert_eq!(err.params.get("max"), Some(&"120".into()));
+    }
+}
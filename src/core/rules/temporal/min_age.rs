@@ -0,0 +1,91 @@
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::ValidationError;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::schema::descriptor::RuleDescriptor;
+use std::collections::HashMap;
+
+/// Full years between a birth date and today's (UTC) date.
+pub(crate) struct MinAge {
+    pub years: u32,
+}
+
+impl<T: TemporalRef> IValidatorRule<T> for MinAge {
+    fn validate(&self, value: &T) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &T,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        let Some(birth) = value.moment() else {
+            return Ok(());
+        };
+
+        let age = birth?.date().years_until(&ctx.now().date());
+        if age < self.years as i32 {
+            return Err(ValidationError::new_with_params(
+                "validator.min_age",
+                HashMap::from([("min".into(), self.years.to_string())]),
+            ));
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        Some(
+            RuleDescriptor::new("min_age", &["validator.min_age", "validator.date_invalid"])
+                .param("min", self.years),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::temporal::clock::FixedClock;
+    use crate::core::temporal::moment::Moment;
+
+    fn ctx() -> ValidationContext {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        ValidationContext::new().with_clock(FixedClock(now))
+    }
+
+    fn validator(years: u32) -> MinAge {
+        MinAge { years }
+    }
+
+    #[test]
+    fn validates_old_enough() {
+        assert!(
+            validator(18)
+                .validate_with_context(&"2006-05-01", &ctx())
+                .is_ok()
+        );
+        assert!(
+            validator(18)
+                .validate_with_context(&"1980-12-31", &ctx())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn fails_too_young() {
+        let err = validator(18)
+            .validate_with_context(&"2006-05-02", &ctx())
+            .unwrap_err();
+        assert_eq!(err.key, "validator.min_age");
+        assert_eq!(err.params.get("min"), Some(&"18".into()));
+    }
+
+    #[test]
+    fn fails_future_birth_date() {
+        assert!(
+            validator(0)
+                .validate_with_context(&"2030-01-01", &ctx())
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,9 @@
+pub mod after;
+pub mod before;
+pub mod between;
+pub mod business_day;
+pub mod date_format;
+pub mod in_future;
+pub mod in_past;
+pub mod max_age;
+pub mod min_age;
@@ -0,0 +1,200 @@
+use crate::core::errors::ValidationError;
+use crate::core::temporal::format::parse_iso8601;
+use crate::core::temporal::moment::Moment;
+use std::borrow::Cow;
+use std::time::SystemTime;
+
+pub trait TemporalRef {
+    /// `None` when there is nothing to check (`Option::None`); `Some(Err(..))` for strings
+    /// that are not ISO-8601 / RFC-3339 dates.
+    fn moment(&self) -> Option<Result<Moment, ValidationError>>;
+}
+
+fn parse(value: &str) -> Option<Result<Moment, ValidationError>> {
+    Some(parse_iso8601(value).ok_or_else(|| ValidationError::new("validator.date_invalid")))
+}
+
+impl TemporalRef for Moment {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        Some(Ok(*self))
+    }
+}
+
+impl TemporalRef for SystemTime {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        Some(Ok(Moment::from_system_time(*self)))
+    }
+}
+
+impl TemporalRef for String {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        parse(self)
+    }
+}
+
+impl TemporalRef for &str {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        parse(self)
+    }
+}
+
+impl TemporalRef for Cow<'static, str> {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        parse(self)
+    }
+}
+
+impl<T: TemporalRef> TemporalRef for Option<T> {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        self.as_ref().and_then(|v| v.moment())
+    }
+}
+
+impl<T: TemporalRef> TemporalRef for &T {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        (**self).moment()
+    }
+}
+
+impl<T: TemporalRef> TemporalRef for Box<T> {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        (**self).moment()
+    }
+}
+
+impl<T: TemporalRef> TemporalRef for std::sync::Arc<T> {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        (**self).moment()
+    }
+}
+
+impl<T: TemporalRef> TemporalRef for std::rc::Rc<T> {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        (**self).moment()
+    }
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_impls {
+    use super::*;
+    use crate::core::temporal::moment::CivilDate;
+    use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Offset, TimeZone};
+
+    impl TemporalRef for NaiveDate {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            let date = CivilDate::new(self.year(), self.month() as u8, self.day() as u8)?;
+            Some(Ok(Moment::from_date(date)))
+        }
+    }
+
+    impl TemporalRef for NaiveDateTime {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            let utc = self.and_utc();
+            Some(Ok(Moment::from_unix(
+                utc.timestamp(),
+                utc.timestamp_subsec_nanos(),
+            )))
+        }
+    }
+
+    impl<Tz: TimeZone> TemporalRef for DateTime<Tz> {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            let offset = self.offset().fix().local_minus_utc() / 60;
+            let moment = Moment::from_unix(self.timestamp(), self.timestamp_subsec_nanos());
+            Some(Ok(moment.with_offset_minutes(offset)))
+        }
+    }
+}
+
+#[cfg(feature = "time")]
+mod time_impls {
+    use super::*;
+    use crate::core::temporal::moment::CivilDate;
+    use time::{Date, OffsetDateTime, PrimitiveDateTime};
+
+    impl TemporalRef for Date {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            let date = CivilDate::new(self.year(), self.month() as u8, self.day())?;
+            Some(Ok(Moment::from_date(date)))
+        }
+    }
+
+    impl TemporalRef for PrimitiveDateTime {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            self.assume_utc().moment()
+        }
+    }
+
+    impl TemporalRef for OffsetDateTime {
+        fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+            let offset = self.offset().whole_minutes() as i32;
+            let moment = Moment::from_unix(self.unix_timestamp(), self.nanosecond());
+            Some(Ok(moment.with_offset_minutes(offset)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    #[test]
+    fn parses_strings() {
+        let value = "2024-05-01T10:00:00Z";
+        assert_eq!(
+            value.moment().unwrap().unwrap().unix_timestamp(),
+            1_714_557_600
+        );
+        assert_eq!(
+            "nope".to_string().moment().unwrap().unwrap_err().key,
+            "validator.date_invalid"
+        );
+    }
+
+    #[test]
+    fn skips_none() {
+        let value: Option<String> = None;
+        assert!(value.moment().is_none());
+    }
+
+    #[test]
+    fn unwraps_containers() {
+        assert!(Box::new("2024-05-01".to_string()).moment().is_some());
+        assert!(Arc::new(Some("2024-05-01")).moment().is_some());
+        assert!(Rc::new(SystemTime::now()).moment().is_some());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn reads_chrono_types() {
+        use chrono::{FixedOffset, NaiveDate, TimeZone};
+
+        let date = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+        assert_eq!(date.moment().unwrap().unwrap().to_string(), "2024-05-01");
+
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let local = offset.with_ymd_and_hms(2024, 5, 1, 1, 0, 0).unwrap();
+        let moment = local.moment().unwrap().unwrap();
+        assert_eq!(moment.to_string(), "2024-05-01T01:00:00+02:00");
+        assert_eq!(moment, Moment::parse("2024-04-30T23:00:00Z").unwrap());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn reads_time_types() {
+        use time::{Date, Month, OffsetDateTime, UtcOffset};
+
+        let date = Date::from_calendar_date(2024, Month::May, 1).unwrap();
+        assert_eq!(date.moment().unwrap().unwrap().to_string(), "2024-05-01");
+
+        let moment = OffsetDateTime::from_unix_timestamp(1_714_557_600)
+            .unwrap()
+            .to_offset(UtcOffset::from_hms(-2, 0, 0).unwrap())
+            .moment()
+            .unwrap()
+            .unwrap();
+        assert_eq!(moment.to_string(), "2024-05-01T08:00:00-02:00");
+    }
+}
@@ -1,3 +1,7 @@
+use std::num::{
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize, NonZeroU8,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+};
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -25,7 +29,27 @@ macro_rules! impl_value_ref_for_numbers {
     };
 }
 
-impl_value_ref_for_numbers!(i8, i16, i32, i64, u8, u16, u32, u64, f32, f64);
+impl_value_ref_for_numbers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+);
+
+impl_value_ref_for_numbers!(
+    NonZeroI8,
+    NonZeroI16,
+    NonZeroI32,
+    NonZeroI64,
+    NonZeroI128,
+    NonZeroIsize,
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroU128,
+    NonZeroUsize
+);
+
+#[cfg(feature = "decimal")]
+impl_value_ref_for_numbers!(rust_decimal::Decimal);
 
 // ┌────────────────────────────────────────────┐
 // │          Делегуючі обгортки                │
@@ -81,6 +105,15 @@ mod tests {
         assert_eq!(f.value(), Some(&3.14));
     }
 
+    #[test]
+    fn value_for_wide_and_non_zero_integers() {
+        assert_eq!(u128::MAX.value(), Some(&u128::MAX));
+        assert_eq!(7usize.value(), Some(&7));
+
+        let n = NonZeroU32::new(5).unwrap();
+        assert_eq!(n.value(), Some(&n));
+    }
+
     #[test]
     fn value_for_reference() {
         let v = &100u16;
@@ -0,0 +1,169 @@
+pub mod sanitizer;
+
+/// Rewrites a field in place before the rules run (`FieldBuilder::sanitize`). Implemented by
+/// `Sanitizer`, by closures `Fn(&mut V)` and by arrays/`Vec`s of sanitizers, applied in order.
+pub trait Sanitize<V>: Send + Sync {
+    fn sanitize(&self, value: &mut V);
+}
+
+impl<V, F> Sanitize<V> for F
+where
+    F: Fn(&mut V) + Send + Sync,
+{
+    fn sanitize(&self, value: &mut V) {
+        self(value)
+    }
+}
+
+impl<V, S: Sanitize<V>, const N: usize> Sanitize<V> for [S; N] {
+    fn sanitize(&self, value: &mut V) {
+        self.iter().for_each(|sanitizer| sanitizer.sanitize(value));
+    }
+}
+
+impl<V, S: Sanitize<V>> Sanitize<V> for Vec<S> {
+    fn sanitize(&self, value: &mut V) {
+        self.iter().for_each(|sanitizer| sanitizer.sanitize(value));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use valida::prelude::*;
+
+    struct SignUp {
+        email: String,
+        nickname: Option<String>,
+    }
+
+    struct SignUpValidator;
+
+    impl IValidate<SignUp, std::io::Error> for SignUpValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<SignUp, std::io::Error>,
+        ) -> RulesBuilder<SignUp, std::io::Error> {
+            builder
+                .field("email", |s| &s.email)
+                .sanitize(|s| &mut s.email, [Sanitizer::Trim, Sanitizer::Lowercase])
+                .email()
+                .trimmed()
+                .build();
+            builder
+                .field("nickname", |s| &s.nickname)
+                .sanitize(|s| &mut s.nickname, Sanitizer::CollapseWhitespace)
+                .max_length(8)
+                .build();
+            builder
+        }
+    }
+
+    #[tokio::test]
+    async fn sanitizes_before_validation() {
+        let mut dto = SignUp {
+            email: "  Ann@Example.COM ".into(),
+            nickname: Some("ann   the  great".into()),
+        };
+
+        assert!(SignUpValidator.validate(&dto).await.is_err());
+        match SignUpValidator.sanitize_and_validate(&mut dto).await {
+            Err(ValidatorFailure::Invalid(errors)) => {
+                assert!(!errors.has_error_for_field("email"));
+                assert!(errors.has_error_for_field("nickname"));
+            }
+            _ => panic!("Should be invalid"),
+        }
+        assert_eq!(dto.email, "ann@example.com");
+        assert_eq!(dto.nickname.as_deref(), Some("ann the great"));
+    }
+
+    struct Team {
+        members: Vec<SignUp>,
+    }
+
+    struct TeamValidator;
+
+    impl IValidate<Team, std::io::Error> for TeamValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Team, std::io::Error>,
+        ) -> RulesBuilder<Team, std::io::Error> {
+            builder
+                .field("members", |t| &t.members)
+                .nested_mut(|t| &mut t.members, SignUpValidator)
+                .build();
+            builder
+        }
+    }
+
+    #[tokio::test]
+    async fn sanitizes_nested_validators() {
+        let mut team = Team {
+            members: vec![SignUp {
+                email: " Bob@Example.com".into(),
+                nickname: Some("bob  b".into()),
+            }],
+        };
+
+        let ctx = ValidationContext::default();
+        assert!(
+            TeamValidator
+                .sanitize_and_validate_with_context(&mut team, &ctx)
+                .await
+                .is_ok()
+        );
+        assert_eq!(team.members[0].email, "bob@example.com");
+        assert_eq!(team.members[0].nickname.as_deref(), Some("bob b"));
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod derive_tests {
+    use valida::prelude::*;
+
+    #[Validatable(std::io::Error)]
+    pub struct Contact {
+        #[sanitize(trim, lowercase)]
+        #[validate(email)]
+        pub email: String,
+
+        #[sanitize(strip_control_chars, nfc)]
+        pub note: String,
+    }
+
+    #[Validatable(std::io::Error)]
+    pub struct Card {
+        #[validate(nested(ContactValidator))]
+        pub contact: Option<Contact>,
+    }
+
+    #[tokio::test]
+    async fn derive_generates_sanitizers() {
+        let mut contact = Contact {
+            email: " Ann@Example.com".into(),
+            note: "Cafe\u{301}\0".into(),
+        };
+
+        assert!(
+            ContactValidator
+                .sanitize_and_validate(&mut contact)
+                .await
+                .is_ok()
+        );
+        assert_eq!(contact.email, "ann@example.com");
+        assert_eq!(contact.note, "Café");
+    }
+
+    #[tokio::test]
+    async fn derive_sanitizes_nested_fields() {
+        let mut card = Card {
+            contact: Some(Contact {
+                email: "Ann@Example.com ".into(),
+                note: String::new(),
+            }),
+        };
+
+        assert!(CardValidator.sanitize_and_validate(&mut card).await.is_ok());
+        assert_eq!(card.contact.unwrap().email, "ann@example.com");
+    }
+}
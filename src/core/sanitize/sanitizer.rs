@@ -0,0 +1,158 @@
+use crate::core::sanitize::Sanitize;
+use std::borrow::Cow;
+use unicode_normalization::{UnicodeNormalization, is_nfc};
+
+/// Built-in string sanitizers. `None` values are left alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sanitizer {
+    /// Removes leading and trailing whitespace.
+    Trim,
+    Lowercase,
+    /// Unicode canonical composition: `e` + U+0301 becomes `é`.
+    Nfc,
+    /// Removes control characters (`\0`, `\x1b`, ...) except tabs and line breaks.
+    StripControlChars,
+    /// Replaces every run of whitespace with a single space.
+    CollapseWhitespace,
+}
+
+impl Sanitizer {
+    fn apply(&self, value: &str) -> Option<String> {
+        match self {
+            Sanitizer::Trim => {
+                let trimmed = value.trim();
+                (trimmed.len() != value.len()).then(|| trimmed.to_string())
+            }
+            Sanitizer::Lowercase => {
+                let lowered = value.to_lowercase();
+                (lowered != value).then_some(lowered)
+            }
+            Sanitizer::Nfc => (!is_nfc(value)).then(|| value.nfc().collect()),
+            Sanitizer::StripControlChars => {
+                let strip = |c: char| c.is_control() && !matches!(c, '\t' | '\n' | '\r');
+                value
+                    .contains(strip)
+                    .then(|| value.chars().filter(|&c| !strip(c)).collect())
+            }
+            Sanitizer::CollapseWhitespace => {
+                let mut collapsed = String::with_capacity(value.len());
+                let mut in_run = false;
+                for c in value.chars() {
+                    if c.is_whitespace() {
+                        if !in_run {
+                            collapsed.push(' ');
+                        }
+                        in_run = true;
+                    } else {
+                        collapsed.push(c);
+                        in_run = false;
+                    }
+                }
+                (collapsed != value).then_some(collapsed)
+            }
+        }
+    }
+}
+
+impl<V: StrAsMut> Sanitize<V> for Sanitizer {
+    fn sanitize(&self, value: &mut V) {
+        if let Some(text) = value.as_string_mut()
+            && let Some(sanitized) = self.apply(text)
+        {
+            *text = sanitized;
+        }
+    }
+}
+
+/// Mutable counterpart of `StrAsRef` for the types sanitizers can rewrite.
+pub trait StrAsMut {
+    fn as_string_mut(&mut self) -> Option<&mut String>;
+}
+
+impl StrAsMut for String {
+    fn as_string_mut(&mut self) -> Option<&mut String> {
+        Some(self)
+    }
+}
+
+impl StrAsMut for Cow<'static, str> {
+    fn as_string_mut(&mut self) -> Option<&mut String> {
+        Some(self.to_mut())
+    }
+}
+
+impl StrAsMut for serde_json::Value {
+    fn as_string_mut(&mut self) -> Option<&mut String> {
+        match self {
+            serde_json::Value::String(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+impl<T: StrAsMut> StrAsMut for Option<T> {
+    fn as_string_mut(&mut self) -> Option<&mut String> {
+        self.as_mut().and_then(|v| v.as_string_mut())
+    }
+}
+
+impl<T: StrAsMut> StrAsMut for Box<T> {
+    fn as_string_mut(&mut self) -> Option<&mut String> {
+        (**self).as_string_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitized(sanitizer: impl Sanitize<String>, value: &str) -> String {
+        let mut value = value.to_string();
+        sanitizer.sanitize(&mut value);
+        value
+    }
+
+    #[test]
+    fn rewrites_strings() {
+        assert_eq!(sanitized(Sanitizer::Trim, " \tAnn \n"), "Ann");
+        assert_eq!(
+            sanitized(Sanitizer::Lowercase, "Ann@Example.COM"),
+            "ann@example.com"
+        );
+        assert_eq!(sanitized(Sanitizer::Nfc, "Cafe\u{301}"), "Café");
+        assert_eq!(
+            sanitized(Sanitizer::StripControlChars, "a\0b\x1bc\nd"),
+            "abc\nd"
+        );
+        assert_eq!(
+            sanitized(Sanitizer::CollapseWhitespace, " Ann \t\n Smith "),
+            " Ann Smith "
+        );
+    }
+
+    #[test]
+    fn applies_lists_in_order() {
+        let normalize = [
+            Sanitizer::StripControlChars,
+            Sanitizer::CollapseWhitespace,
+            Sanitizer::Trim,
+        ];
+        assert_eq!(sanitized(normalize, "  Ann\0  \t Smith "), "Ann Smith");
+        assert_eq!(sanitized(|v: &mut String| v.truncate(3), "Annabel"), "Ann");
+    }
+
+    #[test]
+    fn skips_missing_and_non_string_values() {
+        let mut missing: Option<String> = None;
+        Sanitizer::Trim.sanitize(&mut missing);
+        assert_eq!(missing, None);
+
+        let mut present = Some(" Ann ".to_string());
+        Sanitizer::Trim.sanitize(&mut present);
+        assert_eq!(present.as_deref(), Some("Ann"));
+
+        let mut number = serde_json::json!(42);
+        Sanitizer::Trim.sanitize(&mut number);
+        assert_eq!(number, serde_json::json!(42));
+    }
+}
@@ -0,0 +1,401 @@
+use crate::core::schema::descriptor::{FieldDescriptor, RuleDescriptor, ValidatorDescriptor};
+use crate::core::schema::json_schema::{collect_nested, validator_keys};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeSet, HashMap};
+
+/// Bumped on breaking changes of the manifest layout.
+pub const CLIENT_MANIFEST_VERSION: u32 = 1;
+
+impl ValidatorDescriptor {
+    /// Manifest of field rules for browser-side validation:
+    ///
+    /// ```json
+    /// {
+    ///   "version": 1,
+    ///   "root": "UserValidator",
+    ///   "validators": {
+    ///     "UserValidator": {
+    ///       "target": "User",
+    ///       "fields": {
+    ///         "email": {
+    ///           "rules": [{"rule": "max_length", "keys": ["validator.max_length"], "params": {"max": 64}}],
+    ///           "custom_rules": 0
+    ///         },
+    ///         "address": {"rules": [], "custom_rules": 0, "nested": {"shape": "one", "validator": "AddressValidator"}}
+    ///       }
+    ///     }
+    ///   },
+    ///   "keys": ["validator.max_length"]
+    /// }
+    /// ```
+    ///
+    /// Maps are sorted and rules keep their declaration order, so the output is stable
+    /// between builds. Validators are keyed by name, or by full type name when several share
+    /// a name. Leading `(?i)`/`(?m)`/`(?s)` groups of patterns are moved to `flags` for
+    /// `new RegExp(pattern, flags)`; patterns with Rust-only syntax count as `custom_rules`.
+    pub fn to_client_manifest(&self) -> Value {
+        let mut nested = vec![];
+        collect_nested(self, &mut nested);
+        let names = validator_keys(std::iter::once(self).chain(nested));
+
+        let mut manifest = Manifest {
+            names,
+            validators: Map::new(),
+            keys: BTreeSet::new(),
+        };
+        manifest.collect(self);
+
+        json!({
+            "version": CLIENT_MANIFEST_VERSION,
+            "root": manifest.name_of(self),
+            "validators": manifest.validators,
+            "keys": manifest.keys,
+        })
+    }
+
+    /// [`ValidatorDescriptor::to_client_manifest`] plus the `valida.yml` message templates
+    /// of the used keys, e.g. `messages.en["validator.min_length"]`. Placeholders keep the
+    /// `%{param}` form.
+    #[cfg(feature = "i18n-localization")]
+    pub fn to_client_manifest_with_messages(&self, locales: &[&str]) -> Value {
+        let mut manifest = self.to_client_manifest();
+        let keys: Vec<String> = manifest["keys"]
+            .as_array()
+            .map(|keys| {
+                keys.iter()
+                    .filter_map(|k| k.as_str().map(str::to_string))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        manifest["messages"] =
+            crate::core::features::localization::i18n::client_messages::client_messages(
+                &keys, locales,
+            );
+        manifest
+    }
+}
+
+struct Manifest {
+    /// Full validator type name → key in `validators`.
+    names: HashMap<String, String>,
+    validators: Map<String, Value>,
+    keys: BTreeSet<&'static str>,
+}
+
+impl Manifest {
+    fn name_of(&self, validator: &ValidatorDescriptor) -> String {
+        let name = self.names.get(&validator.type_name);
+        name.unwrap_or(&validator.name).clone()
+    }
+
+    fn collect(&mut self, validator: &ValidatorDescriptor) {
+        let name = self.name_of(validator);
+        if validator.recursive || self.validators.contains_key(&name) {
+            return;
+        }
+        // reserve the name first, nested validators may point back to this one
+        self.validators.insert(name.clone(), Value::Null);
+
+        let mut fields = Map::new();
+        for (field_name, field) in &validator.fields {
+            fields.insert(field_name.clone(), self.field_entry(field));
+            if let Some(nested) = &field.nested {
+                self.collect(&nested.validator);
+            }
+        }
+
+        self.validators
+            .insert(name, json!({"target": validator.target, "fields": fields}));
+    }
+
+    fn field_entry(&mut self, field: &FieldDescriptor) -> Value {
+        let mut server_only = field.custom_rules;
+        let rules: Vec<Value> = field
+            .rules
+            .iter()
+            .filter_map(|r| self.rule_entry(r, &mut server_only))
+            .collect();
+
+        let mut entry = json!({"rules": rules, "custom_rules": server_only});
+        if let Some(nested) = &field.nested {
+            let validator = self.name_of(&nested.validator);
+            entry["nested"] = json!({"shape": nested.shape, "validator": validator});
+        }
+        entry
+    }
+
+    /// `None` for rules a browser cannot run the same way (patterns with Rust-only syntax),
+    /// which are counted in `server_only` instead.
+    fn rule_entry(&mut self, rule: &RuleDescriptor, server_only: &mut usize) -> Option<Value> {
+        let mut params: Map<String, Value> = rule
+            .params
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+
+        if let Some(Value::String(source)) = params.get("pattern") {
+            let Some((pattern, flags)) = ecma_regexp(source) else {
+                *server_only += 1;
+                return None;
+            };
+            params.insert("pattern".into(), pattern.into());
+            params.insert("flags".into(), flags.into());
+        }
+
+        self.keys.extend(rule.keys.iter().copied());
+        let mut entry = json!({"rule": rule.name, "keys": rule.keys, "params": params});
+        if !rule.rules.is_empty() {
+            let inner: Vec<Value> = rule
+                .rules
+                .iter()
+                .filter_map(|r| self.rule_entry(r, server_only))
+                .collect();
+            entry["rules"] = Value::Array(inner);
+        }
+        Some(entry)
+    }
+}
+
+/// `(pattern, flags)` of a Rust regex source for `new RegExp(pattern, flags)`, or `None`
+/// when it uses syntax ECMA-262 lacks: `\A`, `\z`, `(?P<name>` or inline flags past the start.
+pub(crate) fn ecma_regexp(source: &str) -> Option<(String, String)> {
+    let (pattern, flags) = split_flags(source);
+    let rust_only = pattern.contains("(?P<")
+        || pattern.contains("\\A")
+        || pattern.contains("\\z")
+        || pattern
+            .match_indices("(?")
+            .any(|(i, _)| !pattern[i + 2..].starts_with([':', '<']));
+    (!rust_only).then_some((pattern, flags))
+}
+
+/// `(?i)^[a-z]+$` → (`^[a-z]+$`, `i`); flags JS does not know are left in the pattern.
+fn split_flags(pattern: &str) -> (String, String) {
+    let leading = pattern
+        .strip_prefix("(?")
+        .and_then(|rest| rest.split_once(')'))
+        .filter(|(flags, _)| !flags.is_empty() && flags.chars().all(|c| "ims".contains(c)));
+
+    match leading {
+        Some((flags, rest)) => (rest.to_string(), flags.to_string()),
+        None => (pattern.to_string(), String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::RulesBuilder;
+    use crate::core::contract::IValidate;
+    use crate::core::rules::nested::NestedField;
+    use crate::core::rules::string::max_length::MaxLength;
+    use crate::core::text::length::LengthUnit;
+    use async_trait::async_trait;
+    use regex::Regex;
+
+    type Error = std::io::Error;
+
+    struct Device {
+        mac: String,
+    }
+
+    struct Profile {
+        login: String,
+        tags: Vec<String>,
+        devices: Vec<Device>,
+    }
+
+    struct DeviceValidator;
+
+    #[async_trait]
+    impl IValidate<Device, Error> for DeviceValidator {
+        fn rules(&self, mut b: RulesBuilder<Device, Error>) -> RulesBuilder<Device, Error> {
+            b.field("mac", |d| &d.mac).mac_address().build();
+            b
+        }
+    }
+
+    struct ProfileValidator;
+
+    #[async_trait]
+    impl IValidate<Profile, Error> for ProfileValidator {
+        fn rules(&self, mut b: RulesBuilder<Profile, Error>) -> RulesBuilder<Profile, Error> {
+            b.field("login", |p| &p.login)
+                .not_empty()
+                .regex_match(Regex::new("(?i)^[a-z_]+$").unwrap())
+                .build();
+            b.field("tags", |p| &p.tags)
+                .each(MaxLength {
+                    max: 8,
+                    unit: LengthUnit::Chars,
+                })
+                .build();
+            b.field("devices", |p| &p.devices)
+                .nested(DeviceValidator)
+                .build();
+            b
+        }
+    }
+
+    #[test]
+    fn exports_field_rules() {
+        let manifest = ProfileValidator.describe().to_client_manifest();
+
+        assert_eq!(manifest["version"], 1);
+        assert_eq!(manifest["root"], "ProfileValidator");
+
+        let fields = &manifest["validators"]["ProfileValidator"]["fields"];
+        assert_eq!(
+            fields["login"]["rules"],
+            json!([
+                {"rule": "not_empty", "keys": ["validator.required"], "params": {"type": "string"}},
+                {
+                    "rule": "regex_match",
+                    "keys": ["validator.regex"],
+                    "params": {"pattern": "^[a-z_]+$", "flags": "i"}
+                }
+            ])
+        );
+        assert_eq!(
+            fields["tags"]["rules"][0],
+            json!({
+                "rule": "each",
+                "keys": ["validator.max_length"],
+                "params": {},
+                "rules": [{"rule": "max_length", "keys": ["validator.max_length"], "params": {"max": 8}}]
+            })
+        );
+    }
+
+    #[test]
+    fn exports_nested_validators_once() {
+        let manifest = ProfileValidator.describe().to_client_manifest();
+
+        assert_eq!(
+            manifest["validators"]["ProfileValidator"]["fields"]["devices"]["nested"],
+            json!({"shape": "list", "validator": "DeviceValidator"})
+        );
+        assert_eq!(
+            manifest["validators"]["DeviceValidator"]["target"],
+            "Device"
+        );
+        assert_eq!(
+            manifest["keys"],
+            json!([
+                "validator.invalid_mac",
+                "validator.max_length",
+                "validator.regex",
+                "validator.required"
+            ])
+        );
+    }
+
+    #[test]
+    fn output_is_stable() {
+        let first = serde_json::to_string(&ProfileValidator.describe().to_client_manifest());
+        let second = serde_json::to_string(&ProfileValidator.describe().to_client_manifest());
+        assert_eq!(first.unwrap(), second.unwrap());
+    }
+
+    #[test]
+    fn splits_leading_flags() {
+        assert_eq!(split_flags("(?i)^a$"), ("^a$".into(), "i".into()));
+        assert_eq!(split_flags("(?x)^a$"), ("(?x)^a$".into(), String::new()));
+        assert_eq!(split_flags("^a$"), ("^a$".into(), String::new()));
+
+        assert_eq!(ecma_regexp("(?ms)^a$"), Some(("^a$".into(), "ms".into())));
+        assert_eq!(ecma_regexp("(?x)^a$"), None);
+        assert_eq!(ecma_regexp(r"\Aa\z"), None);
+        assert_eq!(ecma_regexp("(?P<n>a)"), None);
+    }
+
+    #[test]
+    fn counts_rust_only_patterns_as_server_rules() {
+        struct LoginValidator;
+
+        #[async_trait]
+        impl IValidate<Device, Error> for LoginValidator {
+            fn rules(&self, mut b: RulesBuilder<Device, Error>) -> RulesBuilder<Device, Error> {
+                b.field("mac", |d| &d.mac)
+                    .regex_match(Regex::new(r"\A[a-f0-9:]+\z").unwrap())
+                    .max_length(17)
+                    .build();
+                b
+            }
+        }
+
+        let manifest = LoginValidator.describe().to_client_manifest();
+        let mac = &manifest["validators"]["LoginValidator"]["fields"]["mac"];
+        assert_eq!(mac["rules"].as_array().unwrap().len(), 1);
+        assert_eq!(mac["rules"][0]["rule"], "max_length");
+        assert_eq!(mac["custom_rules"], 1);
+        assert_eq!(manifest["keys"], json!(["validator.max_length"]));
+    }
+
+    #[test]
+    fn keys_same_named_validators_by_type() {
+        mod other {
+            use super::*;
+
+            pub struct DeviceValidator;
+
+            #[async_trait]
+            impl IValidate<Device, Error> for DeviceValidator {
+                fn rules(&self, mut b: RulesBuilder<Device, Error>) -> RulesBuilder<Device, Error> {
+                    b.field("mac", |d| &d.mac).max_length(17).build();
+                    b
+                }
+            }
+        }
+
+        struct Pair {
+            first: Device,
+            second: Device,
+        }
+
+        struct PairValidator;
+
+        #[async_trait]
+        impl IValidate<Pair, Error> for PairValidator {
+            fn rules(&self, mut b: RulesBuilder<Pair, Error>) -> RulesBuilder<Pair, Error> {
+                b.field("first", |p| &p.first)
+                    .nested(DeviceValidator)
+                    .build();
+                b.field("second", |p| &p.second)
+                    .nested(other::DeviceValidator)
+                    .build();
+                b
+            }
+        }
+
+        let manifest = PairValidator.describe().to_client_manifest();
+        let validators = manifest["validators"].as_object().unwrap();
+        let fields = &validators["PairValidator"]["fields"];
+        let first = fields["first"]["nested"]["validator"].as_str().unwrap();
+        let second = fields["second"]["nested"]["validator"].as_str().unwrap();
+
+        assert_eq!(manifest["root"], "PairValidator");
+        assert_eq!(validators.len(), 3);
+        assert!(first.ends_with(".tests.DeviceValidator"));
+        assert!(second.ends_with(".other.DeviceValidator"));
+        assert_eq!(
+            validators[second]["fields"]["mac"]["rules"][0]["rule"],
+            "max_length"
+        );
+    }
+
+    #[cfg(feature = "i18n-localization")]
+    #[test]
+    fn includes_message_templates() {
+        let manifest = ProfileValidator
+            .describe()
+            .to_client_manifest_with_messages(&["en", "uk"]);
+
+        assert_eq!(
+            manifest["messages"]["en"]["validator.max_length"],
+            "Maximum length is %{max} characters"
+        );
+        assert!(manifest["messages"]["uk"]["validator.required"].is_string());
+    }
+}
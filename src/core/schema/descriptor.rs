@@ -0,0 +1,178 @@
+use serde::Serialize;
+use serde_json::Value;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// Self-description of a built-in rule: its name, the error keys it can produce
+/// and its parameters. Composite rules (`each`, `spec`) list their inner rules.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RuleDescriptor {
+    pub name: &'static str,
+    pub keys: &'static [&'static str],
+    pub params: BTreeMap<&'static str, Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RuleDescriptor>,
+}
+
+impl RuleDescriptor {
+    pub fn new(name: &'static str, keys: &'static [&'static str]) -> Self {
+        Self {
+            name,
+            keys,
+            params: BTreeMap::new(),
+            rules: vec![],
+        }
+    }
+
+    pub fn param(mut self, name: &'static str, value: impl Into<Value>) -> Self {
+        self.params.insert(name, value.into());
+        self
+    }
+
+    /// Numeric rules are generic over `ToString`, so bounds go through their text form.
+    pub fn number(self, name: &'static str, value: &impl ToString) -> Self {
+        let text = value.to_string();
+        let value = match serde_json::from_str::<Value>(&text) {
+            Ok(number @ Value::Number(_)) => number,
+            _ => Value::String(text),
+        };
+        self.param(name, value)
+    }
+
+    pub fn with_rules(mut self, rules: Vec<RuleDescriptor>) -> Self {
+        self.rules = rules;
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NestedShape {
+    One,
+    Optional,
+    List,
+    Map,
+    /// A JSON or YAML document stored in a string field.
+    Document,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NestedDescriptor {
+    pub shape: NestedShape,
+    pub validator: ValidatorDescriptor,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct FieldDescriptor {
+    pub rules: Vec<RuleDescriptor>,
+    /// Custom (sync/async) rules and rules that do not describe themselves.
+    pub custom_rules: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nested: Option<NestedDescriptor>,
+}
+
+impl FieldDescriptor {
+    pub fn has_rule(&self, name: &str) -> bool {
+        self.rules.iter().any(|r| r.name == name)
+    }
+}
+
+/// Rules of one validator, as collected from its `RulesBuilder`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ValidatorDescriptor {
+    /// Short type name of the validator, e.g. `AddressValidator`.
+    pub name: String,
+    /// Full type name of the validator, e.g. `app::dto::AddressValidator`.
+    #[serde(skip)]
+    pub type_name: String,
+    /// Short type name of the validated DTO.
+    pub target: String,
+    pub fields: BTreeMap<String, FieldDescriptor>,
+    /// The validator is already being described further up the tree
+    /// (self-referencing DTOs); `fields` is left empty.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub recursive: bool,
+    /// The JSON Schema of schema-backed validators (`JsonSchemaValidator`), which have no fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<Value>,
+}
+
+thread_local! {
+    static DESCRIBING: RefCell<Vec<&'static str>> = const { RefCell::new(vec![]) };
+}
+
+/// Runs `describe` unless `validator` is already being described on this thread.
+pub(crate) fn describe_validator(
+    validator: &'static str,
+    target: &'static str,
+    describe: impl FnOnce() -> BTreeMap<String, FieldDescriptor>,
+) -> ValidatorDescriptor {
+    let mut descriptor = ValidatorDescriptor {
+        name: short_type_name(validator),
+        type_name: validator.to_string(),
+        target: short_type_name(target),
+        ..Default::default()
+    };
+
+    if DESCRIBING.with(|stack| stack.borrow().contains(&validator)) {
+        descriptor.recursive = true;
+        return descriptor;
+    }
+
+    DESCRIBING.with(|stack| stack.borrow_mut().push(validator));
+    descriptor.fields = describe();
+    DESCRIBING.with(|stack| stack.borrow_mut().pop());
+
+    descriptor
+}
+
+fn short_type_name(full: &str) -> String {
+    let base = full.split('<').next().unwrap_or(full);
+    base.rsplit("::").next().unwrap_or(base).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_keep_their_json_type() {
+        let rule = RuleDescriptor::new("range", &["validator.range"])
+            .number("min", &1)
+            .number("max", &2.5)
+            .number("nan", &f64::NAN);
+
+        assert_eq!(rule.params["min"], serde_json::json!(1));
+        assert_eq!(rule.params["max"], serde_json::json!(2.5));
+        assert_eq!(rule.params["nan"], serde_json::json!("NaN"));
+    }
+
+    #[test]
+    fn shortens_type_names() {
+        assert_eq!(short_type_name("app::dto::UserValidator"), "UserValidator");
+        assert_eq!(short_type_name("app::Wrapper<app::Inner>"), "Wrapper");
+        assert_eq!(short_type_name("Plain"), "Plain");
+    }
+
+    #[test]
+    fn marks_recursive_descriptions() {
+        let outer = describe_validator("a::Tree", "a::Node", || {
+            let inner = describe_validator("a::Tree", "a::Node", BTreeMap::new);
+            assert!(inner.recursive);
+            BTreeMap::from([(
+                "children".to_string(),
+                FieldDescriptor {
+                    nested: Some(NestedDescriptor {
+                        shape: NestedShape::List,
+                        validator: inner,
+                    }),
+                    ..Default::default()
+                },
+            )])
+        });
+
+        assert!(!outer.recursive);
+        assert_eq!(outer.name, "Tree");
+        assert_eq!(outer.target, "Node");
+    }
+}
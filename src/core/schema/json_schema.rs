@@ -0,0 +1,709 @@
+use crate::core::schema::client_manifest::ecma_regexp;
+use crate::core::schema::descriptor::{
+    FieldDescriptor, NestedShape, RuleDescriptor, ValidatorDescriptor,
+};
+use serde_json::{Map, Value, json};
+use std::collections::{BTreeSet, HashMap};
+
+const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl ValidatorDescriptor {
+    /// Draft 2020-12 JSON Schema for the DTO. Nested validators are emitted
+    /// once under `$defs` and referenced via `$ref`, keyed by validator name, or
+    /// by the full type name when several validators share a name (same name in
+    /// different modules, generic validators).
+    ///
+    /// Rules without a JSON Schema counterpart (`cidr`, `password_strength`,
+    /// custom rules, ...) are left out.
+    pub fn to_json_schema(&self) -> Value {
+        let mut defs = Defs::new(self);
+        let mut schema = object_schema(self, &mut defs);
+
+        schema.insert("$schema".into(), DRAFT_2020_12.into());
+        if !defs.schemas.is_empty() {
+            schema.insert("$defs".into(), Value::Object(defs.schemas));
+        }
+
+        Value::Object(schema)
+    }
+}
+
+struct Defs {
+    /// Full validator type name → `$defs` key.
+    keys: HashMap<String, String>,
+    schemas: Map<String, Value>,
+}
+
+impl Defs {
+    fn new(root: &ValidatorDescriptor) -> Self {
+        let mut nested = vec![];
+        collect_nested(root, &mut nested);
+        Self {
+            keys: validator_keys(nested),
+            schemas: Map::new(),
+        }
+    }
+}
+
+/// Full validator type name → key of the validator in `$defs` or a client manifest: its
+/// name, or the full type name when several of `validators` share the name.
+pub(crate) fn validator_keys<'a>(
+    validators: impl IntoIterator<Item = &'a ValidatorDescriptor>,
+) -> HashMap<String, String> {
+    let mut names: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for validator in validators {
+        names
+            .entry(&validator.name)
+            .or_default()
+            .insert(&validator.type_name);
+    }
+
+    names
+        .into_iter()
+        .flat_map(|(name, type_names)| {
+            let shared = type_names.len() > 1;
+            type_names.into_iter().map(move |type_name| {
+                let key = if shared {
+                    def_key(type_name)
+                } else {
+                    name.to_string()
+                };
+                (type_name.to_string(), key)
+            })
+        })
+        .collect()
+}
+
+/// The validators nested below `validator`, at any depth.
+pub(crate) fn collect_nested<'a>(
+    validator: &'a ValidatorDescriptor,
+    nested: &mut Vec<&'a ValidatorDescriptor>,
+) {
+    for field in validator.fields.values().filter_map(|f| f.nested.as_ref()) {
+        nested.push(&field.validator);
+        collect_nested(&field.validator, nested);
+    }
+}
+
+/// `app::Wrapper<app::Inner>` → `app.Wrapper_app.Inner_`
+fn def_key(type_name: &str) -> String {
+    type_name
+        .replace("::", ".")
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+fn object_schema(validator: &ValidatorDescriptor, defs: &mut Defs) -> Map<String, Value> {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    for (name, field) in &validator.fields {
+        if is_required(field) {
+            required.push(Value::String(name.clone()));
+        }
+        properties.insert(name.clone(), Value::Object(field_schema(field, defs)));
+    }
+
+    let mut schema = Map::new();
+    schema.insert("title".into(), validator.target.clone().into());
+    schema.insert("type".into(), "object".into());
+    schema.insert("properties".into(), Value::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".into(), Value::Array(required));
+    }
+    schema
+}
+
+fn field_schema(field: &FieldDescriptor, defs: &mut Defs) -> Map<String, Value> {
+    let mut schema = field_keywords(field);
+
+    if let Some(nested) = &field.nested {
+        let mut reference = || define(&nested.validator, defs);
+        match nested.shape {
+            NestedShape::One => {
+                schema.insert("$ref".into(), reference()["$ref"].clone());
+            }
+            NestedShape::Optional => {
+                schema.insert("anyOf".into(), json!([reference(), {"type": "null"}]));
+            }
+            NestedShape::List => {
+                schema.insert("type".into(), "array".into());
+                schema.insert("items".into(), reference());
+            }
+            NestedShape::Map => {
+                schema.insert("type".into(), "object".into());
+                schema.insert("additionalProperties".into(), reference());
+            }
+            NestedShape::Document => {
+                let content = nested.validator.schema.clone();
+                schema.insert("type".into(), "string".into());
+                schema.insert("contentSchema".into(), content.unwrap_or_else(reference));
+            }
+        }
+    }
+
+    schema
+}
+
+fn define(validator: &ValidatorDescriptor, defs: &mut Defs) -> Value {
+    let key = defs.keys.get(&validator.type_name).cloned();
+    let key = key.unwrap_or_else(|| validator.name.clone());
+
+    if !validator.recursive && !defs.schemas.contains_key(&key) {
+        let schema = object_schema(validator, defs);
+        defs.schemas.insert(key.clone(), Value::Object(schema));
+    }
+    json!({"$ref": format!("#/$defs/{key}")})
+}
+
+/// JSON Schema keywords for the field's own rules (nested validators not included).
+pub(crate) fn field_keywords(field: &FieldDescriptor) -> Map<String, Value> {
+    let mut schema = Map::new();
+    apply_rules(&mut schema, &field.rules);
+    schema
+}
+
+pub(crate) fn is_required(field: &FieldDescriptor) -> bool {
+    field.has_rule("not_empty") || field.has_rule("not_none")
+}
+
+fn apply_rules(schema: &mut Map<String, Value>, rules: &[RuleDescriptor]) {
+    for rule in rules {
+        let param = |name: &str| rule.params.get(name).cloned().unwrap_or(Value::Null);
+
+        match rule.name {
+            "not_empty" => match rule.params.get("type").and_then(Value::as_str) {
+                Some("string") => raise(schema, "minLength", json!(1)),
+                Some("array") => raise(schema, "minItems", json!(1)),
+                Some("object") => raise(schema, "minProperties", json!(1)),
+                _ => {}
+            },
+            // `minLength`/`maxLength` count code points: other units have no counterpart.
+            "min_length" if !rule.params.contains_key("unit") => {
+                raise(schema, "minLength", param("min"))
+            }
+            "max_length" if !rule.params.contains_key("unit") => {
+                lower(schema, "maxLength", param("max"))
+            }
+            "min_items" => raise(schema, "minItems", param("min")),
+            "max_items" => lower(schema, "maxItems", param("max")),
+            "exact_items" => {
+                raise(schema, "minItems", param("expected"));
+                lower(schema, "maxItems", param("expected"));
+            }
+            "min_value" => raise(schema, "minimum", param("min")),
+            "max_value" => lower(schema, "maximum", param("max")),
+            "greater_than" => raise(schema, "exclusiveMinimum", param("min")),
+            "less_than" => lower(schema, "exclusiveMaximum", param("max")),
+            "range" => {
+                raise(schema, "minimum", param("min"));
+                lower(schema, "maximum", param("max"));
+            }
+            "multiple_of" => {
+                schema.insert("multipleOf".into(), param("step"));
+            }
+            "positive" => raise(schema, "exclusiveMinimum", json!(0)),
+            "positive_or_zero" => raise(schema, "minimum", json!(0)),
+            "negative" => lower(schema, "exclusiveMaximum", json!(0)),
+            "negative_or_zero" => lower(schema, "maximum", json!(0)),
+            "one_of" => {
+                schema.insert("enum".into(), param("allowed"));
+            }
+            "regex_match" | "mac_address" | "bic" | "iso4217_currency" | "hex" | "semver"
+            | "slug" | "hex_color" | "ulid" => add_pattern(schema, param("pattern")),
+            // Case-insensitive country codes have no pattern.
+            "iso3166_alpha2" | "iso3166_alpha3" => {
+                if let Some(pattern) = rule.params.get("pattern") {
+                    add_pattern(schema, pattern.clone());
+                }
+            }
+            "password_policy" => {
+                raise(schema, "minLength", param("min_length"));
+                lower(schema, "maxLength", param("max_length"));
+            }
+            "email" => set_format(schema, "email"),
+            "url" => {
+                set_format(schema, "uri");
+                lower(schema, "maxLength", param("max_length"));
+            }
+            "uuid" | "uuid_version" => set_format(schema, "uuid"),
+            "hostname" => set_format(schema, "hostname"),
+            "ipv4" => set_format(schema, "ipv4"),
+            "ipv6" => set_format(schema, "ipv6"),
+            "date_format" => {
+                if let Some(format @ ("date" | "time" | "date-time")) =
+                    rule.params.get("format").and_then(Value::as_str)
+                {
+                    set_format(schema, format);
+                }
+            }
+            "base64" if rule.params.get("url_safe") == Some(&Value::Bool(false)) => {
+                schema.insert("contentEncoding".into(), "base64".into());
+            }
+            "jwt" => {
+                schema.insert("contentMediaType".into(), "application/jwt".into());
+            }
+            "json" => {
+                schema.insert("contentMediaType".into(), "application/json".into());
+            }
+            "yaml" => {
+                schema.insert("contentMediaType".into(), "application/yaml".into());
+            }
+            "xml" => {
+                schema.insert("contentMediaType".into(), "application/xml".into());
+            }
+            "each" => {
+                let items = schema
+                    .entry("items")
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(items) = items {
+                    apply_rules(items, &rule.rules);
+                }
+            }
+            "spec" => apply_rules(schema, &rule.rules),
+            _ => {}
+        }
+    }
+}
+
+fn set_format(schema: &mut Map<String, Value>, format: &str) {
+    schema.insert("format".into(), format.into());
+}
+
+/// A second pattern on the same field goes to `allOf`, so both still apply.
+/// Patterns without an ECMA-262 equivalent are left out.
+fn add_pattern(schema: &mut Map<String, Value>, pattern: Value) {
+    let Some(pattern) = pattern.as_str().and_then(ecma_pattern) else {
+        return;
+    };
+    let pattern = Value::String(pattern);
+
+    if !schema.contains_key("pattern") {
+        schema.insert("pattern".into(), pattern);
+        return;
+    }
+
+    let all_of = schema.entry("allOf").or_insert_with(|| json!([]));
+    if let Value::Array(all_of) = all_of {
+        all_of.push(json!({ "pattern": pattern }));
+    }
+}
+
+/// ECMA-262 form of a Rust regex source. `pattern` has no flags, so a leading `(?i)` is
+/// spelled out as both letter cases (`[a-f]` → `[a-fA-F]`); other flags have no equivalent.
+fn ecma_pattern(source: &str) -> Option<String> {
+    let (pattern, flags) = ecma_regexp(source)?;
+    match flags.as_str() {
+        "" => Some(pattern),
+        "i" => Some(fold_case(&pattern)),
+        _ => None,
+    }
+}
+
+fn fold_case(pattern: &str) -> String {
+    let mut folded = String::with_capacity(pattern.len() * 2);
+    let mut chars = pattern.chars().peekable();
+    let mut in_class = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            // escapes keep their letters: `\d`, `\p{Lu}`, `\x4f`, `\u{e9}`
+            '\\' => {
+                folded.push(c);
+                let escape = chars.next();
+                folded.extend(escape);
+                match escape {
+                    Some('p' | 'P' | 'x' | 'u') if chars.peek() == Some(&'{') => {
+                        while let Some(c) = chars.next_if(|&c| c != '}') {
+                            folded.push(c);
+                        }
+                        folded.extend(chars.next());
+                    }
+                    Some('x') => folded.extend(chars.by_ref().take(2)),
+                    _ => {}
+                }
+            }
+            '[' if !in_class => {
+                in_class = true;
+                folded.push(c);
+            }
+            ']' if in_class => {
+                in_class = false;
+                folded.push(c);
+            }
+            c if c.is_ascii_alphabetic() && in_class => {
+                let mut range = String::from(c);
+                if chars.peek() == Some(&'-') {
+                    let mut ahead = chars.clone();
+                    ahead.next();
+                    if let Some(end) = ahead.next().filter(char::is_ascii_alphabetic) {
+                        range.extend(['-', end]);
+                        chars = ahead;
+                    }
+                }
+                folded.push_str(&range);
+                folded.extend(range.chars().map(swap_case));
+            }
+            c if c.is_ascii_alphabetic() => {
+                folded.extend(['[', c, swap_case(c), ']']);
+            }
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+fn swap_case(c: char) -> char {
+    if c.is_ascii_lowercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c.to_ascii_lowercase()
+    }
+}
+
+/// Lower bounds keep the strictest (largest) value when several rules set them.
+fn raise(schema: &mut Map<String, Value>, keyword: &str, value: Value) {
+    bound(schema, keyword, value, |new, old| new > old);
+}
+
+/// Upper bounds keep the strictest (smallest) value when several rules set them.
+fn lower(schema: &mut Map<String, Value>, keyword: &str, value: Value) {
+    bound(schema, keyword, value, |new, old| new < old);
+}
+
+fn bound(
+    schema: &mut Map<String, Value>,
+    keyword: &str,
+    value: Value,
+    stricter: fn(f64, f64) -> bool,
+) {
+    let Some(new) = value.as_f64() else {
+        return;
+    };
+    let replace = match schema.get(keyword).and_then(Value::as_f64) {
+        Some(old) => stricter(new, old),
+        None => true,
+    };
+    if replace {
+        schema.insert(keyword.into(), value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ecma_pattern;
+    use crate::core::builder::RulesBuilder;
+    use crate::core::contract::IValidate;
+    use crate::core::rules::nested::NestedField;
+    use crate::core::rules::string::max_length::MaxLength;
+    use crate::core::text::length::LengthUnit;
+    use async_trait::async_trait;
+    use regex::Regex;
+    use serde_json::json;
+    use std::collections::{HashMap, HashSet};
+
+    type Error = std::io::Error;
+
+    struct Address {
+        city: String,
+    }
+
+    #[derive(Debug)]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    struct User {
+        email: String,
+        nickname: Option<String>,
+        age: u8,
+        score: f64,
+        role: String,
+        tags: Vec<String>,
+        home: Address,
+        work: Option<Address>,
+        offices: HashMap<String, Address>,
+        tree: Node,
+    }
+
+    struct AddressValidator;
+
+    #[async_trait]
+    impl IValidate<Address, Error> for AddressValidator {
+        fn rules(&self, mut b: RulesBuilder<Address, Error>) -> RulesBuilder<Address, Error> {
+            b.field("city", |a| &a.city)
+                .not_empty()
+                .max_length(40)
+                .build();
+            b
+        }
+    }
+
+    struct NodeValidator;
+
+    #[async_trait]
+    impl IValidate<Node, Error> for NodeValidator {
+        fn rules(&self, mut b: RulesBuilder<Node, Error>) -> RulesBuilder<Node, Error> {
+            b.field("children", |n| &n.children)
+                .max_items(10)
+                .nested(NodeValidator)
+                .build();
+            b
+        }
+    }
+
+    struct UserValidator;
+
+    #[async_trait]
+    impl IValidate<User, Error> for UserValidator {
+        fn rules(&self, mut b: RulesBuilder<User, Error>) -> RulesBuilder<User, Error> {
+            b.field("email", |u| &u.email)
+                .not_empty()
+                .email()
+                .max_length(64)
+                .max_length(128)
+                .build();
+            b.field("nickname", |u| &u.nickname)
+                .min_length(3)
+                .max_length_in(255, LengthUnit::Bytes)
+                .regex_match(Regex::new("^[a-z]+$").unwrap())
+                .build();
+            b.field("age", |u| &u.age).range(18, 120).build();
+            b.field("score", |u| &u.score)
+                .positive()
+                .less_than(1.5)
+                .multiple_of(0.25)
+                .build();
+            b.field("role", |u| &u.role)
+                .one_of(HashSet::from(["user".into(), "admin".into()]))
+                .build();
+            b.field("tags", |u| &u.tags)
+                .min_items(1)
+                .each(MaxLength {
+                    max: 16,
+                    unit: LengthUnit::Chars,
+                })
+                .build();
+            b.field("home", |u| &u.home)
+                .nested(AddressValidator)
+                .build();
+            b.field("work", |u| &u.work)
+                .nested(AddressValidator)
+                .build();
+            b.field("offices", |u| &u.offices)
+                .nested(AddressValidator)
+                .build();
+            b.field("tree", |u| &u.tree).nested(NodeValidator).build();
+            b
+        }
+    }
+
+    #[test]
+    fn emits_draft_2020_12_schema() {
+        let schema = UserValidator.describe().to_json_schema();
+
+        assert_eq!(
+            schema["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
+        assert_eq!(schema["title"], "User");
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"], json!(["email"]));
+
+        let props = &schema["properties"];
+        assert_eq!(
+            props["email"],
+            json!({"format": "email", "minLength": 1, "maxLength": 64})
+        );
+        assert_eq!(
+            props["nickname"],
+            json!({"minLength": 3, "pattern": "^[a-z]+$"})
+        );
+        assert_eq!(props["age"], json!({"minimum": 18, "maximum": 120}));
+        assert_eq!(
+            props["score"],
+            json!({"exclusiveMinimum": 0, "exclusiveMaximum": 1.5, "multipleOf": 0.25})
+        );
+        assert_eq!(props["role"], json!({"enum": ["admin", "user"]}));
+        assert_eq!(
+            props["tags"],
+            json!({"minItems": 1, "items": {"maxLength": 16}})
+        );
+    }
+
+    #[test]
+    fn emits_ecma_patterns() {
+        assert_eq!(ecma_pattern("^[a-z]+$"), Some("^[a-z]+$".into()));
+        assert_eq!(
+            ecma_pattern("(?i)^[0-9A-F]{2}x$"),
+            Some("^[0-9A-Fa-f]{2}[xX]$".into())
+        );
+        assert_eq!(
+            ecma_pattern(r"(?i)^\p{Lu}\x4f\d$"),
+            Some(r"^\p{Lu}\x4f\d$".into())
+        );
+        assert_eq!(ecma_pattern("(?:ab)+"), Some("(?:ab)+".into()));
+        assert_eq!(ecma_pattern("(?m)^a$"), None);
+        assert_eq!(ecma_pattern("^a(?i)b$"), None);
+        assert_eq!(ecma_pattern(r"\Aa\z"), None);
+        assert_eq!(ecma_pattern("(?P<n>a)"), None);
+
+        struct Device {
+            mac: String,
+        }
+
+        struct DeviceValidator;
+
+        #[async_trait]
+        impl IValidate<Device, Error> for DeviceValidator {
+            fn rules(&self, mut b: RulesBuilder<Device, Error>) -> RulesBuilder<Device, Error> {
+                b.field("mac", |d| &d.mac)
+                    .mac_address()
+                    .regex_match(Regex::new("(?s)^.+$").unwrap())
+                    .build();
+                b
+            }
+        }
+
+        let schema = DeviceValidator.describe().to_json_schema();
+        assert_eq!(
+            schema["properties"]["mac"],
+            json!({"pattern": "^([0-9A-Fa-f]{2}[:-]){5}([0-9A-Fa-f]{2})$|^[0-9A-Fa-f]{12}$"})
+        );
+    }
+
+    #[test]
+    fn references_nested_validators() {
+        let schema = UserValidator.describe().to_json_schema();
+        let props = &schema["properties"];
+        let address = json!({"$ref": "#/$defs/AddressValidator"});
+
+        assert_eq!(props["home"], address);
+        assert_eq!(props["work"], json!({"anyOf": [address, {"type": "null"}]}));
+        assert_eq!(
+            props["offices"],
+            json!({"type": "object", "additionalProperties": address})
+        );
+
+        assert_eq!(
+            schema["$defs"]["AddressValidator"],
+            json!({
+                "title": "Address",
+                "type": "object",
+                "properties": {"city": {"minLength": 1, "maxLength": 40}},
+                "required": ["city"]
+            })
+        );
+    }
+
+    #[test]
+    fn keys_same_named_validators_by_type() {
+        mod billing {
+            use super::*;
+
+            pub struct AddressValidator;
+
+            #[async_trait]
+            impl IValidate<Address, Error> for AddressValidator {
+                fn rules(
+                    &self,
+                    mut b: RulesBuilder<Address, Error>,
+                ) -> RulesBuilder<Address, Error> {
+                    b.field("city", |a| &a.city).max_length(10).build();
+                    b
+                }
+            }
+        }
+
+        struct Orders {
+            home: Address,
+            billing: Address,
+        }
+
+        struct OrdersValidator;
+
+        #[async_trait]
+        impl IValidate<Orders, Error> for OrdersValidator {
+            fn rules(&self, mut b: RulesBuilder<Orders, Error>) -> RulesBuilder<Orders, Error> {
+                b.field("home", |o| &o.home)
+                    .nested(AddressValidator)
+                    .build();
+                b.field("billing", |o| &o.billing)
+                    .nested(billing::AddressValidator)
+                    .build();
+                b
+            }
+        }
+
+        let schema = OrdersValidator.describe().to_json_schema();
+        let defs = schema["$defs"].as_object().unwrap();
+        let reference = |field: &str| {
+            let reference = schema["properties"][field]["$ref"].as_str().unwrap();
+            reference.strip_prefix("#/$defs/").unwrap().to_string()
+        };
+
+        assert_eq!(defs.len(), 2);
+        assert!(reference("home").ends_with(".tests.AddressValidator"));
+        assert!(reference("billing").ends_with(".billing.AddressValidator"));
+        assert_eq!(
+            defs[&reference("billing")]["properties"]["city"],
+            json!({"maxLength": 10})
+        );
+    }
+
+    #[test]
+    fn handles_recursive_validators() {
+        let schema = UserValidator.describe().to_json_schema();
+
+        assert_eq!(
+            schema["properties"]["tree"],
+            json!({"$ref": "#/$defs/NodeValidator"})
+        );
+        assert_eq!(
+            schema["$defs"]["NodeValidator"]["properties"]["children"],
+            json!({
+                "type": "array",
+                "maxItems": 10,
+                "items": {"$ref": "#/$defs/NodeValidator"}
+            })
+        );
+    }
+
+    #[test]
+    fn counts_custom_rules() {
+        use crate::core::contract::IValidatorRuleCustom;
+        use crate::core::errors::ValidationError;
+
+        struct AlwaysOk;
+
+        impl IValidatorRuleCustom<String, Error> for AlwaysOk {
+            fn validate(&self, _: &String) -> Result<Option<ValidationError>, Error> {
+                Ok(None)
+            }
+        }
+
+        struct CustomValidator;
+
+        #[async_trait]
+        impl IValidate<Address, Error> for CustomValidator {
+            fn rules(&self, mut b: RulesBuilder<Address, Error>) -> RulesBuilder<Address, Error> {
+                b.field("city", |a| &a.city)
+                    .custom(AlwaysOk)
+                    .trimmed()
+                    .build();
+                b
+            }
+        }
+
+        let descriptor = CustomValidator.describe();
+        let city = &descriptor.fields["city"];
+        assert_eq!(city.custom_rules, 1);
+        assert_eq!(city.rules.len(), 1);
+        assert_eq!(city.rules[0].name, "trimmed");
+        assert_eq!(city.rules[0].keys, ["validator.trimmed"]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod client_manifest;
+pub mod descriptor;
+pub mod json_schema;
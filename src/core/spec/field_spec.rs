@@ -0,0 +1,1217 @@
+use crate::core::codes::CodeCase;
+use crate::core::context::ValidationContext;
+use crate::core::contract::IValidatorRule;
+use crate::core::errors::{RuleFailure, ValidationError};
+#[cfg(feature = "phone")]
+use crate::core::features::phone::{PhoneOptions, PhoneType};
+use crate::core::finance::card::CardBrand;
+use crate::core::formats::base64_options::{Base64Options, Base64Padding};
+use crate::core::formats::document_options::{DocumentOptions, DocumentType};
+use crate::core::identifiers::national::BUILT_IN;
+use crate::core::net::email_options::EmailOptions;
+use crate::core::net::network::IpNetwork;
+use crate::core::net::url_options::UrlOptions;
+use crate::core::password::policy::PasswordPolicy;
+use crate::core::password::strength::StrengthLevel;
+use crate::core::rules::common::not_empty::NotEmpty;
+use crate::core::rules::net::ip::{Ip, IpVersion};
+use crate::core::rules::net::ip_in_cidr::IpInCidr;
+use crate::core::rules::net::port::Port;
+use crate::core::rules::net::public_ip::PublicIp;
+use crate::core::rules::net::socket_addr::SocketAddress;
+use crate::core::rules::numeric::finite::Finite;
+use crate::core::rules::numeric::greater_than::GreaterThan;
+use crate::core::rules::numeric::less_than::LessThan;
+use crate::core::rules::numeric::max_decimal_places::MaxDecimalPlaces;
+use crate::core::rules::numeric::max_value::MaxValue;
+use crate::core::rules::numeric::min_value::MinValue;
+use crate::core::rules::numeric::multiple_of::MultipleOf;
+use crate::core::rules::numeric::negative::Negative;
+use crate::core::rules::numeric::negative_or_zero::NegativeOrZero;
+use crate::core::rules::numeric::positive::Positive;
+use crate::core::rules::numeric::positive_or_zero::PositiveOrZero;
+use crate::core::rules::numeric::precision::Precision;
+use crate::core::rules::numeric::range::Range;
+use crate::core::rules::slice::exact_items::ExactItems;
+use crate::core::rules::slice::max_items::MaxItems;
+use crate::core::rules::slice::min_items::MinItems;
+use crate::core::rules::string::base64::Base64;
+use crate::core::rules::string::bcp47_locale::Bcp47Locale;
+use crate::core::rules::string::bic::Bic;
+use crate::core::rules::string::cidr::Cidr;
+use crate::core::rules::string::credit_card::CreditCard;
+use crate::core::rules::string::css_color::CssColor;
+use crate::core::rules::string::decimal_precision::DecimalPrecision;
+use crate::core::rules::string::ean::Ean;
+use crate::core::rules::string::email::Email;
+use crate::core::rules::string::email_with_options::EmailWithOptions;
+use crate::core::rules::string::hex::Hex;
+use crate::core::rules::string::hex_color::HexColor;
+use crate::core::rules::string::hostname::Hostname;
+use crate::core::rules::string::iana_timezone::IanaTimezone;
+use crate::core::rules::string::iban::Iban;
+use crate::core::rules::string::isbn::Isbn;
+use crate::core::rules::string::iso639_language::Iso639Language;
+use crate::core::rules::string::iso3166_country::{CountryFormat, Iso3166Country};
+use crate::core::rules::string::iso4217_currency::Iso4217Currency;
+use crate::core::rules::string::issn::Issn;
+use crate::core::rules::string::json::Json;
+use crate::core::rules::string::json_with_options::JsonWithOptions;
+use crate::core::rules::string::jwt::Jwt;
+use crate::core::rules::string::lowercased::Lowercased;
+use crate::core::rules::string::mac_address::MacAddress;
+use crate::core::rules::string::max_length::MaxLength;
+use crate::core::rules::string::min_length::MinLength;
+use crate::core::rules::string::national_id::NationalId;
+use crate::core::rules::string::no_bidi_controls::NoBidiControls;
+use crate::core::rules::string::no_zero_width::NoZeroWidth;
+use crate::core::rules::string::not_confusable::NotConfusable;
+use crate::core::rules::string::one_of::OneOf;
+use crate::core::rules::string::password_policy::PasswordPolicyRule;
+use crate::core::rules::string::password_strength::PasswordStrength;
+#[cfg(feature = "phone")]
+use crate::core::rules::string::phone::Phone;
+use crate::core::rules::string::regex_match::RegexMatch;
+use crate::core::rules::string::semver::Semver;
+use crate::core::rules::string::single_script::SingleScript;
+use crate::core::rules::string::slug::Slug;
+use crate::core::rules::string::trimmed::Trimmed;
+use crate::core::rules::string::ulid::Ulid;
+use crate::core::rules::string::unicode_normalized::UnicodeNormalized;
+use crate::core::rules::string::upc::Upc;
+use crate::core::rules::string::uppercased::Uppercased;
+use crate::core::rules::string::url::UrlValid;
+use crate::core::rules::string::url_with_options::UrlWithOptions;
+use crate::core::rules::string::uuid_valid::UuidValid;
+use crate::core::rules::string::uuid_version::{UuidVersion, UuidVersionValidator};
+use crate::core::rules::string::vat::Vat;
+use crate::core::rules::string::word_count::WordCount;
+use crate::core::rules::string::xml::Xml;
+use crate::core::rules::string::yaml::Yaml;
+use crate::core::rules::temporal::after::After;
+use crate::core::rules::temporal::before::Before;
+use crate::core::rules::temporal::business_day::BusinessDay;
+use crate::core::rules::temporal::date_format::DateFormatRule;
+use crate::core::rules::temporal::in_future::InFuture;
+use crate::core::rules::temporal::in_past::InPast;
+use crate::core::rules::temporal::max_age::MaxAge;
+use crate::core::rules::temporal::min_age::MinAge;
+use crate::core::schema::descriptor::RuleDescriptor;
+use crate::core::temporal::bound::TimeBound;
+use crate::core::temporal::calendar::BusinessCalendar;
+use crate::core::temporal::format::{DateFormat, is_valid_pattern};
+use crate::core::text::length::{LengthUnit, WordBoundary};
+use crate::core::text::unicode::NormalizationForm;
+use crate::core::valida_error::ValidaError;
+use crate::core::value_validator::json_node::JsonNode;
+use regex::Regex;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type SpecRule = Box<dyn IValidatorRule<JsonNode>>;
+
+/// Compiled rules of one field from a rule spec.
+///
+/// Applies to any `Serialize` field: the value is converted to JSON and checked with the
+/// same built-in rules a `ValueValidator` uses. Cheap to clone.
+#[derive(Clone)]
+pub struct FieldSpec {
+    rules: Arc<Vec<SpecRule>>,
+}
+
+impl FieldSpec {
+    pub(crate) fn compile(field: &str, entries: &Value) -> Result<Self, ValidaError> {
+        let entries = entries
+            .as_array()
+            .ok_or_else(|| spec_error(field, None, "expected a list of rules"))?;
+
+        let rules = entries
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| compile_rule(field, index, entry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            rules: Arc::new(rules),
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+impl<V: Serialize> IValidatorRule<V> for FieldSpec {
+    fn validate(&self, value: &V) -> Result<(), ValidationError> {
+        self.validate_with_context(value, &ValidationContext::default())
+    }
+
+    fn validate_with_context(
+        &self,
+        value: &V,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidationError> {
+        self.validate_all(value, ctx)
+            .map_err(RuleFailure::into_first)
+    }
+
+    fn validate_all(&self, value: &V, ctx: &ValidationContext) -> Result<(), RuleFailure> {
+        let json = serde_json::to_value(value).map_err(|e| {
+            RuleFailure::Field(ValidationError::new_with_params(
+                "validator.spec.unserializable",
+                HashMap::from([("reason".to_string(), e.to_string())]),
+            ))
+        })?;
+        let node = JsonNode::new(json);
+
+        for rule in self.rules.iter() {
+            rule.validate_all(&node, ctx)?;
+        }
+        Ok(())
+    }
+
+    fn describe(&self) -> Option<RuleDescriptor> {
+        let rules = self.rules.iter().filter_map(|r| r.describe()).collect();
+        Some(RuleDescriptor::new("spec", &[]).with_rules(rules))
+    }
+}
+
+fn spec_error(field: &str, rule: Option<(usize, &str)>, message: &str) -> ValidaError {
+    match rule {
+        Some((index, name)) => ValidaError::InvalidRuleSpec(format!(
+            "field `{field}`, rule #{} `{name}`: {message}",
+            index + 1
+        )),
+        None => ValidaError::InvalidRuleSpec(format!("field `{field}`: {message}")),
+    }
+}
+
+fn min_max(value: &Value) -> Option<(Option<&Value>, Option<&Value>)> {
+    match value {
+        Value::Object(map) => Some((map.get("min"), map.get("max"))),
+        Value::Array(items) if items.len() == 2 => Some((Some(&items[0]), Some(&items[1]))),
+        _ => None,
+    }
+}
+
+fn url_options(map: &serde_json::Map<String, Value>) -> Result<UrlOptions, String> {
+    let mut options = match map.get("ssrf_safe").map(Value::as_bool) {
+        None | Some(Some(false)) => UrlOptions::new(),
+        Some(Some(true)) => UrlOptions::ssrf_safe(),
+        Some(None) => return Err("`ssrf_safe` must be a boolean".into()),
+    };
+
+    for (name, value) in map {
+        let strings = || {
+            value
+                .as_array()
+                .and_then(|items| items.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| format!("`{name}` must be a list of strings"))
+        };
+        let flag = || {
+            value
+                .as_bool()
+                .ok_or_else(|| format!("`{name}` must be a boolean"))
+        };
+
+        options = match name.as_str() {
+            "ssrf_safe" => options,
+            "schemes" => options.schemes(&strings()?),
+            "allow_hosts" => options.allow_hosts(&strings()?),
+            "deny_hosts" => options.deny_hosts(&strings()?),
+            "require_tld" => options.require_tld(flag()?),
+            "forbid_credentials" => options.forbid_credentials(flag()?),
+            "forbid_ip_host" => options.forbid_ip_host(flag()?),
+            "forbid_private" => options.forbid_private(flag()?),
+            "max_length" => match value.as_u64() {
+                Some(max) => options.max_length(max as usize),
+                None => return Err("`max_length` must be a non-negative integer".into()),
+            },
+            _ => return Err(format!("unknown URL option `{name}`")),
+        };
+    }
+    Ok(options)
+}
+
+fn email_options(map: &serde_json::Map<String, Value>) -> Result<EmailOptions, String> {
+    let mut options = EmailOptions::new();
+    for (name, value) in map {
+        let strings = || {
+            value
+                .as_array()
+                .and_then(|items| items.iter().map(Value::as_str).collect::<Option<Vec<_>>>())
+                .ok_or_else(|| format!("`{name}` must be a list of strings"))
+        };
+        let flag = || {
+            value
+                .as_bool()
+                .ok_or_else(|| format!("`{name}` must be a boolean"))
+        };
+
+        options = match name.as_str() {
+            "ascii_only" => options.ascii_only(flag()?),
+            "allow_domains" => options.allow_domains(&strings()?),
+            "deny_domains" => options.deny_domains(&strings()?),
+            "block_disposable" => options.block_disposable(flag()?),
+            "require_tld" => options.require_tld(flag()?),
+            "allow_display_name" => options.allow_display_name(flag()?),
+            _ => return Err(format!("unknown email option `{name}`")),
+        };
+    }
+    Ok(options)
+}
+
+fn base64_options(map: &serde_json::Map<String, Value>) -> Result<Base64Options, String> {
+    let mut options = Base64Options::new();
+    for (name, value) in map {
+        let size = || {
+            value
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or_else(|| format!("`{name}` must be a non-negative integer"))
+        };
+
+        options = match name.as_str() {
+            "url_safe" => match value.as_bool() {
+                Some(url_safe) => options.url_safe(url_safe),
+                None => return Err(format!("`{name}` must be a boolean")),
+            },
+            "padding" => match value.as_str().and_then(Base64Padding::parse) {
+                Some(padding) => options.padding(padding),
+                None => {
+                    return Err(format!(
+                        "`{name}` must be `required`, `optional` or `forbidden`"
+                    ));
+                }
+            },
+            "min_decoded_size" => options.min_decoded_size(size()?),
+            "max_decoded_size" => options.max_decoded_size(size()?),
+            _ => return Err(format!("unknown base64 option `{name}`")),
+        };
+    }
+    Ok(options)
+}
+
+fn document_options(map: &serde_json::Map<String, Value>) -> Result<DocumentOptions, String> {
+    let mut options = DocumentOptions::new();
+    for (name, value) in map {
+        let size = || {
+            value
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or_else(|| format!("`{name}` must be a non-negative integer"))
+        };
+
+        options = match name.as_str() {
+            "max_size" => options.max_size(size()?),
+            "max_depth" => options.max_depth(size()?),
+            "top_level" => match value.as_str().and_then(DocumentType::parse) {
+                Some(top_level) => options.top_level(top_level),
+                None => return Err(format!("`{name}` must be `object` or `array`")),
+            },
+            _ => return Err(format!("unknown document option `{name}`")),
+        };
+    }
+    Ok(options)
+}
+
+#[cfg(feature = "phone")]
+fn phone_options(map: &serde_json::Map<String, Value>) -> Result<PhoneOptions, String> {
+    let mut options = PhoneOptions::new();
+    for (name, value) in map {
+        options = match name.as_str() {
+            "region" => match value
+                .as_str()
+                .filter(|r| r.parse::<phonenumber::country::Id>().is_ok())
+            {
+                Some(region) => options.region(region),
+                None => return Err(format!("`{name}` must be a known region code")),
+            },
+            "region_only" => match value.as_bool() {
+                Some(only) => options.region_only(only),
+                None => return Err(format!("`{name}` must be a boolean")),
+            },
+            "types" => {
+                let types = value
+                    .as_array()
+                    .and_then(|items| {
+                        items
+                            .iter()
+                            .map(|t| t.as_str().and_then(PhoneType::parse))
+                            .collect::<Option<Vec<_>>>()
+                    })
+                    .ok_or_else(|| format!("`{name}` must be a list of phone types"))?;
+                options.allow_types(&types)
+            }
+            _ => return Err(format!("unknown phone option `{name}`")),
+        };
+    }
+    Ok(options)
+}
+
+fn password_policy(map: &serde_json::Map<String, Value>) -> Result<PasswordPolicy, String> {
+    let mut policy = PasswordPolicy::new();
+    for (name, value) in map {
+        let count = || {
+            value
+                .as_u64()
+                .map(|n| n as usize)
+                .ok_or_else(|| format!("`{name}` must be a non-negative integer"))
+        };
+
+        policy = match name.as_str() {
+            "min_length" => policy.min_length(count()?),
+            "max_length" => policy.max_length(count()?),
+            "min_uppercase" => policy.require_uppercase(count()?),
+            "min_lowercase" => policy.require_lowercase(count()?),
+            "min_digits" => policy.require_digit(count()?),
+            "min_symbols" => policy.require_symbol(count()?),
+            "max_repeated" => policy.max_repeated(count()?),
+            "min_unique" => policy.min_unique(count()?),
+            "forbid_common" => match value.as_bool() {
+                Some(forbid) => policy.forbid_common(forbid),
+                None => return Err(format!("`{name}` must be a boolean")),
+            },
+            _ => return Err(format!("unknown password policy option `{name}`")),
+        };
+    }
+    Ok(policy)
+}
+
+fn compile_rule(field: &str, index: usize, entry: &Value) -> Result<SpecRule, ValidaError> {
+    let (name, params) = match entry {
+        Value::String(name) => (name.as_str(), None),
+        Value::Object(map) if map.len() == 1 => {
+            let (name, params) = map.iter().next().unwrap();
+            (name.as_str(), Some(params))
+        }
+        _ => {
+            return Err(spec_error(
+                field,
+                None,
+                &format!("rule #{} must be a name or a single-key map", index + 1),
+            ));
+        }
+    };
+
+    let err = |message: &str| spec_error(field, Some((index, name)), message);
+    let no_params = |rule: SpecRule| match params {
+        None | Some(Value::Null) => Ok(rule),
+        Some(_) => Err(err("takes no parameters")),
+    };
+    let param = || params.ok_or_else(|| err("missing parameter"));
+    let count = |value: &Value| {
+        value
+            .as_u64()
+            .map(|n| n as usize)
+            .ok_or_else(|| err("expected a non-negative integer"))
+    };
+    let length = |value: &Value, bound: &str| match value {
+        Value::Object(map) => {
+            if let Some(name) = map.keys().find(|name| *name != bound && *name != "unit") {
+                return Err(err(&format!("unknown length option `{name}`")));
+            }
+            let unit = match map.get("unit") {
+                None => LengthUnit::Chars,
+                Some(unit) => unit.as_str().and_then(LengthUnit::parse).ok_or_else(|| {
+                    err("`unit` must be `bytes`, `chars`, `graphemes` or `utf16`")
+                })?,
+            };
+            let value = map
+                .get(bound)
+                .ok_or_else(|| err(&format!("missing `{bound}`")))?;
+            Ok((count(value)?, unit))
+        }
+        value => Ok((count(value)?, LengthUnit::Chars)),
+    };
+    let number = |value: &Value| value.as_f64().ok_or_else(|| err("expected a number"));
+    let precision_scale = |value: &Value| {
+        let (precision, scale) = match value {
+            Value::Object(map) => (map.get("precision"), map.get("scale")),
+            Value::Array(items) if items.len() == 2 => (items.first(), items.get(1)),
+            _ => (None, None),
+        };
+        match (precision, scale) {
+            (Some(precision), Some(scale)) => Ok((count(precision)?, count(scale)?)),
+            _ => Err(err(
+                "expected `{ precision, scale }` or `[precision, scale]`",
+            )),
+        }
+    };
+    let min_max =
+        |value| min_max(value).ok_or_else(|| err("expected `{ min, max }` or `[min, max]`"));
+    let bound = |value: &Value| match value.as_str() {
+        Some("now") => Ok(TimeBound::Now),
+        Some(date) => TimeBound::parse(date).map_err(|_| err("expected an ISO-8601 date")),
+        None => Err(err("expected an ISO-8601 date or `now`")),
+    };
+    let networks = |value: &Value| {
+        value
+            .as_array()
+            .ok_or_else(|| err("expected a list of CIDR blocks"))?
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .ok_or_else(|| err("expected a list of CIDR blocks"))
+                    .and_then(|n| IpNetwork::parse(n).map_err(|e| err(&e.to_string())))
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let case = || match params {
+        None | Some(Value::Null) => Ok(CodeCase::Canonical),
+        Some(value) => value
+            .as_str()
+            .and_then(CodeCase::parse)
+            .ok_or_else(|| err("expected `canonical` or `insensitive`")),
+    };
+    let years = |value: &Value| {
+        value
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| err("expected a non-negative integer"))
+    };
+
+    let rule: SpecRule = match name {
+        "not_empty" => no_params(Box::new(NotEmpty {}))?,
+        "cidr" => no_params(Box::new(Cidr {}))?,
+        "email" => match params {
+            None | Some(Value::Null) => Box::new(Email {}),
+            Some(Value::Object(map)) => Box::new(EmailWithOptions {
+                options: email_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of email options")),
+        },
+        "hostname" => no_params(Box::new(Hostname {}))?,
+        "iban" => no_params(Box::new(Iban {}))?,
+        "bic" => no_params(Box::new(Bic {}))?,
+        "iso4217_currency" => no_params(Box::new(Iso4217Currency {}))?,
+        "credit_card" => match params {
+            None | Some(Value::Null) => Box::new(CreditCard { brands: vec![] }),
+            Some(Value::Array(items)) => {
+                let brands = items
+                    .iter()
+                    .map(|v| v.as_str().and_then(CardBrand::parse))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| err("expected a list of card brands"))?;
+                Box::new(CreditCard { brands })
+            }
+            Some(_) => return Err(err("expected a list of card brands")),
+        },
+        "base64" => match params {
+            None | Some(Value::Null) => Box::new(Base64 {
+                options: Base64Options::new(),
+            }),
+            Some(Value::Object(map)) => Box::new(Base64 {
+                options: base64_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of base64 options")),
+        },
+        "hex" => no_params(Box::new(Hex {}))?,
+        "jwt" => match params {
+            None | Some(Value::Null) => Box::new(Jwt { algorithms: vec![] }),
+            Some(Value::Array(items)) => {
+                let algorithms = items
+                    .iter()
+                    .map(|v| v.as_str().map(String::from))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| err("expected a list of algorithms"))?;
+                Box::new(Jwt { algorithms })
+            }
+            Some(_) => return Err(err("expected a list of algorithms")),
+        },
+        "semver" => no_params(Box::new(Semver {}))?,
+        "slug" => no_params(Box::new(Slug {}))?,
+        "hex_color" => no_params(Box::new(HexColor {}))?,
+        "css_color" => no_params(Box::new(CssColor {}))?,
+        "ulid" => no_params(Box::new(Ulid {}))?,
+        "vat" => no_params(Box::new(Vat {}))?,
+        "ean" => no_params(Box::new(Ean {}))?,
+        "upc" => no_params(Box::new(Upc {}))?,
+        "isbn" => no_params(Box::new(Isbn {}))?,
+        "issn" => no_params(Box::new(Issn {}))?,
+        "iso3166_alpha2" => Box::new(Iso3166Country {
+            format: CountryFormat::Alpha2,
+            case: case()?,
+        }),
+        "iso3166_alpha3" => Box::new(Iso3166Country {
+            format: CountryFormat::Alpha3,
+            case: case()?,
+        }),
+        "iso639_language" => Box::new(Iso639Language { case: case()? }),
+        "bcp47_locale" => Box::new(Bcp47Locale { case: case()? }),
+        "iana_timezone" => Box::new(IanaTimezone { case: case()? }),
+        "national_id" => match param()?.as_str() {
+            Some(country) if country.len() == 2 && country.is_ascii() => {
+                match NationalId::resolve(&BUILT_IN, country) {
+                    Some(rule) => Box::new(rule),
+                    None => return Err(err("expected a built-in country (UA, PL, US)")),
+                }
+            }
+            _ => return Err(err("expected a two-letter country code")),
+        },
+        "json" => match params {
+            None | Some(Value::Null) => Box::new(Json {}),
+            Some(Value::Object(map)) => Box::new(JsonWithOptions {
+                options: document_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of document options")),
+        },
+        "yaml" => match params {
+            None | Some(Value::Null) => Box::new(Yaml {
+                options: DocumentOptions::new(),
+            }),
+            Some(Value::Object(map)) => Box::new(Yaml {
+                options: document_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of document options")),
+        },
+        "xml" => no_params(Box::new(Xml {}))?,
+        "lowercased" => no_params(Box::new(Lowercased {}))?,
+        "mac_address" => no_params(Box::new(MacAddress {}))?,
+        "trimmed" => no_params(Box::new(Trimmed {}))?,
+        "uppercased" => no_params(Box::new(Uppercased {}))?,
+        "unicode_normalized" => match param()?.as_str().and_then(NormalizationForm::parse) {
+            Some(form) => Box::new(UnicodeNormalized { form }),
+            None => return Err(err("expected `nfc` or `nfkc`")),
+        },
+        "single_script" => no_params(Box::new(SingleScript {}))?,
+        "no_bidi_controls" => no_params(Box::new(NoBidiControls {}))?,
+        "no_zero_width_chars" => no_params(Box::new(NoZeroWidth {}))?,
+        "url" => match params {
+            None | Some(Value::Null) => Box::new(UrlValid {}),
+            Some(Value::Object(map)) => Box::new(UrlWithOptions {
+                options: url_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of URL options")),
+        },
+        #[cfg(feature = "phone")]
+        "phone" => match params {
+            None | Some(Value::Null) => Box::new(Phone {
+                options: PhoneOptions::new(),
+            }),
+            Some(Value::Object(map)) => Box::new(Phone {
+                options: phone_options(map).map_err(|m| err(&m))?,
+            }),
+            Some(_) => return Err(err("expected a map of phone options")),
+        },
+        "uuid" => no_params(Box::new(UuidValid {}))?,
+        "negative" => no_params(Box::new(Negative {}))?,
+        "negative_or_zero" => no_params(Box::new(NegativeOrZero {}))?,
+        "positive" => no_params(Box::new(Positive {}))?,
+        "positive_or_zero" => no_params(Box::new(PositiveOrZero {}))?,
+        "ip" => no_params(Box::new(Ip {
+            version: IpVersion::Any,
+        }))?,
+        "ipv4" => no_params(Box::new(Ip {
+            version: IpVersion::V4,
+        }))?,
+        "ipv6" => no_params(Box::new(Ip {
+            version: IpVersion::V6,
+        }))?,
+        "public_ip" => no_params(Box::new(PublicIp {}))?,
+        "port" => no_params(Box::new(Port {}))?,
+        "socket_addr" => no_params(Box::new(SocketAddress {}))?,
+        "in_past" => no_params(Box::new(InPast {}))?,
+        "in_future" => no_params(Box::new(InFuture {}))?,
+        "business_day" => no_params(Box::new(BusinessDay {
+            calendar: BusinessCalendar::new(),
+        }))?,
+        "min_length" => {
+            let (min, unit) = length(param()?, "min")?;
+            Box::new(MinLength { min, unit })
+        }
+        "max_length" => {
+            let (max, unit) = length(param()?, "max")?;
+            Box::new(MaxLength { max, unit })
+        }
+        "min_items" => Box::new(MinItems {
+            min: count(param()?)?,
+        }),
+        "max_items" => Box::new(MaxItems {
+            max: count(param()?)?,
+        }),
+        "exact_items" => Box::new(ExactItems {
+            expected: count(param()?)?,
+        }),
+        "min" | "min_value" => Box::new(MinValue {
+            min: number(param()?)?,
+        }),
+        "max" | "max_value" => Box::new(MaxValue {
+            max: number(param()?)?,
+        }),
+        "greater_than" => Box::new(GreaterThan {
+            min: number(param()?)?,
+        }),
+        "less_than" => Box::new(LessThan {
+            max: number(param()?)?,
+        }),
+        "multiple_of" => match number(param()?)? {
+            step if step > 0.0 => Box::new(MultipleOf { step }),
+            _ => return Err(err("expected a positive number")),
+        },
+        "max_decimal_places" => Box::new(MaxDecimalPlaces {
+            max: count(param()?)?,
+        }),
+        "finite" => no_params(Box::new(Finite {}))?,
+        "range" => match min_max(param()?)? {
+            (Some(min), Some(max)) => Box::new(Range {
+                min: number(min)?,
+                max: number(max)?,
+            }),
+            _ => return Err(err("both `min` and `max` are required")),
+        },
+        "ip_in_cidr" => Box::new(IpInCidr {
+            networks: networks(param()?)?,
+            deny: false,
+        }),
+        "ip_not_in_cidr" => Box::new(IpInCidr {
+            networks: networks(param()?)?,
+            deny: true,
+        }),
+        "before" => Box::new(Before {
+            bound: bound(param()?)?,
+        }),
+        "after" => Box::new(After {
+            bound: bound(param()?)?,
+        }),
+        "min_age" => Box::new(MinAge {
+            years: years(param()?)?,
+        }),
+        "max_age" => Box::new(MaxAge {
+            years: years(param()?)?,
+        }),
+        "date_format" => {
+            let param = param()?;
+            let format = match (param.as_str(), param.get("pattern")) {
+                (Some("date"), _) => DateFormat::Date,
+                (Some("time"), _) => DateFormat::Time,
+                (Some("date-time"), _) => DateFormat::DateTime,
+                (Some("iso8601"), _) => DateFormat::Iso8601,
+                (_, Some(Value::String(pattern))) if is_valid_pattern(pattern) => {
+                    DateFormat::pattern(pattern.clone())
+                }
+                (_, Some(_)) => {
+                    return Err(err(
+                        "expected a pattern of %Y %m %d %H %M %S, %% and literals",
+                    ));
+                }
+                _ => {
+                    return Err(err(
+                        "expected one of: date, time, date-time, iso8601 or `{ pattern }`",
+                    ));
+                }
+            };
+            Box::new(DateFormatRule { format })
+        }
+        "decimal_precision" => {
+            let (precision, scale) = precision_scale(param()?)?;
+            Box::new(DecimalPrecision { precision, scale })
+        }
+        "precision" => {
+            let (precision, scale) = precision_scale(param()?)?;
+            Box::new(Precision { precision, scale })
+        }
+        "word_count" => {
+            let (min, max) = min_max(param()?)?;
+            let boundary = match param()?.get("boundary") {
+                None => WordBoundary::Whitespace,
+                Some(value) => value
+                    .as_str()
+                    .and_then(WordBoundary::parse)
+                    .ok_or_else(|| err("`boundary` must be `whitespace` or `unicode`"))?,
+            };
+            Box::new(WordCount {
+                min: min.map(count).transpose()?,
+                max: max.map(count).transpose()?,
+                boundary,
+            })
+        }
+        "one_of" => {
+            let allowed = param()?
+                .as_array()
+                .ok_or_else(|| err("expected a list of strings"))?
+                .iter()
+                .map(|v| {
+                    v.as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| err("expected a list of strings"))
+                })
+                .collect::<Result<_, _>>()?;
+            Box::new(OneOf { allowed })
+        }
+        "not_confusable_with" => {
+            let reserved = param()?
+                .as_array()
+                .ok_or_else(|| err("expected a list of strings"))?
+                .iter()
+                .map(|v| v.as_str().ok_or_else(|| err("expected a list of strings")))
+                .collect::<Result<Vec<_>, _>>()?;
+            Box::new(NotConfusable::new(&reserved))
+        }
+        "regex_match" => {
+            let pattern = param()?
+                .as_str()
+                .ok_or_else(|| err("expected a pattern string"))?;
+            let pattern = Regex::new(pattern).map_err(|e| err(&format!("invalid pattern: {e}")))?;
+            Box::new(RegexMatch { pattern })
+        }
+        "password_strength" => {
+            let level = match param()?.as_str() {
+                Some("very_weak") => StrengthLevel::VeryWeak,
+                Some("weak") => StrengthLevel::Weak,
+                Some("medium") => StrengthLevel::Medium,
+                Some("strong") => StrengthLevel::Strong,
+                Some("very_strong") => StrengthLevel::VeryStrong,
+                _ => {
+                    return Err(err(
+                        "expected one of: very_weak, weak, medium, strong, very_strong",
+                    ));
+                }
+            };
+            Box::new(PasswordStrength { level })
+        }
+        "password_policy" => match param()? {
+            Value::Object(map) => Box::new(PasswordPolicyRule {
+                policy: password_policy(map).map_err(|m| err(&m))?,
+            }),
+            _ => return Err(err("expected a map of password policy options")),
+        },
+        "uuid_version" => {
+            let version = match param()?.as_str().map(|v| v.to_ascii_lowercase()).as_deref() {
+                Some("v1") => UuidVersion::V1,
+                Some("v3") => UuidVersion::V3,
+                Some("v4") => UuidVersion::V4,
+                Some("v5") => UuidVersion::V5,
+                Some("v6") => UuidVersion::V6,
+                Some("v7") => UuidVersion::V7,
+                Some("v8") => UuidVersion::V8,
+                _ => return Err(err("expected one of: v1, v3, v4, v5, v6, v7, v8")),
+            };
+            Box::new(UuidVersionValidator { version })
+        }
+        _ => return Err(err("unknown rule")),
+    };
+
+    Ok(rule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn compile(entries: Value) -> Result<FieldSpec, ValidaError> {
+        FieldSpec::compile("name", &entries)
+    }
+
+    fn message(result: Result<FieldSpec, ValidaError>) -> String {
+        match result {
+            Err(e) => e.to_string(),
+            Ok(_) => panic!("Should fail"),
+        }
+    }
+
+    #[test]
+    fn compiles_rules_with_and_without_params() {
+        let spec = compile(json!([
+            "trimmed",
+            {"min_length": 2},
+            {"max_length": 10},
+            {"one_of": ["Ann", "Bob"]}
+        ]))
+        .unwrap();
+        assert_eq!(spec.len(), 4);
+    }
+
+    #[test]
+    fn validates_serializable_values() {
+        let spec = compile(json!([{"min_length": 3}])).unwrap();
+        assert!(spec.validate(&"Ann".to_string()).is_ok());
+
+        let err = spec.validate(&"Al".to_string()).unwrap_err();
+        assert_eq!(err.key, "validator.min_length");
+        assert_eq!(err.params.get("min"), Some(&"3".into()));
+    }
+
+    #[test]
+    fn validates_numbers_as_f64() {
+        let spec = compile(json!([{"range": {"min": 1, "max": 10}}])).unwrap();
+        assert!(spec.validate(&5u32).is_ok());
+        assert!(spec.validate(&11i64).is_err());
+
+        let spec = compile(json!([{"range": [0.5, 1.5]}])).unwrap();
+        assert!(spec.validate(&2.0f64).is_err());
+    }
+
+    #[test]
+    fn none_is_only_rejected_by_not_empty() {
+        let spec = compile(json!([{"min_length": 3}])).unwrap();
+        assert!(spec.validate(&None::<String>).is_ok());
+
+        let spec = compile(json!(["not_empty"])).unwrap();
+        assert!(spec.validate(&None::<String>).is_err());
+    }
+
+    #[test]
+    fn compiles_temporal_rules() {
+        let spec =
+            compile(json!([{"date_format": "date"}, {"after": "2000-01-01"}, "in_past"])).unwrap();
+        assert!(spec.validate(&"2010-06-15").is_ok());
+        assert!(spec.validate(&"1999-12-31").is_err());
+        assert!(spec.validate(&"15.06.2010").is_err());
+
+        let msg = message(compile(json!([{"before": "tomorrow"}])));
+        assert!(msg.contains("`before`: expected an ISO-8601 date"));
+
+        let spec = compile(json!([{"date_format": {"pattern": "%d.%m.%Y"}}])).unwrap();
+        assert!(spec.validate(&"15.06.2010").is_ok());
+        assert!(spec.validate(&"2010-06-15").is_err());
+
+        let msg = message(compile(json!([{"date_format": {"pattern": "%d.%q"}}])));
+        assert!(msg.contains("expected a pattern of %Y %m %d %H %M %S"));
+        let msg = message(compile(json!([{"date_format": "yesterday"}])));
+        assert!(msg.contains("or `{ pattern }`"));
+    }
+
+    #[test]
+    fn compiles_network_rules() {
+        let spec =
+            compile(json!(["ip", "public_ip", {"ip_not_in_cidr": ["100.64.0.0/10"]}])).unwrap();
+        assert!(spec.validate(&"8.8.8.8").is_ok());
+        assert!(spec.validate(&"10.0.0.1").is_err());
+
+        let spec = compile(json!(["port"])).unwrap();
+        assert!(spec.validate(&8080u16).is_ok());
+        assert!(spec.validate(&0u16).is_err());
+
+        let msg = message(compile(json!([{"ip_in_cidr": ["10.0.0.0/40"]}])));
+        assert!(msg.contains("`ip_in_cidr`: Invalid CIDR block: 10.0.0.0/40"));
+    }
+
+    #[test]
+    fn compiles_url_options() {
+        let spec = compile(json!([{"url": {"ssrf_safe": true, "schemes": ["https"]}}])).unwrap();
+        assert!(spec.validate(&"https://example.com").is_ok());
+
+        let err = spec.validate(&"http://example.com").unwrap_err();
+        assert_eq!(err.key, "validator.url.scheme");
+
+        let msg = message(compile(json!([{"url": {"schemes": "https"}}])));
+        assert!(msg.contains("`url`: `schemes` must be a list of strings"));
+
+        let msg = message(compile(json!([{"url": {"hosts": []}}])));
+        assert!(msg.contains("unknown URL option `hosts`"));
+    }
+
+    #[test]
+    fn compiles_email_options() {
+        let spec = compile(
+            json!([{"email": {"block_disposable": true, "deny_domains": ["example.org"]}}]),
+        )
+        .unwrap();
+        assert!(spec.validate(&"user@example.com").is_ok());
+
+        let err = spec.validate(&"user@mailinator.com").unwrap_err();
+        assert_eq!(err.key, "validator.email.disposable");
+        let err = spec.validate(&"user@example.org").unwrap_err();
+        assert_eq!(err.key, "validator.email.domain");
+
+        let msg = message(compile(json!([{"email": {"ascii_only": "yes"}}])));
+        assert!(msg.contains("`email`: `ascii_only` must be a boolean"));
+
+        let msg = message(compile(json!([{"email": {"block": true}}])));
+        assert!(msg.contains("unknown email option `block`"));
+    }
+
+    #[cfg(feature = "phone")]
+    #[test]
+    fn compiles_phone_options() {
+        let spec = compile(json!([{"phone": {"region": "UA", "types": ["mobile"]}}])).unwrap();
+        assert!(spec.validate(&"050 123 4567").is_ok());
+
+        let err = spec.validate(&"044 123 4567").unwrap_err();
+        assert_eq!(err.key, "validator.phone.type");
+
+        let msg = message(compile(json!([{"phone": {"region": "XX"}}])));
+        assert!(msg.contains("`region` must be a known region code"));
+
+        let msg = message(compile(json!([{"phone": {"types": ["cell"]}}])));
+        assert!(msg.contains("`types` must be a list of phone types"));
+    }
+
+    #[test]
+    fn compiles_financial_rules() {
+        let spec = compile(json!(["iban", {"decimal_precision": [6, 2]}])).unwrap();
+        let err = spec.validate(&"DE88370400440532013000").unwrap_err();
+        assert_eq!(err.key, "validator.iban.checksum");
+
+        let spec = compile(json!([{"credit_card": ["visa"]}])).unwrap();
+        assert!(spec.validate(&"4111111111111111").is_ok());
+        let err = spec.validate(&"5555555555554444").unwrap_err();
+        assert_eq!(err.key, "validator.credit_card.brand");
+
+        let spec = compile(json!([{"decimal_precision": {"precision": 6, "scale": 2}}])).unwrap();
+        assert!(spec.validate(&"9999.99").is_ok());
+        assert!(spec.validate(&"99999.99").is_err());
+
+        let msg = message(compile(json!([{"credit_card": ["visa", "bitcoin"]}])));
+        assert!(msg.contains("expected a list of card brands"));
+        let msg = message(compile(json!([{"decimal_precision": {"precision": 6}}])));
+        assert!(msg.contains("expected `{ precision, scale }` or `[precision, scale]`"));
+    }
+
+    #[test]
+    fn compiles_identifier_rules() {
+        let spec = compile(json!(["isbn"])).unwrap();
+        assert!(spec.validate(&"978-0-306-40615-7").is_ok());
+        assert_eq!(
+            spec.validate(&"978-0-306-40615-8").unwrap_err().key,
+            "validator.isbn"
+        );
+
+        let spec = compile(json!([{"national_id": "ua"}])).unwrap();
+        assert!(spec.validate(&"3184710691").is_ok());
+        let err = spec.validate(&"3184710692").unwrap_err();
+        assert_eq!(err.params["country"], "UA");
+
+        let msg = message(compile(json!([{"national_id": "Ukraine"}])));
+        assert!(msg.contains("expected a two-letter country code"));
+        let msg = message(compile(json!([{"national_id": "UK"}])));
+        assert!(msg.contains("expected a built-in country (UA, PL, US)"));
+    }
+
+    #[test]
+    fn compiles_code_rules() {
+        let spec = compile(json!(["iso3166_alpha2"])).unwrap();
+        assert!(spec.validate(&"UA").is_ok());
+        assert_eq!(spec.validate(&"ua").unwrap_err().key, "validator.code.case");
+
+        let spec = compile(json!([{"bcp47_locale": "insensitive"}])).unwrap();
+        assert!(spec.validate(&"uk-ua").is_ok());
+
+        let msg = message(compile(json!([{"iso639_language": "lower"}])));
+        assert!(msg.contains("expected `canonical` or `insensitive`"));
+    }
+
+    #[test]
+    fn compiles_format_rules() {
+        let spec = compile(
+            json!([{"base64": {"url_safe": true, "padding": "forbidden", "max_decoded_size": 2}}]),
+        )
+        .unwrap();
+        assert!(spec.validate(&"aGk").is_ok());
+        assert_eq!(
+            spec.validate(&"aGVsbG8").unwrap_err().key,
+            "validator.base64.too_large"
+        );
+
+        let spec = compile(json!([{"jwt": ["RS256"]}])).unwrap();
+        assert_eq!(
+            spec.validate(&"eyJhbGciOiJIUzI1NiJ9.e30.c2ln")
+                .unwrap_err()
+                .key,
+            "validator.jwt.alg"
+        );
+
+        let msg = message(compile(json!([{"base64": {"padding": "maybe"}}])));
+        assert!(msg.contains("`padding` must be `required`, `optional` or `forbidden`"));
+        let msg = message(compile(json!([{"jwt": "RS256"}])));
+        assert!(msg.contains("expected a list of algorithms"));
+    }
+
+    #[test]
+    fn compiles_document_rules() {
+        let spec = compile(json!([{"json": {"max_depth": 1, "top_level": "object"}}])).unwrap();
+        assert!(spec.validate(&r#"{"a": 1}"#).is_ok());
+        assert_eq!(
+            spec.validate(&"[]").unwrap_err().key,
+            "validator.document.top_level"
+        );
+
+        let spec = compile(json!(["yaml", "xml"])).unwrap();
+        assert_eq!(
+            spec.validate(&"a: [").unwrap_err().key,
+            "validator.invalid_yaml"
+        );
+
+        let msg = message(compile(json!([{"yaml": {"top_level": "scalar"}}])));
+        assert!(msg.contains("`top_level` must be `object` or `array`"));
+        let msg = message(compile(json!([{"json": {"depth": 2}}])));
+        assert!(msg.contains("unknown document option `depth`"));
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_serialize() {
+        let spec = compile(json!(["not_empty"])).unwrap();
+        let value = HashMap::from([(vec![1u8], 1)]);
+
+        let error = spec.validate(&value).unwrap_err();
+
+        assert_eq!(error.key, "validator.spec.unserializable");
+        assert!(error.params.contains_key("reason"));
+    }
+
+    #[test]
+    fn compiles_numeric_precision_rules() {
+        let spec = compile(json!([
+            {"multiple_of": 0.05},
+            {"precision": [6, 2]},
+            {"max_decimal_places": 1}
+        ]))
+        .unwrap();
+        assert!(spec.validate(&json!(19.5)).is_ok());
+        assert_eq!(
+            spec.validate(&json!(19.52)).unwrap_err().key,
+            "validator.multiple_of"
+        );
+        assert_eq!(
+            spec.validate(&json!(12345.5)).unwrap_err().key,
+            "validator.decimal.precision"
+        );
+        assert_eq!(
+            spec.validate(&json!(19.55)).unwrap_err().key,
+            "validator.decimal.scale"
+        );
+        assert!(
+            compile(json!(["finite"]))
+                .unwrap()
+                .validate(&json!(1.5))
+                .is_ok()
+        );
+
+        let msg = message(compile(json!([{"multiple_of": 0}])));
+        assert!(msg.contains("expected a positive number"));
+        let msg = message(compile(json!([{"precision": {"precision": 6}}])));
+        assert!(msg.contains("expected `{ precision, scale }` or `[precision, scale]`"));
+    }
+
+    #[test]
+    fn compiles_length_units() {
+        let spec = compile(json!([
+            {"max_length": {"max": 2, "unit": "graphemes"}},
+            {"min_length": {"min": 4, "unit": "bytes"}}
+        ]))
+        .unwrap();
+        assert!(spec.validate(&"e\u{301}x").is_ok());
+
+        let err = spec.validate(&"abc").unwrap_err();
+        assert_eq!(err.key, "validator.max_length");
+        assert_eq!(err.params["unit"], "graphemes");
+        assert_eq!(spec.validate(&"éa").unwrap_err().key, "validator.min_bytes");
+
+        let spec = compile(json!([{"word_count": {"max": 3, "boundary": "unicode"}}])).unwrap();
+        assert!(spec.validate(&"well-known").is_ok());
+        assert_eq!(
+            spec.validate(&"a-b-c-d").unwrap_err().key,
+            "validator.word_count.too_many"
+        );
+
+        let msg = message(compile(
+            json!([{"max_length": {"max": 2, "unit": "words"}}]),
+        ));
+        assert!(msg.contains("`unit` must be `bytes`, `chars`, `graphemes` or `utf16`"));
+        let msg = message(compile(json!([{"min_length": {"unit": "chars"}}])));
+        assert!(msg.contains("missing `min`"));
+        let msg = message(compile(
+            json!([{"word_count": {"max": 3, "boundary": "icu"}}]),
+        ));
+        assert!(msg.contains("`boundary` must be `whitespace` or `unicode`"));
+    }
+
+    #[test]
+    fn compiles_unicode_rules() {
+        let spec = compile(json!([
+            {"unicode_normalized": "nfkc"},
+            "single_script",
+            "no_bidi_controls",
+            "no_zero_width_chars",
+            {"not_confusable_with": ["admin", "root"]}
+        ]))
+        .unwrap();
+        assert!(spec.validate(&"kateryna").is_ok());
+        assert_eq!(
+            spec.validate(&"ａdmin").unwrap_err().key,
+            "validator.unicode.not_normalized"
+        );
+        assert_eq!(
+            spec.validate(&"rооt").unwrap_err().key,
+            "validator.unicode.mixed_script"
+        );
+        assert_eq!(
+            spec.validate(&"ro\u{200B}ot").unwrap_err().key,
+            "validator.unicode.zero_width"
+        );
+        assert_eq!(
+            spec.validate(&"Admin").unwrap_err().key,
+            "validator.unicode.confusable"
+        );
+
+        let msg = message(compile(json!([{"unicode_normalized": "nfd"}])));
+        assert!(msg.contains("expected `nfc` or `nfkc`"));
+        let msg = message(compile(json!([{"not_confusable_with": "admin"}])));
+        assert!(msg.contains("expected a list of strings"));
+    }
+
+    #[test]
+    fn compiles_password_policy() {
+        let spec =
+            compile(json!([{"password_policy": {"min_length": 10, "min_digits": 1}}])).unwrap();
+        assert!(spec.validate(&"correct-horse-7").is_ok());
+
+        let err = spec.validate(&"horse").unwrap_err();
+        assert_eq!(err.key, "validator.password.too_short");
+        let ctx = ValidationContext::default();
+        match spec.validate_all(&"horse", &ctx) {
+            Err(RuleFailure::Children(children)) => assert_eq!(children.len(), 2),
+            _ => panic!("expected an error per requirement"),
+        }
+
+        let msg = message(compile(json!([{"password_policy": {"min_digits": -1}}])));
+        assert!(msg.contains("`min_digits` must be a non-negative integer"));
+
+        let msg = message(compile(json!([{"password_policy": {"digits": 1}}])));
+        assert!(msg.contains("unknown password policy option `digits`"));
+    }
+
+    #[test]
+    fn reports_unknown_rule() {
+        let msg = message(compile(json!(["trimmed", "shiny"])));
+        assert_eq!(
+            msg,
+            "Invalid rule spec: field `name`, rule #2 `shiny`: unknown rule"
+        );
+    }
+
+    #[test]
+    fn reports_bad_params() {
+        let msg = message(compile(json!([{"max_length": "ten"}])));
+        assert!(msg.contains("`max_length`: expected a non-negative integer"));
+
+        let msg = message(compile(json!([{"email": true}])));
+        assert!(msg.contains("`email`: expected a map of email options"));
+
+        let msg = message(compile(json!(["min_length"])));
+        assert!(msg.contains("`min_length`: missing parameter"));
+
+        let msg = message(compile(json!([{"regex_match": "("}])));
+        assert!(msg.contains("invalid pattern"));
+
+        let msg = message(compile(json!([{"range": {"min": 1}}])));
+        assert!(msg.contains("both `min` and `max` are required"));
+    }
+
+    #[test]
+    fn reports_malformed_entries() {
+        let msg = message(compile(json!({"min_length": 2})));
+        assert!(msg.contains("expected a list of rules"));
+
+        let msg = message(compile(json!([{"min_length": 2, "max_length": 4}])));
+        assert!(msg.contains("rule #1 must be a name or a single-key map"));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod field_spec;
+pub mod rule_spec;
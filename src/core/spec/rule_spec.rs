@@ -0,0 +1,220 @@
+use crate::core::spec::field_spec::FieldSpec;
+use crate::core::valida_error::ValidaError;
+use crate::core::value_validator::validator::ValueValidator;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::Path;
+
+/// Declarative rules loaded at runtime:
+///
+/// ```yaml
+/// fields:
+///   email: [not_empty, email, { max_length: 64 }]
+///   plan:
+///     - one_of: [free, pro]
+///   "$.items[*].qty":
+///     - range: { min: 1, max: 100 }
+/// ```
+#[derive(Clone)]
+pub struct RuleSpec {
+    fields: BTreeMap<String, FieldSpec>,
+}
+
+impl RuleSpec {
+    pub fn from_yaml_str(source: &str) -> Result<Self, ValidaError> {
+        let raw: Value = serde_yaml::from_str(source)
+            .map_err(|e| ValidaError::InvalidRuleSpec(format!("YAML: {e}")))?;
+        Self::from_value(&raw)
+    }
+
+    pub fn from_json_str(source: &str) -> Result<Self, ValidaError> {
+        let raw: Value = serde_json::from_str(source)?;
+        Self::from_value(&raw)
+    }
+
+    /// Loads `.json` files as JSON and everything else as YAML.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ValidaError> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Err(ValidaError::MissingFile(path.display().to_string()));
+        }
+
+        let source = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("json") => Self::from_json_str(&source),
+            _ => Self::from_yaml_str(&source),
+        }
+    }
+
+    pub fn from_value(raw: &Value) -> Result<Self, ValidaError> {
+        let fields = raw
+            .get("fields")
+            .and_then(Value::as_object)
+            .ok_or_else(|| ValidaError::InvalidRuleSpec("expected a `fields` map".into()))?;
+
+        let fields = fields
+            .iter()
+            .map(|(name, entries)| Ok((name.clone(), FieldSpec::compile(name, entries)?)))
+            .collect::<Result<_, ValidaError>>()?;
+
+        Ok(Self { fields })
+    }
+
+    pub fn field(&self, name: &str) -> Option<&FieldSpec> {
+        self.fields.get(name)
+    }
+
+    /// Like [`RuleSpec::field`], but a missing field is an error.
+    pub fn require(&self, name: &str) -> Result<&FieldSpec, ValidaError> {
+        self.field(name)
+            .ok_or_else(|| ValidaError::InvalidRuleSpec(format!("field `{name}` is not defined")))
+    }
+
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &FieldSpec)> {
+        self.fields.iter().map(|(name, spec)| (name.as_str(), spec))
+    }
+
+    /// Builds a validator for dynamic JSON. Field names without a leading `$`
+    /// are treated as top-level keys (`email` → `$.email`).
+    pub fn to_value_validator<E>(&self) -> Result<ValueValidator<E>, ValidaError>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        self.fields
+            .iter()
+            .try_fold(ValueValidator::new(), |validator, (name, spec)| {
+                let path = if name.starts_with('$') {
+                    name.clone()
+                } else {
+                    format!("$.{name}")
+                };
+                validator.path(&path, |f| f.spec(spec))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::builder::RulesBuilder;
+    use crate::core::contract::{IValidate, ValidatorFailure};
+    use async_trait::async_trait;
+    use serde_json::json;
+
+    const YAML: &str = r#"
+fields:
+  email: [not_empty, email, { max_length: 64 }]
+  plan:
+    - one_of: [free, pro]
+  "$.items[*].qty":
+    - range: { min: 1, max: 100 }
+"#;
+
+    #[test]
+    fn loads_yaml() {
+        let spec = RuleSpec::from_yaml_str(YAML).unwrap();
+        assert_eq!(spec.field("email").map(|f| f.len()), Some(3));
+        assert_eq!(spec.fields().count(), 3);
+    }
+
+    #[test]
+    fn loads_json() {
+        let spec = RuleSpec::from_json_str(r#"{"fields": {"name": [{"min_length": 2}]}}"#).unwrap();
+        assert!(spec.field("name").is_some());
+    }
+
+    #[test]
+    fn loads_file() {
+        let path = std::env::temp_dir().join("valida_rule_spec_test.yaml");
+        std::fs::write(&path, YAML).unwrap();
+        let spec = RuleSpec::from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert!(spec.is_ok());
+
+        let missing = RuleSpec::from_file("/definitely/missing/rules.yaml");
+        assert!(matches!(missing, Err(ValidaError::MissingFile(_))));
+    }
+
+    #[test]
+    fn reports_structure_errors() {
+        let err = RuleSpec::from_yaml_str("rules: {}").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "Invalid rule spec: expected a `fields` map"
+        );
+
+        let err = RuleSpec::from_yaml_str("fields: [").err().unwrap();
+        assert!(err.to_string().starts_with("Invalid rule spec: YAML:"));
+
+        let spec = RuleSpec::from_yaml_str(YAML).unwrap();
+        assert!(spec.require("phone").is_err());
+    }
+
+    #[tokio::test]
+    async fn builds_value_validator() {
+        let validator = RuleSpec::from_yaml_str(YAML)
+            .unwrap()
+            .to_value_validator::<std::io::Error>()
+            .unwrap();
+
+        let doc = json!({"plan": "gold", "items": [{"qty": 0}]});
+        let errors = match validator.validate(&doc).await {
+            Err(ValidatorFailure::Invalid(e)) => e.to_json_dot_raw(),
+            _ => panic!("Should be invalid"),
+        };
+
+        assert_eq!(errors["email"]["key"], "validator.required");
+        assert_eq!(errors["plan"]["key"], "validator.one_of");
+        assert_eq!(errors["items.0.qty"]["key"], "validator.range");
+    }
+
+    #[tokio::test]
+    async fn applies_to_typed_fields() {
+        struct Order {
+            plan: String,
+            seats: u32,
+        }
+
+        struct OrderValidator {
+            spec: RuleSpec,
+        }
+
+        #[async_trait]
+        impl IValidate<Order, std::io::Error> for OrderValidator {
+            fn rules(
+                &self,
+                mut builder: RulesBuilder<Order, std::io::Error>,
+            ) -> RulesBuilder<Order, std::io::Error> {
+                builder
+                    .field("plan", |o| &o.plan)
+                    .spec(self.spec.require("plan").unwrap())
+                    .build();
+                builder
+                    .field("seats", |o| &o.seats)
+                    .spec(self.spec.require("seats").unwrap())
+                    .build();
+                builder
+            }
+        }
+
+        let validator = OrderValidator {
+            spec: RuleSpec::from_yaml_str(
+                "fields:\n  plan: [{one_of: [free, pro]}]\n  seats: [{max: 50}]",
+            )
+            .unwrap(),
+        };
+
+        let order = Order {
+            plan: "gold".into(),
+            seats: 51,
+        };
+
+        let errors = match validator.validate(&order).await {
+            Err(ValidatorFailure::Invalid(e)) => e,
+            _ => panic!("Should be invalid"),
+        };
+        assert!(errors.has_error_for_field("plan"));
+        assert!(errors.has_error_for_field("seats"));
+    }
+}
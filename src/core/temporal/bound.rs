@@ -0,0 +1,69 @@
+use crate::core::temporal::moment::Moment;
+use crate::core::valida_error::ValidaError;
+use std::time::Duration;
+
+/// A limit for `before` / `after` / `between`: absolute, or relative to the clock.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimeBound {
+    At(Moment),
+    Now,
+    Ago(Duration),
+    FromNow(Duration),
+}
+
+impl TimeBound {
+    /// `TimeBound::At` from an ISO-8601 / RFC-3339 string.
+    pub fn parse(value: &str) -> Result<Self, ValidaError> {
+        Moment::parse(value).map(TimeBound::At)
+    }
+
+    /// `None` when a relative bound overflows.
+    pub fn resolve(&self, now: Moment) -> Option<Moment> {
+        match self {
+            TimeBound::At(moment) => Some(*moment),
+            TimeBound::Now => Some(now),
+            TimeBound::Ago(duration) => now.checked_sub(*duration),
+            TimeBound::FromNow(duration) => now.checked_add(*duration),
+        }
+    }
+
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            TimeBound::At(moment) => moment.to_string(),
+            TimeBound::Now => "now".into(),
+            TimeBound::Ago(duration) => format!("now-{}s", duration.as_secs()),
+            TimeBound::FromNow(duration) => format!("now+{}s", duration.as_secs()),
+        }
+    }
+}
+
+impl From<Moment> for TimeBound {
+    fn from(moment: Moment) -> Self {
+        TimeBound::At(moment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_relative_bounds() {
+        let now = Moment::parse("2024-05-01T12:00:00Z").unwrap();
+        let day = Duration::from_secs(86_400);
+
+        assert_eq!(TimeBound::Now.resolve(now), Some(now));
+        assert_eq!(
+            TimeBound::Ago(day).resolve(now),
+            Moment::parse("2024-04-30T12:00:00Z").ok()
+        );
+        assert_eq!(
+            TimeBound::FromNow(day).resolve(now),
+            Moment::parse("2024-05-02T12:00:00Z").ok()
+        );
+        assert_eq!(
+            TimeBound::parse("2030-01-01").unwrap().resolve(now),
+            Moment::parse("2030-01-01").ok()
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use crate::core::temporal::moment::{CivilDate, Weekday};
+use crate::core::valida_error::ValidaError;
+use std::collections::HashSet;
+
+/// Working days and holidays for `business_day`. Defaults to Monday–Friday without holidays.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BusinessCalendar {
+    weekend: Vec<Weekday>,
+    holidays: HashSet<CivilDate>,
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> Self {
+        Self {
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            holidays: HashSet::new(),
+        }
+    }
+}
+
+impl BusinessCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn weekend(mut self, days: &[Weekday]) -> Self {
+        self.weekend = days.to_vec();
+        self
+    }
+
+    pub fn holiday(mut self, date: CivilDate) -> Self {
+        self.holidays.insert(date);
+        self
+    }
+
+    /// Adds holidays given as `YYYY-MM-DD` strings.
+    pub fn holidays(mut self, dates: &[&str]) -> Result<Self, ValidaError> {
+        for date in dates {
+            let moment = crate::core::temporal::moment::Moment::parse(date)?;
+            self.holidays.insert(moment.date());
+        }
+        Ok(self)
+    }
+
+    pub fn is_business_day(&self, date: &CivilDate) -> bool {
+        !self.weekend.contains(&date.weekday()) && !self.holidays.contains(date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u8, d: u8) -> CivilDate {
+        CivilDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn default_calendar_skips_weekends() {
+        let calendar = BusinessCalendar::new();
+        assert!(calendar.is_business_day(&date(2024, 5, 3)));
+        assert!(!calendar.is_business_day(&date(2024, 5, 4)));
+        assert!(!calendar.is_business_day(&date(2024, 5, 5)));
+    }
+
+    #[test]
+    fn custom_weekend_and_holidays() {
+        let calendar = BusinessCalendar::new()
+            .weekend(&[Weekday::Fri, Weekday::Sat])
+            .holidays(&["2024-12-25"])
+            .unwrap();
+
+        assert!(!calendar.is_business_day(&date(2024, 5, 3)));
+        assert!(calendar.is_business_day(&date(2024, 5, 5)));
+        assert!(!calendar.is_business_day(&date(2024, 12, 25)));
+        assert!(BusinessCalendar::new().holidays(&["25.12.2024"]).is_err());
+    }
+}
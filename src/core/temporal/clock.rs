@@ -0,0 +1,24 @@
+use crate::core::temporal::moment::Moment;
+use std::time::SystemTime;
+
+/// Source of "now" for relative temporal rules (`in_past`, `min_age`, `TimeBound::ago`, ...).
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Moment;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Moment {
+        Moment::from_system_time(SystemTime::now())
+    }
+}
+
+/// Always returns the same moment; for tests and reproducible batch jobs.
+pub struct FixedClock(pub Moment);
+
+impl Clock for FixedClock {
+    fn now(&self) -> Moment {
+        self.0
+    }
+}
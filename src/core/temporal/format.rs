@@ -0,0 +1,311 @@
+use crate::core::temporal::moment::{CivilDate, Moment};
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateFormat {
+    /// `2024-05-01`
+    Date,
+    /// `10:30:00`, `10:30:00.250`
+    Time,
+    /// RFC 3339: `2024-05-01T10:30:00Z`, `2024-05-01T10:30:00.5+02:00`
+    DateTime,
+    /// ISO-8601 date or date-time, offset optional: everything `Moment::parse` accepts
+    Iso8601,
+    /// `%Y`, `%m`, `%d`, `%H`, `%M`, `%S` and `%%`, e.g. `%d.%m.%Y`
+    Pattern(Cow<'static, str>),
+}
+
+impl DateFormat {
+    /// A [`DateFormat::Pattern`] from a literal or a runtime string.
+    pub fn pattern(pattern: impl Into<Cow<'static, str>>) -> Self {
+        DateFormat::Pattern(pattern.into())
+    }
+
+    /// Shown in error messages as `%{format}`.
+    pub fn name(&self) -> &str {
+        match self {
+            DateFormat::Date => "date",
+            DateFormat::Time => "time",
+            DateFormat::DateTime => "date-time",
+            DateFormat::Iso8601 => "iso8601",
+            DateFormat::Pattern(pattern) => pattern,
+        }
+    }
+
+    pub fn matches(&self, value: &str) -> bool {
+        match self {
+            DateFormat::Date => {
+                let mut cursor = Cursor::new(value);
+                parse_date(&mut cursor).is_some() && cursor.done()
+            }
+            DateFormat::Time => {
+                let mut cursor = Cursor::new(value);
+                parse_time(&mut cursor, true).is_some() && cursor.done()
+            }
+            DateFormat::DateTime => parse_rfc3339(value).is_some(),
+            DateFormat::Iso8601 => parse_iso8601(value).is_some(),
+            DateFormat::Pattern(pattern) => matches_pattern(pattern, value),
+        }
+    }
+}
+
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(value: &'a str) -> Self {
+        Self {
+            bytes: value.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, expected: u8) -> bool {
+        let matched = self.peek() == Some(expected);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let slice = self.bytes.get(self.pos..self.pos + count)?;
+        if !slice.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        self.pos += count;
+        Some(
+            slice
+                .iter()
+                .fold(0, |acc, d| acc * 10 + u32::from(d - b'0')),
+        )
+    }
+}
+
+fn parse_date(cursor: &mut Cursor) -> Option<CivilDate> {
+    let year = cursor.digits(4)?;
+    cursor.eat(b'-').then_some(())?;
+    let month = cursor.digits(2)?;
+    cursor.eat(b'-').then_some(())?;
+    let day = cursor.digits(2)?;
+    CivilDate::new(year as i32, month as u8, day as u8)
+}
+
+/// Seconds of day and nanoseconds. A leap second (`:60`) is folded into `:59`.
+fn parse_time(cursor: &mut Cursor, require_seconds: bool) -> Option<(u32, u32)> {
+    let hour = cursor.digits(2).filter(|h| *h < 24)?;
+    cursor.eat(b':').then_some(())?;
+    let minute = cursor.digits(2).filter(|m| *m < 60)?;
+
+    let mut second = 0;
+    let mut nanos = 0;
+    if cursor.eat(b':') {
+        second = cursor.digits(2).filter(|s| *s <= 60)?.min(59);
+        if cursor.eat(b'.') || cursor.eat(b',') {
+            let start = cursor.pos;
+            let mut scale = 100_000_000;
+            while let Some(digit) = cursor.peek().filter(u8::is_ascii_digit) {
+                nanos += u32::from(digit - b'0') * scale;
+                scale /= 10;
+                cursor.pos += 1;
+            }
+            (cursor.pos > start).then_some(())?;
+        }
+    } else if require_seconds {
+        return None;
+    }
+
+    Some((hour * 3600 + minute * 60 + second, nanos))
+}
+
+/// `Z` or `±HH:MM` / `±HHMM` in minutes; `None` when there is no offset.
+fn parse_offset(cursor: &mut Cursor) -> Option<Option<i32>> {
+    if cursor.eat(b'Z') || cursor.eat(b'z') {
+        return Some(Some(0));
+    }
+
+    let sign = match cursor.peek() {
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Some(None),
+    };
+    cursor.pos += 1;
+
+    let hours = cursor.digits(2).filter(|h| *h < 24)?;
+    cursor.eat(b':');
+    let minutes = cursor.digits(2).filter(|m| *m < 60)?;
+    Some(Some(sign * (hours * 60 + minutes) as i32))
+}
+
+fn parse_date_time(value: &str, strict: bool) -> Option<Moment> {
+    let mut cursor = Cursor::new(value);
+    let date = parse_date(&mut cursor)?;
+    if cursor.done() {
+        return (!strict).then(|| Moment::from_date(date));
+    }
+
+    if !(cursor.eat(b'T') || cursor.eat(b't') || cursor.eat(b' ')) {
+        return None;
+    }
+    let (seconds, nanos) = parse_time(&mut cursor, strict)?;
+    let offset = parse_offset(&mut cursor)?;
+    if !cursor.done() || (strict && offset.is_none()) {
+        return None;
+    }
+
+    Some(Moment::from_local(
+        date,
+        seconds,
+        nanos,
+        offset.unwrap_or(0),
+    ))
+}
+
+pub(crate) fn parse_iso8601(value: &str) -> Option<Moment> {
+    parse_date_time(value, false)
+}
+
+pub(crate) fn parse_rfc3339(value: &str) -> Option<Moment> {
+    parse_date_time(value, true)
+}
+
+/// Every `%` is followed by one of the supported specifiers.
+pub(crate) fn is_valid_pattern(pattern: &str) -> bool {
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' && !matches!(chars.next(), Some('Y' | 'm' | 'd' | 'H' | 'M' | 'S' | '%')) {
+            return false;
+        }
+    }
+    true
+}
+
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let mut cursor = Cursor::new(value);
+    let mut chars = pattern.chars();
+    let (mut year, mut month, mut day) = (None, None, None);
+
+    while let Some(c) = chars.next() {
+        let parsed = match (c, c == '%') {
+            (_, true) => match chars.next() {
+                Some('Y') => cursor.digits(4).map(|v| year = Some(v as i32)),
+                Some('m') => cursor.digits(2).map(|v| month = Some(v as u8)),
+                Some('d') => cursor.digits(2).map(|v| day = Some(v as u8)),
+                Some('H') => cursor.digits(2).filter(|h| *h < 24).map(|_| ()),
+                Some('M') => cursor.digits(2).filter(|m| *m < 60).map(|_| ()),
+                Some('S') => cursor.digits(2).filter(|s| *s <= 60).map(|_| ()),
+                Some('%') => cursor.eat(b'%').then_some(()),
+                _ => None,
+            },
+            (literal, false) => {
+                let mut buffer = [0; 4];
+                literal
+                    .encode_utf8(&mut buffer)
+                    .bytes()
+                    .all(|b| cursor.eat(b))
+                    .then_some(())
+            }
+        };
+        if parsed.is_none() {
+            return false;
+        }
+    }
+
+    if !cursor.done() {
+        return false;
+    }
+
+    // a leap year accepts Feb 29 when the pattern has no year
+    let year = year.unwrap_or(2000);
+    let month_ok = month.is_none_or(|m| (1..=12).contains(&m));
+    let day_ok = match day {
+        None => true,
+        Some(day) => CivilDate::new(year, month.unwrap_or(1), day).is_some(),
+    };
+    month_ok && day_ok
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_dates() {
+        assert!(DateFormat::Date.matches("2024-02-29"));
+        assert!(!DateFormat::Date.matches("2023-02-29"));
+        assert!(!DateFormat::Date.matches("2024-2-9"));
+        assert!(!DateFormat::Date.matches("2024-05-01T10:00:00Z"));
+    }
+
+    #[test]
+    fn parses_times() {
+        assert!(DateFormat::Time.matches("23:59:59"));
+        assert!(DateFormat::Time.matches("10:30:00.250"));
+        assert!(!DateFormat::Time.matches("24:00:00"));
+        assert!(!DateFormat::Time.matches("10:30"));
+    }
+
+    #[test]
+    fn parses_rfc3339() {
+        assert!(DateFormat::DateTime.matches("2024-05-01T10:30:00Z"));
+        assert!(DateFormat::DateTime.matches("2024-05-01t10:30:00.5+02:00"));
+        assert!(DateFormat::DateTime.matches("2024-05-01 10:30:00-0530"));
+        assert!(DateFormat::DateTime.matches("2016-12-31T23:59:60Z"));
+        assert!(!DateFormat::DateTime.matches("2024-05-01T10:30:00"));
+        assert!(!DateFormat::DateTime.matches("2024-05-01T10:30Z"));
+        assert!(!DateFormat::DateTime.matches("2024-05-01"));
+        assert!(!DateFormat::DateTime.matches("2024-05-01T10:30:00.Z"));
+    }
+
+    #[test]
+    fn parses_iso8601() {
+        assert!(DateFormat::Iso8601.matches("2024-05-01"));
+        assert!(DateFormat::Iso8601.matches("2024-05-01T10:30"));
+        assert!(DateFormat::Iso8601.matches("2024-05-01T10:30:00"));
+        assert!(DateFormat::Iso8601.matches("2024-05-01T10:30:00+02:00"));
+        assert!(!DateFormat::Iso8601.matches("2024-05-01T"));
+        assert!(!DateFormat::Iso8601.matches("01.05.2024"));
+    }
+
+    #[test]
+    fn converts_offsets() {
+        let utc = parse_iso8601("2024-05-01T10:00:00Z").unwrap();
+        let local = parse_iso8601("2024-05-01T12:00:00+02:00").unwrap();
+        let naive = parse_iso8601("2024-05-01T10:00:00").unwrap();
+        assert_eq!(utc, local);
+        assert_eq!(utc, naive);
+        assert_eq!(utc.unix_timestamp(), 1_714_557_600);
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let format = DateFormat::pattern(String::from("%d.%m.%Y"));
+        assert!(format.matches("29.02.2024"));
+        assert!(!format.matches("29.02.2023"));
+        assert!(!format.matches("2024-02-29"));
+        assert!(!format.matches("1.02.2024"));
+
+        assert!(DateFormat::pattern("%H:%M").matches("23:05"));
+        assert!(!DateFormat::pattern("%H:%M").matches("24:05"));
+        assert!(DateFormat::pattern("%d/%m").matches("29/02"));
+        assert!(DateFormat::pattern("%Y年%m月%d日").matches("2024年05月01日"));
+        assert!(!DateFormat::pattern("%Q").matches("x"));
+    }
+
+    #[test]
+    fn checks_pattern_specifiers() {
+        assert!(is_valid_pattern("%d.%m.%Y %H:%M:%S %%"));
+        assert!(!is_valid_pattern("%d.%q"));
+        assert!(!is_valid_pattern("%Y%"));
+    }
+}
@@ -0,0 +1,5 @@
+pub mod bound;
+pub mod calendar;
+pub mod clock;
+pub mod format;
+pub mod moment;
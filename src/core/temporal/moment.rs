@@ -0,0 +1,339 @@
+use crate::core::temporal::format::parse_iso8601;
+use crate::core::valida_error::ValidaError;
+use std::cmp::Ordering;
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDate {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+impl CivilDate {
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        let valid = (1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month);
+        valid.then_some(Self { year, month, day })
+    }
+
+    /// Days since 1970-01-01 (proleptic Gregorian calendar).
+    pub fn days_since_epoch(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = i64::from(self.month);
+        let day_of_year =
+            (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let mp = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u8;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Self { year, month, day }
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        match (self.days_since_epoch() + 3).rem_euclid(7) {
+            0 => Weekday::Mon,
+            1 => Weekday::Tue,
+            2 => Weekday::Wed,
+            3 => Weekday::Thu,
+            4 => Weekday::Fri,
+            5 => Weekday::Sat,
+            _ => Weekday::Sun,
+        }
+    }
+
+    /// Full years from `self` (a birth date) to `today`.
+    pub fn years_until(&self, today: &CivilDate) -> i32 {
+        let mut years = today.year - self.year;
+        if (today.month, today.day) < (self.month, self.day) {
+            years -= 1;
+        }
+        years
+    }
+}
+
+impl fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+pub(crate) fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// A point in time, as understood by the temporal rules.
+///
+/// Keeps the UTC offset it was written with, so calendar checks (`business_day`,
+/// `min_age`) use the local date. Values without an offset are treated as UTC;
+/// date-only values are midnight.
+#[derive(Debug, Clone, Copy)]
+pub struct Moment {
+    unix: i64,
+    nanos: u32,
+    offset_minutes: i32,
+    date_only: bool,
+}
+
+impl Moment {
+    pub fn from_unix(unix: i64, nanos: u32) -> Self {
+        Self {
+            unix,
+            nanos,
+            offset_minutes: 0,
+            date_only: false,
+        }
+    }
+
+    /// Same instant, with calendar dates taken in the given UTC offset.
+    pub fn with_offset_minutes(mut self, offset_minutes: i32) -> Self {
+        self.offset_minutes = offset_minutes;
+        self
+    }
+
+    pub fn from_date(date: CivilDate) -> Self {
+        Self {
+            unix: date.days_since_epoch() * SECONDS_PER_DAY,
+            nanos: 0,
+            offset_minutes: 0,
+            date_only: true,
+        }
+    }
+
+    pub(crate) fn from_local(
+        date: CivilDate,
+        seconds_of_day: u32,
+        nanos: u32,
+        offset_minutes: i32,
+    ) -> Self {
+        let local = date.days_since_epoch() * SECONDS_PER_DAY + i64::from(seconds_of_day);
+        Self {
+            unix: local - i64::from(offset_minutes) * 60,
+            nanos,
+            offset_minutes,
+            date_only: false,
+        }
+    }
+
+    pub fn from_system_time(time: SystemTime) -> Self {
+        match time.duration_since(UNIX_EPOCH) {
+            Ok(after) => Self::from_unix(after.as_secs() as i64, after.subsec_nanos()),
+            Err(before) => {
+                let before = before.duration();
+                let mut unix = -(before.as_secs() as i64);
+                let mut nanos = before.subsec_nanos();
+                if nanos > 0 {
+                    unix -= 1;
+                    nanos = 1_000_000_000 - nanos;
+                }
+                Self::from_unix(unix, nanos)
+            }
+        }
+    }
+
+    /// Parses an ISO-8601 / RFC-3339 date (`2024-05-01`) or date-time
+    /// (`2024-05-01T10:00:00+02:00`).
+    pub fn parse(value: &str) -> Result<Self, ValidaError> {
+        parse_iso8601(value).ok_or_else(|| ValidaError::InvalidDate(value.to_string()))
+    }
+
+    pub fn unix_timestamp(&self) -> i64 {
+        self.unix
+    }
+
+    pub fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Calendar date in the value's own UTC offset.
+    pub fn date(&self) -> CivilDate {
+        let local = self.unix + i64::from(self.offset_minutes) * 60;
+        CivilDate::from_days_since_epoch(local.div_euclid(SECONDS_PER_DAY))
+    }
+
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        let mut result = *self;
+        result.date_only = false;
+        result.unix = result
+            .unix
+            .checked_add(i64::try_from(duration.as_secs()).ok()?)?;
+        result.nanos += duration.subsec_nanos();
+        if result.nanos >= 1_000_000_000 {
+            result.nanos -= 1_000_000_000;
+            result.unix = result.unix.checked_add(1)?;
+        }
+        Some(result)
+    }
+
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        let mut result = *self;
+        result.date_only = false;
+        result.unix = result
+            .unix
+            .checked_sub(i64::try_from(duration.as_secs()).ok()?)?;
+        if result.nanos < duration.subsec_nanos() {
+            result.nanos += 1_000_000_000;
+            result.unix = result.unix.checked_sub(1)?;
+        }
+        result.nanos -= duration.subsec_nanos();
+        Some(result)
+    }
+}
+
+impl PartialEq for Moment {
+    fn eq(&self, other: &Self) -> bool {
+        (self.unix, self.nanos) == (other.unix, other.nanos)
+    }
+}
+
+impl Eq for Moment {}
+
+impl PartialOrd for Moment {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Moment {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.unix, self.nanos).cmp(&(other.unix, other.nanos))
+    }
+}
+
+/// `2024-05-01` for dates, RFC 3339 in the original offset otherwise.
+impl fmt::Display for Moment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.date_only {
+            return write!(f, "{}", self.date());
+        }
+
+        let local = self.unix + i64::from(self.offset_minutes) * 60;
+        let seconds = local.rem_euclid(SECONDS_PER_DAY);
+        write!(
+            f,
+            "{}T{:02}:{:02}:{:02}",
+            self.date(),
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )?;
+
+        match self.offset_minutes {
+            0 => write!(f, "Z"),
+            offset => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                write!(f, "{sign}{:02}:{:02}", offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u8, d: u8) -> CivilDate {
+        CivilDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn converts_civil_dates() {
+        assert_eq!(date(1970, 1, 1).days_since_epoch(), 0);
+        assert_eq!(date(2000, 3, 1).days_since_epoch(), 11_017);
+        assert_eq!(date(1969, 12, 31).days_since_epoch(), -1);
+
+        for days in [-800_000, -1, 0, 59, 11_016, 19_782, 2_000_000] {
+            let civil = CivilDate::from_days_since_epoch(days);
+            assert_eq!(civil.days_since_epoch(), days);
+        }
+    }
+
+    #[test]
+    fn rejects_impossible_dates() {
+        assert!(CivilDate::new(2023, 2, 29).is_none());
+        assert!(CivilDate::new(2024, 2, 29).is_some());
+        assert!(CivilDate::new(1900, 2, 29).is_none());
+        assert!(CivilDate::new(2000, 2, 29).is_some());
+        assert!(CivilDate::new(2024, 13, 1).is_none());
+        assert!(CivilDate::new(2024, 4, 31).is_none());
+    }
+
+    #[test]
+    fn computes_weekday() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thu);
+        assert_eq!(date(2024, 5, 4).weekday(), Weekday::Sat);
+        assert_eq!(date(2024, 5, 6).weekday(), Weekday::Mon);
+    }
+
+    #[test]
+    fn counts_full_years() {
+        let birth = date(2000, 6, 15);
+        assert_eq!(birth.years_until(&date(2018, 6, 14)), 17);
+        assert_eq!(birth.years_until(&date(2018, 6, 15)), 18);
+        assert_eq!(date(2004, 2, 29).years_until(&date(2022, 2, 28)), 17);
+        assert_eq!(date(2004, 2, 29).years_until(&date(2022, 3, 1)), 18);
+    }
+
+    #[test]
+    fn keeps_local_date_of_offset() {
+        let moment = Moment::parse("2024-05-01T23:30:00-02:00").unwrap();
+        assert_eq!(moment.date(), date(2024, 5, 1));
+        assert_eq!(moment.to_string(), "2024-05-01T23:30:00-02:00");
+        assert_eq!(moment, Moment::parse("2024-05-02T01:30:00Z").unwrap());
+    }
+
+    #[test]
+    fn converts_system_time() {
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+        let moment = Moment::from_system_time(before_epoch);
+        assert_eq!((moment.unix_timestamp(), moment.nanos()), (-2, 500_000_000));
+    }
+
+    #[test]
+    fn shifts_by_duration() {
+        let moment = Moment::parse("2024-05-01").unwrap();
+        assert_eq!(moment.to_string(), "2024-05-01");
+
+        let later = moment.checked_add(Duration::from_secs(90)).unwrap();
+        assert_eq!(later.to_string(), "2024-05-01T00:01:30Z");
+        assert_eq!(later.checked_sub(Duration::from_secs(90)), Some(moment));
+    }
+}
@@ -0,0 +1,106 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+/// What `min_length_in` / `max_length_in` count. The same text has different lengths in each:
+/// `"👩‍💻 é"` (with `é` as `e` + U+0301) is 3 graphemes, 6 chars, 8 UTF-16 code units and
+/// 15 bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthUnit {
+    /// UTF-8 bytes, for byte-sized storage: `VARBINARY(n)`, index key limits, fixed buffers.
+    Bytes,
+    /// Unicode scalar values (`str::chars`), like JSON Schema `maxLength` and PostgreSQL
+    /// `varchar(n)`.
+    #[default]
+    Chars,
+    /// Extended grapheme clusters (UAX #29): what a person counts as one character.
+    Graphemes,
+    /// UTF-16 code units, like JavaScript `String.length` and HTML `maxlength`.
+    Utf16,
+}
+
+impl LengthUnit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LengthUnit::Bytes => "bytes",
+            LengthUnit::Chars => "chars",
+            LengthUnit::Graphemes => "graphemes",
+            LengthUnit::Utf16 => "utf16",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        [
+            LengthUnit::Bytes,
+            LengthUnit::Chars,
+            LengthUnit::Graphemes,
+            LengthUnit::Utf16,
+        ]
+        .into_iter()
+        .find(|unit| unit.as_str() == name)
+    }
+
+    pub fn count(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Bytes => value.len(),
+            LengthUnit::Chars => value.chars().count(),
+            LengthUnit::Graphemes => value.graphemes(true).count(),
+            LengthUnit::Utf16 => value.encode_utf16().count(),
+        }
+    }
+}
+
+/// How `word_count_by` splits text into words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordBoundary {
+    /// Runs of non-whitespace: `"hello, world!"` is 2 words, `"東京タワー"` is 1.
+    #[default]
+    Whitespace,
+    /// UAX #29 words, ignoring punctuation: `"state-of-the-art"` is 4 words; CJK text is split
+    /// per ideograph or kana run.
+    Unicode,
+}
+
+impl WordBoundary {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WordBoundary::Whitespace => "whitespace",
+            WordBoundary::Unicode => "unicode",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        [WordBoundary::Whitespace, WordBoundary::Unicode]
+            .into_iter()
+            .find(|boundary| boundary.as_str() == name)
+    }
+
+    pub fn count(&self, value: &str) -> usize {
+        match self {
+            WordBoundary::Whitespace => value.split_whitespace().count(),
+            WordBoundary::Unicode => value.unicode_words().count(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_in_every_unit() {
+        let text = "👩\u{200D}💻 e\u{301}";
+        assert_eq!(LengthUnit::Graphemes.count(text), 3);
+        assert_eq!(LengthUnit::Chars.count(text), 6);
+        assert_eq!(LengthUnit::Utf16.count(text), 8);
+        assert_eq!(LengthUnit::Bytes.count(text), 15);
+        assert_eq!(LengthUnit::parse("utf16"), Some(LengthUnit::Utf16));
+        assert_eq!(LengthUnit::parse("codepoints"), None);
+    }
+
+    #[test]
+    fn splits_words() {
+        assert_eq!(WordBoundary::Whitespace.count("state-of-the-art design"), 2);
+        assert_eq!(WordBoundary::Unicode.count("state-of-the-art design"), 5);
+        assert_eq!(WordBoundary::Unicode.count("hello, world!"), 2);
+        assert_eq!(WordBoundary::parse("unicode"), Some(WordBoundary::Unicode));
+    }
+}
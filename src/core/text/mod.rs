@@ -0,0 +1,2 @@
+pub mod length;
+pub mod unicode;
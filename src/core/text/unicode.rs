@@ -0,0 +1,108 @@
+use unicode_normalization::{is_nfc, is_nfkc};
+use unicode_script::{Script, UnicodeScript};
+
+/// Unicode normalization form a string must already be in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizationForm {
+    /// Canonical composition: `é` as one code point, not `e` + U+0301.
+    #[default]
+    Nfc,
+    /// Compatibility composition: also folds `ﬁ` to `fi`, full-width `Ａ` to `A`, `²` to `2`.
+    Nfkc,
+}
+
+impl NormalizationForm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NormalizationForm::Nfc => "NFC",
+            NormalizationForm::Nfkc => "NFKC",
+        }
+    }
+
+    pub(crate) fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "nfc" => Some(NormalizationForm::Nfc),
+            "nfkc" => Some(NormalizationForm::Nfkc),
+            _ => None,
+        }
+    }
+
+    pub fn is_normalized(&self, value: &str) -> bool {
+        match self {
+            NormalizationForm::Nfc => is_nfc(value),
+            NormalizationForm::Nfkc => is_nfkc(value),
+        }
+    }
+}
+
+/// Explicit directional formatting characters (UAX #9), the "Trojan Source" characters.
+pub(crate) fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Characters that render with no width: ZWSP, ZWNJ, ZWJ, word joiner, BOM, Mongolian vowel
+/// separator.
+pub(crate) fn is_zero_width(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}'..='\u{200D}' | '\u{2060}' | '\u{FEFF}' | '\u{180E}'
+    )
+}
+
+/// `U+202E` form of a character, for error params (invisible characters do not print).
+pub(crate) fn code_point(c: char) -> String {
+    format!("U+{:04X}", c as u32)
+}
+
+/// UTS #39 skeleton of the lowercased value: strings that look alike share a skeleton
+/// (`раураl` in Cyrillic and `paypal`, `rn` and `m`).
+pub(crate) fn skeleton(value: &str) -> String {
+    unicode_security::skeleton(&value.to_lowercase()).collect()
+}
+
+/// Scripts of the letters of `value` in order of appearance, without Common and Inherited.
+pub(crate) fn scripts(value: &str) -> Vec<&'static str> {
+    let mut found: Vec<&'static str> = vec![];
+    for c in value.chars() {
+        let script = c.script();
+        if matches!(script, Script::Common | Script::Inherited | Script::Unknown) {
+            continue;
+        }
+        if !found.contains(&script.full_name()) {
+            found.push(script.full_name());
+        }
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_normalization_forms() {
+        assert!(NormalizationForm::Nfc.is_normalized("Café"));
+        assert!(!NormalizationForm::Nfc.is_normalized("Cafe\u{301}"));
+        assert!(NormalizationForm::Nfc.is_normalized("ﬁle"));
+        assert!(!NormalizationForm::Nfkc.is_normalized("ﬁle"));
+        assert_eq!(
+            NormalizationForm::parse("NFKC"),
+            Some(NormalizationForm::Nfkc)
+        );
+    }
+
+    #[test]
+    fn computes_skeletons() {
+        assert_eq!(skeleton("раураl"), skeleton("paypal"));
+        assert_eq!(skeleton("PayPal"), skeleton("paypal"));
+        assert_eq!(skeleton("rnicrosoft"), skeleton("microsoft"));
+        assert_ne!(skeleton("paypals"), skeleton("paypal"));
+    }
+
+    #[test]
+    fn lists_scripts() {
+        assert_eq!(scripts("раураl"), ["Cyrillic", "Latin"]);
+        assert_eq!(scripts("user_42!"), ["Latin"]);
+        assert!(scripts("42").is_empty());
+    }
+}
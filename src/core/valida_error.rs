@@ -1,4 +1,5 @@
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum ValidaError {
     Io(std::io::Error),
     InvalidLocale(String),
@@ -6,6 +7,11 @@ pub enum ValidaError {
     FluentParse(String),
     FluentMessage(String),
     SerdeError(serde_json::Error),
+    InvalidJsonPath(String),
+    InvalidRuleSpec(String),
+    InvalidDate(String),
+    InvalidCidr(String),
+    InvalidJsonSchema(String),
 }
 
 impl std::fmt::Display for ValidaError {
@@ -17,6 +23,11 @@ impl std::fmt::Display for ValidaError {
             ValidaError::FluentParse(p) => write!(f, "Fluent parse error: {p}"),
             ValidaError::FluentMessage(k) => write!(f, "Missing Fluent message key: {k}"),
             ValidaError::SerdeError(e) => write!(f, "Serialization error: {e}"),
+            ValidaError::InvalidJsonPath(p) => write!(f, "Invalid JSON path: {p}"),
+            ValidaError::InvalidRuleSpec(m) => write!(f, "Invalid rule spec: {m}"),
+            ValidaError::InvalidDate(d) => write!(f, "Invalid ISO-8601 date: {d}"),
+            ValidaError::InvalidCidr(c) => write!(f, "Invalid CIDR block: {c}"),
+            ValidaError::InvalidJsonSchema(m) => write!(f, "Invalid JSON Schema: {m}"),
         }
     }
 }
@@ -0,0 +1,205 @@
+use crate::core::errors::ValidationError;
+use crate::core::rules::common::not_empty::RuleTarget;
+use crate::core::rules::net_ref::{IpRef, PortRef, SocketAddrRef, port_from};
+use crate::core::rules::slice_ref::SliceRef;
+use crate::core::rules::str_ref::StrAsRef;
+use crate::core::rules::temporal_ref::TemporalRef;
+use crate::core::rules::value_ref::ValueRef;
+use crate::core::temporal::moment::Moment;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
+
+static NULL: Value = Value::Null;
+
+/// A value resolved from a JSON document, adapted to the built-in rule traits:
+/// strings work with string rules, numbers with numeric rules (as `f64`),
+/// arrays with collection rules. Rules skip values of other JSON types.
+///
+/// The node shares the document and points into it, so resolving a subtree
+/// never copies it.
+#[derive(Debug, Clone)]
+pub struct JsonNode {
+    document: Arc<Value>,
+    pointer: String,
+    number: Option<f64>,
+}
+
+impl JsonNode {
+    pub fn new(value: Value) -> Self {
+        Self::at(Arc::new(value), String::new())
+    }
+
+    pub fn missing() -> Self {
+        Self::new(Value::Null)
+    }
+
+    /// The value under the JSON pointer `pointer`; `null` when nothing is there.
+    pub(crate) fn at(document: Arc<Value>, pointer: String) -> Self {
+        let number = document.pointer(&pointer).and_then(Value::as_f64);
+        Self {
+            document,
+            pointer,
+            number,
+        }
+    }
+
+    pub fn json(&self) -> &Value {
+        self.document.pointer(&self.pointer).unwrap_or(&NULL)
+    }
+}
+
+/// RFC 6901 pointer for the given path segments.
+pub(crate) fn pointer_of(segments: &[String]) -> String {
+    segments.iter().fold(String::new(), |mut pointer, segment| {
+        pointer.push('/');
+        pointer.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+        pointer
+    })
+}
+
+impl Serialize for JsonNode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.json().serialize(serializer)
+    }
+}
+
+impl StrAsRef for JsonNode {
+    fn as_str_ref(&self) -> Option<&str> {
+        self.json().as_str()
+    }
+}
+
+impl ValueRef for JsonNode {
+    type Target = f64;
+    fn value(&self) -> Option<&Self::Target> {
+        self.number.as_ref()
+    }
+}
+
+impl SliceRef for JsonNode {
+    type Item = Value;
+    fn slice(&self) -> Option<&[Value]> {
+        self.json().slice()
+    }
+}
+
+impl TemporalRef for JsonNode {
+    fn moment(&self) -> Option<Result<Moment, ValidationError>> {
+        self.json().as_str().and_then(|s| s.moment())
+    }
+}
+
+impl IpRef for JsonNode {
+    fn ip_addr(&self) -> Option<Result<IpAddr, ValidationError>> {
+        self.json().as_str().and_then(|s| s.ip_addr())
+    }
+}
+
+impl SocketAddrRef for JsonNode {
+    fn socket_addr(&self) -> Option<Result<SocketAddr, ValidationError>> {
+        self.json().as_str().and_then(|s| s.socket_addr())
+    }
+}
+
+/// Integers and decimal strings; other numbers (`80.5`, `-1`) are not ports.
+impl PortRef for JsonNode {
+    fn port(&self) -> Option<Result<u16, ValidationError>> {
+        match self.json() {
+            Value::String(s) => s.as_str().port(),
+            Value::Number(n) => match n.as_u64() {
+                Some(n) => port_from(n),
+                None => Some(Err(ValidationError::new("validator.port"))),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl RuleTarget for JsonNode {
+    fn is_empty(&self) -> bool {
+        match self.json() {
+            Value::Null => true,
+            Value::String(s) => s.trim().is_empty(),
+            Value::Array(items) => items.is_empty(),
+            Value::Object(map) => map.is_empty(),
+            Value::Bool(_) | Value::Number(_) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn points_into_shared_document() {
+        let document = Arc::new(json!({"a/b": {"c~d": [1, 2.5]}}));
+        let segments = ["a/b", "c~d", "1"].map(String::from);
+
+        let node = JsonNode::at(document.clone(), pointer_of(&segments));
+
+        assert_eq!(node.value(), Some(&2.5));
+        assert_eq!(node.json(), &json!(2.5));
+        assert_eq!(Arc::strong_count(&document), 2);
+
+        let absent = JsonNode::at(document, pointer_of(&["nope".to_string()]));
+        assert!(absent.is_empty());
+    }
+
+    #[test]
+    fn exposes_string() {
+        let node = JsonNode::new(json!("hello"));
+        assert_eq!(node.as_str_ref(), Some("hello"));
+        assert_eq!(node.value(), None);
+    }
+
+    #[test]
+    fn exposes_number_as_f64() {
+        assert_eq!(JsonNode::new(json!(42)).value(), Some(&42.0));
+        assert_eq!(JsonNode::new(json!(-1.5)).value(), Some(&-1.5));
+        assert_eq!(JsonNode::new(json!(42)).as_str_ref(), None);
+    }
+
+    #[test]
+    fn exposes_array() {
+        let node = JsonNode::new(json!([1, 2]));
+        assert_eq!(node.slice().map(|s| s.len()), Some(2));
+        assert_eq!(JsonNode::new(json!("x")).slice(), None);
+    }
+
+    #[test]
+    fn exposes_date_strings() {
+        let node = JsonNode::new(json!("2024-05-01"));
+        assert_eq!(node.moment().unwrap().unwrap().to_string(), "2024-05-01");
+        assert!(JsonNode::new(json!("yesterday")).moment().unwrap().is_err());
+        assert!(JsonNode::new(json!(1714521600)).moment().is_none());
+    }
+
+    #[test]
+    fn exposes_network_values() {
+        assert!(JsonNode::new(json!("10.0.0.1")).ip_addr().unwrap().is_ok());
+        assert!(
+            JsonNode::new(json!("10.0.0.1:80"))
+                .socket_addr()
+                .unwrap()
+                .is_ok()
+        );
+        assert!(JsonNode::new(json!(443)).port().unwrap().is_ok());
+        assert!(JsonNode::new(json!("443")).port().unwrap().is_ok());
+        assert!(JsonNode::new(json!(80.5)).port().unwrap().is_err());
+        assert!(JsonNode::new(json!(true)).port().is_none());
+    }
+
+    #[test]
+    fn empty_values() {
+        assert!(JsonNode::missing().is_empty());
+        assert!(JsonNode::new(json!("  ")).is_empty());
+        assert!(JsonNode::new(json!([])).is_empty());
+        assert!(JsonNode::new(json!({})).is_empty());
+        assert!(!JsonNode::new(json!(0)).is_empty());
+        assert!(!JsonNode::new(json!(false)).is_empty());
+    }
+}
@@ -0,0 +1,248 @@
+use crate::core::valida_error::ValidaError;
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathStep {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Parsed JSON path: `$`, `$.user.email`, `$.items[*].qty`, `$['odd.key'][0]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    pub source: String,
+    pub steps: Vec<PathStep>,
+}
+
+/// One resolved location: concrete path segments and the value found there (if any).
+#[derive(Debug)]
+pub struct PathMatch<'a> {
+    pub segments: Vec<String>,
+    pub value: Option<&'a Value>,
+}
+
+impl JsonPath {
+    pub fn parse(source: &str) -> Result<Self, ValidaError> {
+        let invalid = || ValidaError::InvalidJsonPath(source.to_string());
+
+        let rest = source.strip_prefix('$').ok_or_else(invalid)?;
+        let chars: Vec<char> = rest.chars().collect();
+        let mut steps = vec![];
+        let mut i = 0;
+
+        while i < chars.len() {
+            match chars[i] {
+                '.' => {
+                    let start = i + 1;
+                    let mut end = start;
+                    while end < chars.len() && chars[end] != '.' && chars[end] != '[' {
+                        end += 1;
+                    }
+                    let name: String = chars[start..end].iter().collect();
+                    if name.is_empty() {
+                        return Err(invalid());
+                    }
+                    steps.push(if name == "*" {
+                        PathStep::Wildcard
+                    } else {
+                        PathStep::Key(name)
+                    });
+                    i = end;
+                }
+                '[' => {
+                    let close = chars[i..]
+                        .iter()
+                        .position(|&c| c == ']')
+                        .map(|p| p + i)
+                        .ok_or_else(invalid)?;
+                    let inner: String = chars[i + 1..close].iter().collect();
+                    let inner = inner.trim();
+
+                    let step = if inner == "*" {
+                        PathStep::Wildcard
+                    } else if let Some(quoted) = inner
+                        .strip_prefix('\'')
+                        .and_then(|s| s.strip_suffix('\''))
+                        .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+                    {
+                        PathStep::Key(quoted.to_string())
+                    } else {
+                        PathStep::Index(inner.parse().map_err(|_| invalid())?)
+                    };
+
+                    steps.push(step);
+                    i = close + 1;
+                }
+                _ => return Err(invalid()),
+            }
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            steps,
+        })
+    }
+
+    pub fn has_wildcard(&self) -> bool {
+        self.steps.contains(&PathStep::Wildcard)
+    }
+
+    /// Resolves the path against a document.
+    ///
+    /// Missing values are reported with `value: None` as long as the rest of the path is
+    /// concrete, so rules like `not_empty` can flag absent fields. Wildcards over missing
+    /// or non-container values produce no matches.
+    pub fn resolve<'a>(&self, document: &'a Value) -> Vec<PathMatch<'a>> {
+        let mut matches = vec![];
+        resolve_steps(&self.steps, Some(document), vec![], &mut matches);
+        matches
+    }
+}
+
+fn resolve_steps<'a>(
+    steps: &[PathStep],
+    current: Option<&'a Value>,
+    segments: Vec<String>,
+    output: &mut Vec<PathMatch<'a>>,
+) {
+    let Some((step, rest)) = steps.split_first() else {
+        output.push(PathMatch {
+            segments,
+            value: current,
+        });
+        return;
+    };
+
+    let child = |segment: String| {
+        let mut path = segments.clone();
+        path.push(segment);
+        path
+    };
+
+    match step {
+        PathStep::Key(key) => {
+            let next = current.and_then(|v| v.get(key));
+            resolve_missing_or(rest, next, child(key.clone()), output);
+        }
+        PathStep::Index(index) => {
+            let next = current.and_then(|v| v.get(index));
+            resolve_missing_or(rest, next, child(index.to_string()), output);
+        }
+        PathStep::Wildcard => match current {
+            Some(Value::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    resolve_steps(rest, Some(item), child(i.to_string()), output);
+                }
+            }
+            Some(Value::Object(map)) => {
+                for (key, item) in map {
+                    resolve_steps(rest, Some(item), child(key.clone()), output);
+                }
+            }
+            _ => {}
+        },
+    }
+}
+
+fn resolve_missing_or<'a>(
+    steps: &[PathStep],
+    value: Option<&'a Value>,
+    segments: Vec<String>,
+    output: &mut Vec<PathMatch<'a>>,
+) {
+    if value.is_some() || !steps.contains(&PathStep::Wildcard) {
+        resolve_steps(steps, value, segments, output);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn segments(path: &str, doc: &Value) -> Vec<(String, Option<Value>)> {
+        JsonPath::parse(path)
+            .unwrap()
+            .resolve(doc)
+            .into_iter()
+            .map(|m| (m.segments.join("."), m.value.cloned()))
+            .collect()
+    }
+
+    #[test]
+    fn parses_dotted_path() {
+        let path = JsonPath::parse("$.user.email").unwrap();
+        assert_eq!(
+            path.steps,
+            vec![PathStep::Key("user".into()), PathStep::Key("email".into())]
+        );
+    }
+
+    #[test]
+    fn parses_brackets_and_wildcards() {
+        let path = JsonPath::parse("$.items[*]['odd.key'][2].*").unwrap();
+        assert_eq!(
+            path.steps,
+            vec![
+                PathStep::Key("items".into()),
+                PathStep::Wildcard,
+                PathStep::Key("odd.key".into()),
+                PathStep::Index(2),
+                PathStep::Wildcard,
+            ]
+        );
+        assert!(path.has_wildcard());
+    }
+
+    #[test]
+    fn parses_root() {
+        assert!(JsonPath::parse("$").unwrap().steps.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_paths() {
+        assert!(JsonPath::parse("user.email").is_err());
+        assert!(JsonPath::parse("$.").is_err());
+        assert!(JsonPath::parse("$.items[").is_err());
+        assert!(JsonPath::parse("$.items[x]").is_err());
+        assert!(JsonPath::parse("$user").is_err());
+    }
+
+    #[test]
+    fn resolves_concrete_path() {
+        let doc = json!({"user": {"email": "a@b.c"}});
+        assert_eq!(
+            segments("$.user.email", &doc),
+            vec![("user.email".into(), Some(json!("a@b.c")))]
+        );
+    }
+
+    #[test]
+    fn reports_missing_concrete_path() {
+        let doc = json!({"user": {}});
+        assert_eq!(
+            segments("$.user.email", &doc),
+            vec![("user.email".into(), None)]
+        );
+    }
+
+    #[test]
+    fn expands_array_wildcard() {
+        let doc = json!({"items": [{"qty": 1}, {"qty": 2}, {}]});
+        assert_eq!(
+            segments("$.items[*].qty", &doc),
+            vec![
+                ("items.0.qty".into(), Some(json!(1))),
+                ("items.1.qty".into(), Some(json!(2))),
+                ("items.2.qty".into(), None),
+            ]
+        );
+    }
+
+    #[test]
+    fn wildcard_over_missing_value_matches_nothing() {
+        let doc = json!({});
+        assert!(segments("$.items[*].qty", &doc).is_empty());
+    }
+}
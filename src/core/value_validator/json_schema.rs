@@ -0,0 +1,771 @@
+use crate::core::builder::RulesBuilder;
+use crate::core::context::ValidationContext;
+use crate::core::contract::{IValidate, ValidatorFailure};
+use crate::core::errors::{ValidationError, ValidationErrors};
+use crate::core::schema::descriptor::ValidatorDescriptor;
+use crate::core::valida_error::ValidaError;
+use crate::core::value_validator::validator::{ROOT, is_occupied};
+use async_trait::async_trait;
+use regex::Regex;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::marker::PhantomData;
+
+/// Keywords that do not constrain the instance.
+const ANNOTATIONS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "$defs",
+    "definitions",
+    "title",
+    "description",
+    "default",
+    "examples",
+    "deprecated",
+    "readOnly",
+    "writeOnly",
+    "format",
+    "contentEncoding",
+    "contentMediaType",
+    "contentSchema",
+];
+
+/// Validator for `serde_json::Value` documents described by a JSON Schema (draft 2020-12
+/// subset). Errors are reported per instance path with the keys of the equivalent built-in
+/// rules (`validator.required`, `validator.min_length`, ...).
+///
+/// Keywords outside the supported subset are rejected by `new` instead of being silently
+/// ignored, so a schema never validates less than it says.
+pub struct JsonSchemaValidator<E> {
+    source: Value,
+    root: Schema,
+    defs: HashMap<String, Schema>,
+    _phantom: PhantomData<E>,
+}
+
+impl<E> JsonSchemaValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    pub fn new(schema: Value) -> Result<Self, ValidaError> {
+        let mut defs = HashMap::new();
+        if let Value::Object(map) = &schema {
+            for container in ["$defs", "definitions"] {
+                let Some(entries) = map.get(container) else {
+                    continue;
+                };
+                let entries = entries
+                    .as_object()
+                    .ok_or_else(|| invalid(&format!("`{container}` must be an object")))?;
+                for (name, def) in entries {
+                    defs.insert(format!("#/{container}/{name}"), Schema::compile(def)?);
+                }
+            }
+        }
+        let root = Schema::compile(&schema)?;
+
+        let validator = Self {
+            source: schema,
+            root,
+            defs,
+            _phantom: PhantomData,
+        };
+        if let Some(missing) = validator.unresolved_ref() {
+            return Err(invalid(&format!("unresolved `$ref` {missing}")));
+        }
+        if let Some(cycle) = validator.ref_cycle() {
+            return Err(invalid(&format!(
+                "`$ref` {cycle} refers back to itself without descending into the document"
+            )));
+        }
+        Ok(validator)
+    }
+
+    pub fn schema(&self) -> &Value {
+        &self.source
+    }
+
+    /// Errors of `document`, keyed by instance path (`$` for the document itself).
+    pub fn validate_document(&self, document: &Value) -> ValidationErrors {
+        let mut found = vec![];
+        self.check(&self.root, document, &mut vec![], &mut found);
+
+        let mut result = ValidationErrors::default();
+        for (path, error) in found {
+            let segments = if path.is_empty() {
+                vec![ROOT.to_string()]
+            } else {
+                path
+            };
+            // the first error on a path wins over errors of its parents/children
+            if !is_occupied(&result.errors, &segments) {
+                result.add(segments, error);
+            }
+        }
+        result
+    }
+
+    fn is_valid(&self, schema: &Schema, value: &Value) -> bool {
+        let mut found = vec![];
+        self.check(schema, value, &mut vec![], &mut found);
+        found.is_empty()
+    }
+
+    fn unresolved_ref(&self) -> Option<&str> {
+        std::iter::once(&self.root)
+            .chain(self.defs.values())
+            .flat_map(Schema::refs)
+            .find(|reference| !self.defs.contains_key(*reference))
+    }
+
+    /// A definition that reaches itself through `$ref`s applied to the same instance, which
+    /// `check` would follow forever. Cycles through `items` or `properties` end with the document.
+    fn ref_cycle(&self) -> Option<&str> {
+        let mut names: Vec<&str> = self.defs.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        let mut done = HashSet::new();
+        names
+            .into_iter()
+            .find_map(|name| self.cycle_from(name, &mut vec![], &mut done))
+    }
+
+    fn cycle_from<'a>(
+        &'a self,
+        name: &'a str,
+        stack: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<&'a str> {
+        if stack.contains(&name) {
+            return Some(name);
+        }
+        if !done.insert(name) {
+            return None;
+        }
+        stack.push(name);
+        let cycle = self.defs[name]
+            .same_instance_refs()
+            .into_iter()
+            .find_map(|next| self.cycle_from(next, stack, done));
+        stack.pop();
+        cycle
+    }
+
+    /// Errors of `schema` at `path` come before those of its children, so they win.
+    fn check(
+        &self,
+        schema: &Schema,
+        value: &Value,
+        path: &mut Vec<String>,
+        found: &mut Vec<(Vec<String>, ValidationError)>,
+    ) {
+        let keywords = match schema {
+            Schema::Bool(true) => return,
+            Schema::Bool(false) => {
+                found.push((
+                    path.clone(),
+                    ValidationError::new("validator.json_schema.not"),
+                ));
+                return;
+            }
+            Schema::Keywords(keywords) => keywords,
+        };
+
+        if !keywords.types.is_empty() && !keywords.types.iter().any(|t| has_type(value, t)) {
+            let expected = keywords.types.join(", ");
+            let error = error_with("validator.json_schema.type", "expected", expected);
+            found.push((path.clone(), error));
+            return;
+        }
+
+        let mut here = vec![];
+        let mut children = vec![];
+        if let Some(reference) = &keywords.reference {
+            self.check(&self.defs[reference], value, path, &mut children);
+        }
+        if let Some(allowed) = keywords.allowed.as_ref().filter(|a| !a.contains(value)) {
+            let allowed: Vec<_> = allowed.iter().map(Value::to_string).collect();
+            here.push(error_with(
+                "validator.one_of",
+                "allowed",
+                allowed.join(", "),
+            ));
+        }
+
+        match value {
+            Value::String(text) => {
+                let length = text.chars().count();
+                if let Some(min) = keywords.min_length.filter(|&min| length < min) {
+                    here.push(error_with("validator.min_length", "min", min));
+                }
+                if let Some(max) = keywords.max_length.filter(|&max| length > max) {
+                    here.push(error_with("validator.max_length", "max", max));
+                }
+                if let Some(pattern) = keywords.pattern.as_ref().filter(|p| !p.is_match(text)) {
+                    here.push(error_with("validator.regex", "pattern", pattern.as_str()));
+                }
+            }
+            Value::Number(number) => {
+                let number = number.as_f64().unwrap_or(f64::NAN);
+                let violated = [
+                    (
+                        keywords.minimum.filter(|&min| number < min),
+                        "validator.min_value",
+                        "min",
+                    ),
+                    (
+                        keywords.maximum.filter(|&max| number > max),
+                        "validator.max_value",
+                        "max",
+                    ),
+                    (
+                        keywords.exclusive_minimum.filter(|&min| number <= min),
+                        "validator.greater_than",
+                        "min",
+                    ),
+                    (
+                        keywords.exclusive_maximum.filter(|&max| number >= max),
+                        "validator.less_than",
+                        "max",
+                    ),
+                ];
+                for (bound, key, param) in violated {
+                    if let Some(bound) = bound {
+                        here.push(error_with(key, param, bound));
+                    }
+                }
+            }
+            Value::Array(items) => {
+                if let Some(min) = keywords.min_items.filter(|&min| items.len() < min) {
+                    here.push(error_with("validator.min_items", "min", min));
+                }
+                if let Some(max) = keywords.max_items.filter(|&max| items.len() > max) {
+                    here.push(error_with("validator.max_items", "max", max));
+                }
+                if let Some(item_schema) = &keywords.items {
+                    for (index, item) in items.iter().enumerate() {
+                        path.push(index.to_string());
+                        self.check(item_schema, item, path, &mut children);
+                        path.pop();
+                    }
+                }
+            }
+            Value::Object(map) => {
+                for name in keywords.required.iter().filter(|n| !map.contains_key(*n)) {
+                    let mut field = path.clone();
+                    field.push(name.clone());
+                    children.push((field, ValidationError::new("validator.required")));
+                }
+                for (name, property) in map {
+                    path.push(name.clone());
+                    match (keywords.properties.get(name), &keywords.additional) {
+                        (Some(property_schema), _) => {
+                            self.check(property_schema, property, path, &mut children)
+                        }
+                        (None, Some(Schema::Bool(false))) => {
+                            let key = "validator.json_schema.additional_property";
+                            children.push((path.clone(), ValidationError::new(key)));
+                        }
+                        (None, Some(additional)) => {
+                            self.check(additional, property, path, &mut children)
+                        }
+                        (None, None) => {}
+                    }
+                    path.pop();
+                }
+            }
+            _ => {}
+        }
+
+        for sub_schema in &keywords.all_of {
+            self.check(sub_schema, value, path, &mut children);
+        }
+        if !keywords.any_of.is_empty() && !keywords.any_of.iter().any(|s| self.is_valid(s, value)) {
+            here.push(ValidationError::new("validator.json_schema.any_of"));
+        }
+        if !keywords.one_of.is_empty() {
+            let matched = keywords
+                .one_of
+                .iter()
+                .filter(|s| self.is_valid(s, value))
+                .count();
+            if matched != 1 {
+                here.push(error_with(
+                    "validator.json_schema.one_of",
+                    "matched",
+                    matched,
+                ));
+            }
+        }
+        if keywords
+            .not
+            .as_ref()
+            .is_some_and(|not| self.is_valid(not, value))
+        {
+            here.push(ValidationError::new("validator.json_schema.not"));
+        }
+
+        found.extend(here.into_iter().map(|error| (path.clone(), error)));
+        found.extend(children);
+    }
+}
+
+#[async_trait]
+impl<E> IValidate<Value, E> for JsonSchemaValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn rules(&self, builder: RulesBuilder<Value, E>) -> RulesBuilder<Value, E> {
+        builder
+    }
+
+    fn describe(&self) -> ValidatorDescriptor {
+        ValidatorDescriptor {
+            name: "JsonSchemaValidator".into(),
+            target: "Value".into(),
+            schema: Some(self.source.clone()),
+            ..Default::default()
+        }
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &Value,
+        _ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
+        let errors = self.validate_document(dto);
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidatorFailure::Invalid(errors))
+        }
+    }
+}
+
+/// A compiled (sub)schema.
+enum Schema {
+    Bool(bool),
+    Keywords(Box<Keywords>),
+}
+
+#[derive(Default)]
+struct Keywords {
+    reference: Option<String>,
+    types: Vec<String>,
+    /// `enum`, or `const` as a single-value enum.
+    allowed: Option<Vec<Value>>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    exclusive_maximum: Option<f64>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    items: Option<Schema>,
+    required: Vec<String>,
+    properties: HashMap<String, Schema>,
+    additional: Option<Schema>,
+    all_of: Vec<Schema>,
+    any_of: Vec<Schema>,
+    one_of: Vec<Schema>,
+    not: Option<Schema>,
+}
+
+impl Schema {
+    fn compile(schema: &Value) -> Result<Self, ValidaError> {
+        let map = match schema {
+            Value::Bool(accept) => return Ok(Schema::Bool(*accept)),
+            Value::Object(map) => map,
+            other => return Err(invalid(&format!("expected a schema, got {other}"))),
+        };
+
+        let mut keywords = Keywords::default();
+        for (keyword, value) in map {
+            let bad = || invalid(&format!("invalid `{keyword}`: {value}"));
+            match keyword.as_str() {
+                "$ref" => {
+                    let reference = value.as_str().ok_or_else(bad)?;
+                    if !reference.starts_with("#/$defs/")
+                        && !reference.starts_with("#/definitions/")
+                    {
+                        return Err(invalid(&format!(
+                            "only local `$defs` references are supported, got {reference}"
+                        )));
+                    }
+                    keywords.reference = Some(reference.to_string());
+                }
+                "type" => {
+                    keywords.types = match value {
+                        Value::String(name) => vec![name.clone()],
+                        Value::Array(names) => names
+                            .iter()
+                            .map(|name| name.as_str().map(String::from))
+                            .collect::<Option<_>>()
+                            .ok_or_else(bad)?,
+                        _ => return Err(bad()),
+                    };
+                    const TYPES: &[&str] = &[
+                        "null", "boolean", "object", "array", "number", "integer", "string",
+                    ];
+                    if keywords.types.iter().any(|t| !TYPES.contains(&t.as_str())) {
+                        return Err(bad());
+                    }
+                }
+                "enum" => keywords.allowed = Some(value.as_array().ok_or_else(bad)?.clone()),
+                "const" => keywords.allowed = Some(vec![value.clone()]),
+                "minLength" => keywords.min_length = Some(count(value).ok_or_else(bad)?),
+                "maxLength" => keywords.max_length = Some(count(value).ok_or_else(bad)?),
+                "pattern" => {
+                    let pattern = value.as_str().ok_or_else(bad)?;
+                    keywords.pattern = Some(Regex::new(pattern).map_err(|_| bad())?);
+                }
+                "minimum" => keywords.minimum = Some(value.as_f64().ok_or_else(bad)?),
+                "maximum" => keywords.maximum = Some(value.as_f64().ok_or_else(bad)?),
+                "exclusiveMinimum" => {
+                    keywords.exclusive_minimum = Some(value.as_f64().ok_or_else(bad)?)
+                }
+                "exclusiveMaximum" => {
+                    keywords.exclusive_maximum = Some(value.as_f64().ok_or_else(bad)?)
+                }
+                "minItems" => keywords.min_items = Some(count(value).ok_or_else(bad)?),
+                "maxItems" => keywords.max_items = Some(count(value).ok_or_else(bad)?),
+                "items" => keywords.items = Some(Schema::compile(value)?),
+                "required" => {
+                    keywords.required = value
+                        .as_array()
+                        .and_then(|names| {
+                            names
+                                .iter()
+                                .map(|name| name.as_str().map(String::from))
+                                .collect()
+                        })
+                        .ok_or_else(bad)?;
+                }
+                "properties" => {
+                    for (name, property) in value.as_object().ok_or_else(bad)? {
+                        keywords
+                            .properties
+                            .insert(name.clone(), Schema::compile(property)?);
+                    }
+                }
+                "additionalProperties" => keywords.additional = Some(Schema::compile(value)?),
+                "allOf" => keywords.all_of = compile_all(value).ok_or_else(bad)??,
+                "anyOf" => keywords.any_of = compile_all(value).ok_or_else(bad)??,
+                "oneOf" => keywords.one_of = compile_all(value).ok_or_else(bad)??,
+                "not" => keywords.not = Some(Schema::compile(value)?),
+                annotation if ANNOTATIONS.contains(&annotation) => {}
+                unsupported => {
+                    return Err(invalid(&format!("unsupported keyword `{unsupported}`")));
+                }
+            }
+        }
+        Ok(Schema::Keywords(Box::new(keywords)))
+    }
+
+    /// Every `$ref` of this schema and its subschemas.
+    fn refs(&self) -> Vec<&str> {
+        let Schema::Keywords(keywords) = self else {
+            return vec![];
+        };
+        let children = keywords
+            .items
+            .iter()
+            .chain(keywords.properties.values())
+            .chain(&keywords.additional)
+            .chain(&keywords.all_of)
+            .chain(&keywords.any_of)
+            .chain(&keywords.one_of)
+            .chain(&keywords.not);
+
+        keywords
+            .reference
+            .as_deref()
+            .into_iter()
+            .chain(children.flat_map(Schema::refs))
+            .collect()
+    }
+
+    /// The `$ref`s applied to the instance of this schema itself, not to its items or properties.
+    fn same_instance_refs(&self) -> Vec<&str> {
+        let Schema::Keywords(keywords) = self else {
+            return vec![];
+        };
+        let applicators = keywords
+            .all_of
+            .iter()
+            .chain(&keywords.any_of)
+            .chain(&keywords.one_of)
+            .chain(&keywords.not);
+
+        keywords
+            .reference
+            .as_deref()
+            .into_iter()
+            .chain(applicators.flat_map(Schema::same_instance_refs))
+            .collect()
+    }
+}
+
+fn compile_all(value: &Value) -> Option<Result<Vec<Schema>, ValidaError>> {
+    let schemas = value.as_array().filter(|schemas| !schemas.is_empty())?;
+    Some(schemas.iter().map(Schema::compile).collect())
+}
+
+fn count(value: &Value) -> Option<usize> {
+    value.as_u64().map(|count| count as usize)
+}
+
+fn has_type(value: &Value, name: &str) -> bool {
+    match name {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "number" => value.is_number(),
+        "integer" => value.as_f64().is_some_and(|n| n.fract() == 0.0),
+        _ => value.is_string(),
+    }
+}
+
+fn error_with(key: &str, param: &str, value: impl ToString) -> ValidationError {
+    ValidationError::new_with_params(key, HashMap::from([(param.into(), value.to_string())]))
+}
+
+fn invalid(message: &str) -> ValidaError {
+    ValidaError::InvalidJsonSchema(message.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::contract::ValidatorFailure;
+    use serde_json::json;
+
+    fn validator(schema: Value) -> JsonSchemaValidator<std::io::Error> {
+        JsonSchemaValidator::new(schema).unwrap()
+    }
+
+    fn errors(schema: Value, document: Value) -> Value {
+        validator(schema)
+            .validate_document(&document)
+            .to_json_dot_raw()
+    }
+
+    fn user_schema() -> Value {
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "required": ["name", "role"],
+            "additionalProperties": false,
+            "properties": {
+                "name": {"type": "string", "minLength": 2, "maxLength": 10},
+                "age": {"type": "integer", "minimum": 0, "exclusiveMaximum": 150},
+                "role": {"enum": ["admin", "user"]},
+                "tags": {"type": "array", "maxItems": 2, "items": {"pattern": "^[a-z]+$"}},
+                "address": {"$ref": "#/$defs/address"}
+            },
+            "$defs": {
+                "address": {
+                    "type": "object",
+                    "required": ["city"],
+                    "properties": {"city": {"type": "string"}}
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn accepts_valid_documents() {
+        let document = json!({
+            "name": "Ann",
+            "age": 30,
+            "role": "admin",
+            "tags": ["a", "b"],
+            "address": {"city": "Kyiv"}
+        });
+        assert_eq!(errors(user_schema(), document), json!({}));
+    }
+
+    #[test]
+    fn reports_errors_under_instance_paths() {
+        let document = json!({
+            "name": "A",
+            "age": 150,
+            "role": "guest",
+            "tags": ["ok", "Not OK"],
+            "address": {},
+            "extra": true
+        });
+        let errors = errors(user_schema(), document);
+
+        assert_eq!(errors["name"]["key"], "validator.min_length");
+        assert_eq!(errors["name"]["params"]["min"], "2");
+        assert_eq!(errors["age"]["key"], "validator.less_than");
+        assert_eq!(errors["role"]["key"], "validator.one_of");
+        assert_eq!(errors["tags.1"]["key"], "validator.regex");
+        assert_eq!(errors["address.city"]["key"], "validator.required");
+        assert_eq!(
+            errors["extra"]["key"],
+            "validator.json_schema.additional_property"
+        );
+        assert!(errors.get("tags.0").is_none());
+    }
+
+    #[test]
+    fn reports_type_mismatches_once() {
+        let errors = errors(user_schema(), json!({"name": 42, "role": "user"}));
+        assert_eq!(errors["name"]["key"], "validator.json_schema.type");
+        assert_eq!(errors["name"]["params"]["expected"], "string");
+
+        let errors = self::errors(user_schema(), json!([1]));
+        assert_eq!(errors["$"]["params"]["expected"], "object");
+    }
+
+    #[test]
+    fn combines_subschemas() {
+        let schema = json!({
+            "anyOf": [{"type": "string"}, {"type": "number"}],
+            "not": {"const": 0}
+        });
+        assert_eq!(errors(schema.clone(), json!("x")), json!({}));
+        assert_eq!(
+            errors(schema.clone(), json!(null))["$"]["key"],
+            "validator.json_schema.any_of"
+        );
+        assert_eq!(
+            errors(schema, json!(0))["$"]["key"],
+            "validator.json_schema.not"
+        );
+
+        let one_of = json!({"oneOf": [{"minimum": 0}, {"maximum": 10}]});
+        let errors = errors(one_of, json!(5));
+        assert_eq!(errors["$"]["key"], "validator.json_schema.one_of");
+        assert_eq!(errors["$"]["params"]["matched"], "2");
+    }
+
+    #[test]
+    fn follows_recursive_references() {
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": {
+                        "children": {"type": "array", "items": {"$ref": "#/$defs/node"}},
+                        "name": {"type": "string"}
+                    }
+                }
+            }
+        });
+        let document = json!({"children": [{"children": [{"name": 1}]}]});
+        let errors = errors(schema, document);
+        assert_eq!(
+            errors["children.0.children.0.name"]["key"],
+            "validator.json_schema.type"
+        );
+    }
+
+    struct Plugin {
+        config: String,
+    }
+
+    struct PluginValidator;
+
+    #[async_trait]
+    impl IValidate<Plugin, std::io::Error> for PluginValidator {
+        fn rules(
+            &self,
+            mut builder: RulesBuilder<Plugin, std::io::Error>,
+        ) -> RulesBuilder<Plugin, std::io::Error> {
+            let schema = json!({
+                "type": "object",
+                "required": ["port"],
+                "properties": {"port": {"type": "integer", "maximum": 65535}}
+            });
+            builder
+                .field("config", |p| &p.config)
+                .json()
+                .json_nested(validator(schema))
+                .build();
+            builder
+        }
+    }
+
+    async fn plugin_errors(config: &str) -> Value {
+        let plugin = Plugin {
+            config: config.into(),
+        };
+        match PluginValidator.validate(&plugin).await {
+            Ok(_) => json!({}),
+            Err(ValidatorFailure::Invalid(errors)) => errors.to_json_dot_raw(),
+            Err(ValidatorFailure::System(e)) => panic!("System error: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn validates_documents_stored_in_string_fields() {
+        assert_eq!(plugin_errors(r#"{"port": 8080}"#).await, json!({}));
+
+        let errors = plugin_errors(r#"{"port": 70000}"#).await;
+        assert_eq!(errors["config.port"]["key"], "validator.max_value");
+
+        let errors = plugin_errors(r#"{"port": "#).await;
+        assert_eq!(errors["config"]["key"], "validator.invalid_json");
+        assert!(errors.get("config.port").is_none());
+    }
+
+    #[test]
+    fn is_exported_as_content_schema() {
+        let schema = PluginValidator.describe().to_json_schema();
+        let config = &schema["properties"]["config"];
+
+        assert_eq!(config["type"], "string");
+        assert_eq!(config["contentMediaType"], "application/json");
+        assert_eq!(config["contentSchema"]["required"], json!(["port"]));
+        assert!(schema.get("$defs").is_none());
+    }
+
+    #[test]
+    fn rejects_unsupported_schemas() {
+        let result = JsonSchemaValidator::<std::io::Error>::new(json!({"prefixItems": []}));
+        assert!(
+            matches!(result, Err(ValidaError::InvalidJsonSchema(m)) if m.contains("prefixItems"))
+        );
+
+        let unresolved = json!({"$ref": "#/$defs/missing"});
+        assert!(JsonSchemaValidator::<std::io::Error>::new(unresolved).is_err());
+
+        let remote = json!({"$ref": "https://example.com/schema.json"});
+        assert!(JsonSchemaValidator::<std::io::Error>::new(remote).is_err());
+        assert!(JsonSchemaValidator::<std::io::Error>::new(json!({"type": "text"})).is_err());
+    }
+
+    #[test]
+    fn rejects_references_that_never_reach_the_document() {
+        let message = |schema| match JsonSchemaValidator::<std::io::Error>::new(schema) {
+            Err(ValidaError::InvalidJsonSchema(m)) => m,
+            _ => panic!("expected an invalid schema"),
+        };
+        let itself = json!({"$defs": {"a": {"$ref": "#/$defs/a"}}, "$ref": "#/$defs/a"});
+        assert!(message(itself).contains("`$ref` #/$defs/a refers back to itself"));
+
+        let mutual = json!({
+            "$defs": {
+                "a": {"anyOf": [{"type": "null"}, {"$ref": "#/$defs/b"}]},
+                "b": {"not": {"$ref": "#/$defs/a"}}
+            },
+            "type": "object"
+        });
+        assert!(message(mutual).contains("refers back to itself"));
+
+        let nested = json!({
+            "$defs": {"list": {"type": "array", "items": {"$ref": "#/$defs/list"}}},
+            "$ref": "#/$defs/list"
+        });
+        let validator = validator(nested);
+        assert!(validator.validate_document(&json!([[], [[]]])).is_empty());
+    }
+}
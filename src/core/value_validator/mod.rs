@@ -0,0 +1,4 @@
+pub mod json_node;
+pub mod json_path;
+pub mod json_schema;
+pub mod validator;
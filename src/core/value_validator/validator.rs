@@ -0,0 +1,320 @@
+use crate::core::builder::RulesBuilder;
+use crate::core::context::ValidationContext;
+use crate::core::contract::{IValidate, ValidatorFailure};
+use crate::core::errors::{ValidationErrors, ValidationNode};
+use crate::core::field_builder::main::FieldBuilder;
+use crate::core::valida_error::ValidaError;
+use crate::core::value_validator::json_node::{JsonNode, pointer_of};
+use crate::core::value_validator::json_path::JsonPath;
+use async_trait::async_trait;
+use serde_json::Value;
+use std::error::Error;
+use std::sync::Arc;
+
+pub(crate) const ROOT: &str = "$";
+
+/// Validator for untyped JSON documents: rules are declared against JSON paths
+/// and errors are reported under the concrete path of every matched value.
+///
+/// A location holds a single error, so when a value and one of its parents or
+/// children both fail, the path declared first wins: declare `$.items` before
+/// `$.items[*].qty` to report the collection error, or after it to report the
+/// item errors. Within a path, as for typed fields, the error of the last failing
+/// rule is the one kept.
+pub struct ValueValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    paths: Vec<(JsonPath, RulesBuilder<JsonNode, E>)>,
+}
+
+impl<E> ValueValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    pub fn new() -> Self {
+        Self { paths: vec![] }
+    }
+
+    pub fn path<F>(mut self, path: &str, rules: F) -> Result<Self, ValidaError>
+    where
+        F: FnOnce(
+            FieldBuilder<'_, JsonNode, JsonNode, E>,
+        ) -> FieldBuilder<'_, JsonNode, JsonNode, E>,
+    {
+        let path = JsonPath::parse(path)?;
+        let mut builder = RulesBuilder::new();
+        rules(builder.field(ROOT, |node: &JsonNode| node)).build();
+
+        self.paths.push((path, builder));
+        Ok(self)
+    }
+
+    /// Takes the document by value or as a shared [`Arc`]; matched values are
+    /// read in place. Validating through [`IValidate`] copies a borrowed
+    /// document once.
+    pub async fn validate_document(
+        &self,
+        document: impl Into<Arc<Value>>,
+    ) -> Result<ValidationErrors, E> {
+        self.validate_document_with_context(document, &ValidationContext::default())
+            .await
+    }
+
+    pub async fn validate_document_with_context(
+        &self,
+        document: impl Into<Arc<Value>>,
+        ctx: &ValidationContext,
+    ) -> Result<ValidationErrors, E> {
+        let document = document.into();
+        let mut result = ValidationErrors::default();
+
+        for (path, builder) in &self.paths {
+            for found in path.resolve(&document) {
+                let node = JsonNode::at(document.clone(), pointer_of(&found.segments));
+
+                let Some(node_errors) = builder
+                    .validate_with_context(&node, ctx)
+                    .await?
+                    .errors
+                    .remove(ROOT)
+                else {
+                    continue;
+                };
+
+                let segments = if found.segments.is_empty() {
+                    vec![ROOT.to_string()]
+                } else {
+                    found.segments
+                };
+
+                // the path declared first wins over errors of its parents/children
+                if is_occupied(&result.errors, &segments) {
+                    continue;
+                }
+
+                match node_errors {
+                    ValidationNode::Leaf(error) => result.add(segments, error),
+                    ValidationNode::Branch(errors) => {
+                        result.add_nested(segments, ValidationErrors { errors })
+                    }
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub(crate) fn is_occupied(
+    errors: &std::collections::HashMap<String, ValidationNode>,
+    segments: &[String],
+) -> bool {
+    let Some((head, tail)) = segments.split_first() else {
+        return false;
+    };
+
+    match errors.get(head) {
+        None => false,
+        Some(ValidationNode::Leaf(_)) => true,
+        Some(ValidationNode::Branch(children)) => tail.is_empty() || is_occupied(children, tail),
+    }
+}
+
+impl<E> Default for ValueValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<E> IValidate<Value, E> for ValueValidator<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn rules(&self, builder: RulesBuilder<Value, E>) -> RulesBuilder<Value, E> {
+        builder
+    }
+
+    async fn validate_with_context(
+        &self,
+        dto: &Value,
+        ctx: &ValidationContext,
+    ) -> Result<(), ValidatorFailure<E>> {
+        let errors = self
+            .validate_document_with_context(dto.clone(), ctx)
+            .await?;
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidatorFailure::Invalid(errors))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::rules::nested::NestedField;
+    use serde_json::json;
+
+    fn validator() -> ValueValidator<std::io::Error> {
+        ValueValidator::new()
+            .path("$.user.email", |f| f.not_empty().email())
+            .unwrap()
+            .path("$.user.name", |f| f.min_length(2))
+            .unwrap()
+            .path("$.items[*].qty", |f| f.range(1.0, 100.0))
+            .unwrap()
+            .path("$.items", |f| f.min_items(1))
+            .unwrap()
+    }
+
+    async fn errors_of(doc: Value) -> Value {
+        match validator().validate(&doc).await {
+            Ok(_) => json!({}),
+            Err(ValidatorFailure::Invalid(e)) => e.to_json_dot_raw(),
+            Err(ValidatorFailure::System(e)) => panic!("System error: {e:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn passes_valid_document() {
+        let doc = json!({
+            "user": {"email": "user@example.com", "name": "Ann"},
+            "items": [{"qty": 1}, {"qty": 5}]
+        });
+        assert_eq!(errors_of(doc).await, json!({}));
+    }
+
+    #[tokio::test]
+    async fn reports_errors_under_concrete_paths() {
+        let doc = json!({
+            "user": {"email": "broken", "name": "A"},
+            "items": [{"qty": 1}, {"qty": 500}]
+        });
+
+        let errors = errors_of(doc).await;
+
+        assert_eq!(errors["user.email"]["key"], "validator.email_format");
+        assert_eq!(errors["user.name"]["key"], "validator.min_length");
+        assert_eq!(errors["items.1.qty"]["key"], "validator.range");
+        assert!(errors.get("items.0.qty").is_none());
+    }
+
+    #[tokio::test]
+    async fn missing_value_fails_required_only() {
+        let doc = json!({"items": []});
+
+        let errors = errors_of(doc).await;
+
+        assert_eq!(errors["user.email"]["key"], "validator.required");
+        assert!(errors.get("user.name").is_none());
+        assert_eq!(errors["items"]["key"], "validator.min_items");
+    }
+
+    #[tokio::test]
+    async fn rules_skip_values_of_other_types() {
+        let doc = json!({
+            "user": {"email": "user@example.com", "name": 42},
+            "items": [{"qty": "many"}]
+        });
+        assert_eq!(errors_of(doc).await, json!({}));
+    }
+
+    #[tokio::test]
+    async fn first_declared_path_wins_on_overlap() {
+        let doc = json!({"items": [{"qty": 0}], "tags": [{"len": 0}]});
+        let validator = ValueValidator::<std::io::Error>::new()
+            .path("$.items", |f| f.max_items(0))
+            .unwrap()
+            .path("$.items[*].qty", |f| f.range(1.0, 2.0))
+            .unwrap()
+            .path("$.tags[*].len", |f| f.range(1.0, 2.0))
+            .unwrap()
+            .path("$.tags", |f| f.max_items(0))
+            .unwrap();
+
+        let errors = validator
+            .validate_document(doc)
+            .await
+            .unwrap()
+            .to_json_dot_raw();
+
+        assert_eq!(errors["items"]["key"], "validator.max_items");
+        assert!(errors.get("items.0.qty").is_none());
+        assert_eq!(errors["tags.0.len"]["key"], "validator.range");
+        assert!(errors.get("tags").is_none());
+    }
+
+    #[tokio::test]
+    async fn last_failing_rule_of_a_path_is_kept() {
+        let validator = ValueValidator::<std::io::Error>::new()
+            .path("$.email", |f| f.min_length(3).email())
+            .unwrap();
+        let errors = validator
+            .validate_document(json!({"email": "ab"}))
+            .await
+            .unwrap()
+            .to_json_dot_raw();
+        assert_eq!(errors["email"]["key"], "validator.email_format");
+    }
+
+    #[tokio::test]
+    async fn invalid_path_is_reported() {
+        let result = ValueValidator::<std::io::Error>::new().path("user.email", |f| f);
+        assert!(matches!(result, Err(ValidaError::InvalidJsonPath(_))));
+    }
+
+    #[tokio::test]
+    async fn validates_root_value() {
+        let validator = ValueValidator::<std::io::Error>::new()
+            .path("$", |f| f.max_length(3))
+            .unwrap();
+
+        let errors = match validator.validate(&json!("long")).await {
+            Err(ValidatorFailure::Invalid(e)) => e,
+            _ => panic!("Should be invalid"),
+        };
+        assert!(errors.has_error_for_field("$"));
+    }
+
+    #[tokio::test]
+    async fn nests_into_typed_validator() {
+        struct Webhook {
+            payload: Value,
+        }
+
+        struct WebhookValidator;
+
+        #[async_trait]
+        impl IValidate<Webhook, std::io::Error> for WebhookValidator {
+            fn rules(
+                &self,
+                mut builder: RulesBuilder<Webhook, std::io::Error>,
+            ) -> RulesBuilder<Webhook, std::io::Error> {
+                builder
+                    .field("payload", |w| &w.payload)
+                    .nested(validator())
+                    .build();
+                builder
+            }
+        }
+
+        let webhook = Webhook {
+            payload: json!({"user": {"email": "x"}, "items": [{"qty": 1}]}),
+        };
+
+        let errors = match WebhookValidator.validate(&webhook).await {
+            Err(ValidatorFailure::Invalid(e)) => e.to_json_dot_raw(),
+            _ => panic!("Should be invalid"),
+        };
+        assert_eq!(
+            errors["payload.user.email"]["key"],
+            "validator.email_format"
+        );
+    }
+}
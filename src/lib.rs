@@ -9,3 +9,43 @@ rust_i18n::i18n!(
     "dummy_in_memory",
     backend = core::features::localization::i18n::valida_backend::ValidaBackend::new()
 );
+
+#[cfg(feature = "utoipa")]
+#[doc(hidden)]
+pub use utoipa as __utoipa;
+
+/// Implements `utoipa::Modify` for a validator, so it can be passed to
+/// `#[openapi(modifiers(&UserValidator))]`. The DTO's component is found by its
+/// `ToSchema::name()` when it has one. `#[Validatable]` calls it for every
+/// generated validator; without the `utoipa` feature it expands to nothing.
+#[cfg(feature = "utoipa")]
+#[macro_export]
+macro_rules! openapi_modifier {
+    ($validator:ty, $dto:ty, $error:ty) => {
+        impl $crate::__utoipa::Modify for $validator {
+            fn modify(&self, openapi: &mut $crate::__utoipa::openapi::OpenApi) {
+                // only one of the two traits applies to a given DTO
+                #[allow(unused_imports)]
+                use $crate::core::features::openapi::constraints::{
+                    ComponentOf, DefaultComponent as _, RegisteredComponent as _,
+                };
+
+                let descriptor =
+                    <$validator as $crate::core::contract::IValidate<$dto, $error>>::describe(self);
+                let component =
+                    (&&&ComponentOf::<$dto>(::std::marker::PhantomData)).component(&descriptor);
+                let _ = $crate::core::features::openapi::constraints::apply_to_component(
+                    openapi,
+                    &component,
+                    &descriptor,
+                );
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "utoipa"))]
+#[macro_export]
+macro_rules! openapi_modifier {
+    ($validator:ty, $dto:ty, $error:ty) => {};
+}
@@ -1,8 +1,43 @@
 pub use valida::core::builder::RulesBuilder;
-pub use valida::core::contract::{IValidate, IValidatorRuleCustomAsync, ValidatorFailure};
-pub use valida::core::errors::ValidationError;
-pub use valida::core::rules::nested::NestedField;
+pub use valida::core::codes::CodeCase;
+pub use valida::core::context::ValidationContext;
+pub use valida::core::contract::{
+    IValidate, IValidatorRuleCustomAsync, IValidatorRuleDto, ValidatorFailure,
+};
+pub use valida::core::errors::{RuleFailure, ValidationError};
+pub use valida::core::finance::card::CardBrand;
+pub use valida::core::formats::base64_options::{Base64Options, Base64Padding};
+pub use valida::core::formats::document_options::{DocumentOptions, DocumentType};
+pub use valida::core::identifiers::national::NationalIdRegistry;
+pub use valida::core::net::dns::{DnsResolver, EmailDeliverable};
+pub use valida::core::net::email_options::EmailOptions;
+pub use valida::core::net::network::IpNetwork;
+pub use valida::core::net::pwned::{LocalHashFile, PasswordNotPwned, RangeFetcher};
+pub use valida::core::net::url_options::UrlOptions;
+pub use valida::core::password::estimate::{PasswordEstimate, estimate as estimate_password};
+pub use valida::core::password::policy::PasswordPolicy;
+pub use valida::core::password::strength::StrengthLevel;
+pub use valida::core::path_case::PathCase;
+pub use valida::core::rules::nested::{NestedField, NestedMutField};
+pub use valida::core::sanitize::Sanitize;
+pub use valida::core::sanitize::sanitizer::{Sanitizer, StrAsMut};
+pub use valida::core::schema::descriptor::{RuleDescriptor, ValidatorDescriptor};
+pub use valida::core::spec::field_spec::FieldSpec;
+pub use valida::core::spec::rule_spec::RuleSpec;
+pub use valida::core::temporal::bound::TimeBound;
+pub use valida::core::temporal::calendar::BusinessCalendar;
+pub use valida::core::temporal::clock::{Clock, FixedClock, SystemClock};
+pub use valida::core::temporal::format::DateFormat;
+pub use valida::core::temporal::moment::{CivilDate, Moment, Weekday};
+pub use valida::core::text::length::{LengthUnit, WordBoundary};
+pub use valida::core::text::unicode::NormalizationForm;
 pub use valida::core::valida_error::ValidaError;
+pub use valida::core::value_validator::json_node::JsonNode;
+pub use valida::core::value_validator::json_schema::JsonSchemaValidator;
+pub use valida::core::value_validator::validator::ValueValidator;
+
+#[cfg(feature = "phone")]
+pub use valida::core::features::phone::{PhoneOptions, PhoneType};
 
 #[cfg(feature = "derive")]
 pub use valida_derive::Validatable;
@@ -1,8 +1,10 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn::{
+    ext::IdentExt,
     parse::{Parse, ParseStream},
-    Fields, Ident, ItemStruct, Meta, Type, Token,
+    punctuated::Punctuated,
+    Attribute, Fields, Ident, ItemStruct, LitStr, Meta, Type, Token,
 };
 
 /// Одне правило: email, min_length(5), trimmed()
@@ -28,34 +30,160 @@ impl Parse for RuleAst {
     }
 }
 
-/// Набір правил
-#[derive(Debug)]
-pub struct RuleSet(pub Vec<RuleAst>);
+/// Набір правил + опціональне перейменування: rename = "firstName"
+#[derive(Debug, Default)]
+pub struct RuleSet {
+    pub rules: Vec<RuleAst>,
+    pub rename: Option<String>,
+}
 
 impl Parse for RuleSet {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut rules = vec![];
+        let mut rename = None;
         while !input.is_empty() {
-            rules.push(input.parse()?);
+            if input.peek(Ident) && input.peek2(Token![=]) {
+                let key: Ident = input.parse()?;
+                if key != "rename" {
+                    return Err(syn::Error::new(key.span(), "unknown validate option"));
+                }
+                input.parse::<Token![=]>()?;
+                rename = Some(input.parse::<LitStr>()?.value());
+            } else {
+                rules.push(input.parse()?);
+            }
             if input.peek(Token![,]) {
                 input.parse::<Token![,]>()?;
             }
         }
-        Ok(RuleSet(rules))
+        Ok(RuleSet { rules, rename })
+    }
+}
+
+/// serde rename_all: правила перетворення імен полів (як у serde_derive)
+#[derive(Debug, Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_str(value: &LitStr) -> syn::Result<Self> {
+        match value.value().as_str() {
+            "lowercase" => Ok(RenameRule::Lower),
+            "UPPERCASE" => Ok(RenameRule::Upper),
+            "PascalCase" => Ok(RenameRule::Pascal),
+            "camelCase" => Ok(RenameRule::Camel),
+            "snake_case" => Ok(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnake),
+            "kebab-case" => Ok(RenameRule::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(RenameRule::ScreamingKebab),
+            _ => Err(syn::Error::new(value.span(), "unknown rename_all rule")),
+        }
+    }
+
+    pub fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_owned(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_ascii_uppercase(),
+            RenameRule::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            RenameRule::Camel => {
+                let pascal = RenameRule::Pascal.apply_to_field(field);
+                let mut chars = pascal.chars();
+                match chars.next() {
+                    Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+                    None => pascal,
+                }
+            }
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.to_ascii_uppercase().replace('_', "-"),
+        }
     }
 }
 
-/// Очищення структури: видаляє #[validate(...)]
+/// Значення з #[serde(key = "...")] або #[serde(key(deserialize = "..."))]
+fn serde_name_value(attrs: &[Attribute], key: &str) -> syn::Result<Option<LitStr>> {
+    let mut found = None;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(key) {
+                // пропускаємо чужі опції разом з їх значенням
+                if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                } else if meta.input.peek(syn::token::Paren) {
+                    meta.parse_nested_meta(|nested| {
+                        if nested.input.peek(Token![=]) {
+                            nested.value()?.parse::<syn::Expr>()?;
+                        }
+                        Ok(())
+                    })?;
+                }
+                return Ok(());
+            }
+
+            if meta.input.peek(Token![=]) {
+                found = Some(meta.value()?.parse()?);
+            } else {
+                // для вхідних даних важливе ім'я десеріалізації
+                meta.parse_nested_meta(|nested| {
+                    let value: LitStr = nested.value()?.parse()?;
+                    if nested.path.is_ident("deserialize") {
+                        found = Some(value);
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(found)
+}
+
+/// Очищення структури: видаляє #[validate(...)] та #[sanitize(...)]
 pub fn strip_validate_attrs(input: &ItemStruct) -> ItemStruct {
     let mut cleaned = input.clone();
     if let Fields::Named(ref mut fields) = cleaned.fields {
         for field in fields.named.iter_mut() {
-            field.attrs.retain(|a| !a.path().is_ident("validate"));
+            field
+                .attrs
+                .retain(|a| !a.path().is_ident("validate") && !a.path().is_ident("sanitize"));
         }
     }
     cleaned
 }
 
+/// #[sanitize(trim, strip_control_chars)] → [Sanitizer::Trim, Sanitizer::StripControlChars]
+fn sanitizer_variants(attr: &Attribute) -> syn::Result<Vec<Ident>> {
+    let names = attr.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated)?;
+    Ok(names
+        .iter()
+        .map(|name| {
+            let variant = RenameRule::Pascal.apply_to_field(&name.to_string());
+            Ident::new(&variant, name.span())
+        })
+        .collect())
+}
+
 /// Генерація валідатора
 pub fn generate_validator(input: &ItemStruct, error_type: &Type) -> TokenStream {
     let struct_name = &input.ident;
@@ -64,33 +192,77 @@ pub fn generate_validator(input: &ItemStruct, error_type: &Type) -> TokenStream
     let generated_mod_name = format_ident!("__validator_mod_{}", struct_name.to_string().to_lowercase());
 
 
+    let rename_all = match serde_name_value(&input.attrs, "rename_all") {
+        Ok(Some(rule)) => match RenameRule::from_str(&rule) {
+            Ok(rule) => Some(rule),
+            Err(e) => return e.to_compile_error(),
+        },
+        Ok(None) => None,
+        Err(e) => return e.to_compile_error(),
+    };
+
     let mut builder_lines = vec![];
 
     if let Fields::Named(fields) = &input.fields {
         for field in &fields.named {
             let field_ident = field.ident.as_ref().unwrap();
-            let field_name_str = field_ident.to_string();
             let accessor = quote! { |x| &x.#field_ident };
 
-            let Some(attr) = field.attrs.iter().find(|a| a.path().is_ident("validate")) else {
+            let validate_attr = field.attrs.iter().find(|a| a.path().is_ident("validate"));
+            let sanitize_attr = field.attrs.iter().find(|a| a.path().is_ident("sanitize"));
+            if validate_attr.is_none() && sanitize_attr.is_none() {
                 continue;
+            }
+
+            let parsed = match validate_attr.map(|a| a.meta.clone()) {
+                Some(Meta::List(meta_list)) => match syn::parse2::<RuleSet>(meta_list.tokens) {
+                    Ok(v) => v,
+                    Err(e) => return e.to_compile_error(),
+                },
+                _ => RuleSet::default(),
             };
 
-            let Meta::List(meta_list) = attr.meta.clone() else {
-                continue;
+            let sanitizers = match sanitize_attr.map(sanitizer_variants) {
+                Some(Ok(variants)) => variants,
+                Some(Err(e)) => return e.to_compile_error(),
+                None => vec![],
             };
 
-            let parsed = match syn::parse2::<RuleSet>(meta_list.tokens.clone()) {
-                Ok(v) => v,
-                Err(e) => return e.to_compile_error(),
+            let RuleSet { rules, rename } = parsed;
+
+            // пріоритет: validate(rename) → serde(rename) → serde(rename_all) → ім'я поля
+            let field_name_str = match rename {
+                Some(name) => name,
+                None => match serde_name_value(&field.attrs, "rename") {
+                    Ok(Some(name)) => name.value(),
+                    Ok(None) => {
+                        let raw = field_ident.unraw().to_string();
+                        match rename_all {
+                            Some(rule) => rule.apply_to_field(&raw),
+                            None => raw,
+                        }
+                    }
+                    Err(e) => return e.to_compile_error(),
+                },
             };
 
-            let RuleSet(rules) = parsed;
             let mut chain = quote! { builder.field(#field_name_str, #accessor) };
 
+            // санітайзери виконуються до правил, через мутабельний доступ до поля
+            if !sanitizers.is_empty() {
+                chain = quote! {
+                    #chain.sanitize(|x| &mut x.#field_ident, [#(Sanitizer::#sanitizers),*])
+                };
+            }
+
             for rule in rules {
                 let method = &rule.key;
-                if let Some(val) = &rule.value {
+                // nested(...) отримує й мутабельний доступ, щоб санітайзери вкладеного DTO теж виконувались
+                if method == "nested"
+                    && let Some(val) = &rule.value
+                {
+                    chain = quote! { #chain.nested_mut(|x| &mut x.#field_ident, #val) };
+                } else if let Some(val) = &rule.value {
                     chain = quote! { #chain.#method(#val) };
                 } else {
                     chain = quote! { #chain.#method() };
@@ -122,5 +294,7 @@ pub fn generate_validator(input: &ItemStruct, error_type: &Type) -> TokenStream
         }
 
         pub use #generated_mod_name::#validator_name;
+
+        valida::openapi_modifier!(#validator_name, #struct_name, #error_type);
     }
 }